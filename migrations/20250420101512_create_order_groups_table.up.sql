@@ -0,0 +1,9 @@
+CREATE TABLE order_groups (
+  id VARCHAR PRIMARY KEY NOT NULL,
+  status VARCHAR NOT NULL,
+  payment_method VARCHAR NOT NULL,
+  total NUMERIC NOT NULL,
+  owner_id VARCHAR NOT NULL,
+  created_at TIMESTAMP DEFAULT NOW() NOT NULL,
+  updated_at TIMESTAMP
+);
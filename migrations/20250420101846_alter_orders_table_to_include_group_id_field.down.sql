@@ -0,0 +1,5 @@
+ALTER TABLE orders
+DROP CONSTRAINT fk_orders_group_id;
+
+ALTER TABLE orders
+DROP COLUMN group_id;
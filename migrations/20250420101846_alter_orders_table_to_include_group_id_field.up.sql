@@ -0,0 +1,5 @@
+ALTER TABLE orders
+ADD COLUMN group_id VARCHAR;
+
+ALTER TABLE orders
+ADD CONSTRAINT fk_orders_group_id FOREIGN KEY (group_id) REFERENCES order_groups (id);
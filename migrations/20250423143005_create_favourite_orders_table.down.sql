@@ -0,0 +1 @@
+DROP TABLE favourite_orders;
@@ -0,0 +1,10 @@
+CREATE TABLE favourite_orders (
+  id VARCHAR PRIMARY KEY NOT NULL,
+  name VARCHAR NOT NULL,
+  order_id VARCHAR NOT NULL,
+  owner_id VARCHAR NOT NULL,
+  created_at TIMESTAMP DEFAULT NOW() NOT NULL,
+  updated_at TIMESTAMP,
+  CONSTRAINT fk_favourite_orders_order_id FOREIGN KEY (order_id) REFERENCES orders (id),
+  CONSTRAINT fk_favourite_orders_owner_id FOREIGN KEY (owner_id) REFERENCES users (id)
+);
@@ -0,0 +1,7 @@
+CREATE TABLE guest_carts (
+  id VARCHAR PRIMARY KEY NOT NULL,
+  token VARCHAR UNIQUE NOT NULL,
+  items JSON NOT NULL,
+  created_at TIMESTAMP DEFAULT NOW() NOT NULL,
+  updated_at TIMESTAMP
+);
@@ -0,0 +1,4 @@
+ALTER TABLE kitchens
+DROP COLUMN latitude,
+DROP COLUMN longitude,
+DROP COLUMN delivery_radius;
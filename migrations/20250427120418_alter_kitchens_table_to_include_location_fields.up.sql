@@ -0,0 +1,4 @@
+ALTER TABLE kitchens
+ADD COLUMN latitude DOUBLE PRECISION,
+ADD COLUMN longitude DOUBLE PRECISION,
+ADD COLUMN delivery_radius DOUBLE PRECISION;
@@ -0,0 +1,6 @@
+DROP INDEX meals_search_document_idx;
+DROP INDEX kitchen_cities_name_trgm_idx;
+DROP INDEX kitchens_type_trgm_idx;
+DROP INDEX kitchens_name_trgm_idx;
+DROP INDEX meals_description_trgm_idx;
+DROP INDEX meals_name_trgm_idx;
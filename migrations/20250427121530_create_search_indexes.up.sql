@@ -0,0 +1,6 @@
+CREATE INDEX meals_name_trgm_idx ON meals USING GIN (name gin_trgm_ops);
+CREATE INDEX meals_description_trgm_idx ON meals USING GIN (description gin_trgm_ops);
+CREATE INDEX kitchens_name_trgm_idx ON kitchens USING GIN (name gin_trgm_ops);
+CREATE INDEX kitchens_type_trgm_idx ON kitchens USING GIN (type gin_trgm_ops);
+CREATE INDEX kitchen_cities_name_trgm_idx ON kitchen_cities USING GIN (name gin_trgm_ops);
+CREATE INDEX meals_search_document_idx ON meals USING GIN (TO_TSVECTOR('english', name || ' ' || description));
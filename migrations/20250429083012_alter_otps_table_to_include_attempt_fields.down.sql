@@ -0,0 +1,4 @@
+ALTER TABLE otps
+DROP COLUMN provider,
+DROP COLUMN attempts,
+DROP COLUMN sent_at;
@@ -0,0 +1,7 @@
+ALTER TABLE otps
+ADD COLUMN provider VARCHAR NOT NULL DEFAULT 'TERMII',
+ADD COLUMN attempts INTEGER NOT NULL DEFAULT 0,
+ADD COLUMN sent_at TIMESTAMP NOT NULL DEFAULT NOW();
+
+ALTER TABLE otps
+ALTER COLUMN provider DROP DEFAULT;
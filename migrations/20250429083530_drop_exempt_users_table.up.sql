@@ -0,0 +1 @@
+DROP TABLE exempt_users;
@@ -0,0 +1,2 @@
+ALTER TABLE otps
+DROP COLUMN delivery_attempts;
@@ -0,0 +1,2 @@
+ALTER TABLE otps
+ADD COLUMN delivery_attempts JSONB NOT NULL DEFAULT '[]';
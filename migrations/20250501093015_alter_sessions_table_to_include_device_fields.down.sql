@@ -0,0 +1,8 @@
+ALTER TABLE push_tokens
+DROP COLUMN session_id;
+
+ALTER TABLE sessions
+DROP COLUMN user_agent,
+DROP COLUMN ip_address,
+DROP COLUMN created_at,
+DROP COLUMN updated_at;
@@ -0,0 +1,8 @@
+ALTER TABLE sessions
+ADD COLUMN user_agent VARCHAR,
+ADD COLUMN ip_address VARCHAR,
+ADD COLUMN created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+ADD COLUMN updated_at TIMESTAMP;
+
+ALTER TABLE push_tokens
+ADD COLUMN session_id VARCHAR REFERENCES sessions(id) ON DELETE CASCADE;
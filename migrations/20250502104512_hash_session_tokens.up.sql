@@ -0,0 +1,4 @@
+UPDATE sessions
+SET
+    access_token = ENCODE(SHA256(access_token::BYTEA), 'hex'),
+    refresh_token = ENCODE(SHA256(refresh_token::BYTEA), 'hex');
@@ -0,0 +1,8 @@
+CREATE TABLE rotated_refresh_tokens (
+    id VARCHAR PRIMARY KEY NOT NULL,
+    session_id VARCHAR NOT NULL REFERENCES sessions(id) ON DELETE CASCADE,
+    token_hash VARCHAR NOT NULL UNIQUE,
+    rotated_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX rotated_refresh_tokens_session_id_idx ON rotated_refresh_tokens (session_id);
@@ -0,0 +1,8 @@
+CREATE TABLE security_events (
+    id VARCHAR PRIMARY KEY NOT NULL,
+    user_id VARCHAR NOT NULL REFERENCES users(id),
+    type VARCHAR NOT NULL,
+    meta JSONB NOT NULL DEFAULT '{}',
+    reviewed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
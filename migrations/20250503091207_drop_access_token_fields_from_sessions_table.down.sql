@@ -0,0 +1,5 @@
+DELETE FROM sessions;
+
+ALTER TABLE sessions
+ADD COLUMN access_token VARCHAR NOT NULL UNIQUE,
+ADD COLUMN access_token_expires_at TIMESTAMP NOT NULL;
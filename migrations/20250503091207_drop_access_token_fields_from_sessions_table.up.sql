@@ -0,0 +1,3 @@
+ALTER TABLE sessions
+DROP COLUMN access_token,
+DROP COLUMN access_token_expires_at;
@@ -0,0 +1 @@
+DROP TABLE kitchen_staff;
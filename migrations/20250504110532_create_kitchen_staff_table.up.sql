@@ -0,0 +1,7 @@
+CREATE TABLE kitchen_staff (
+  user_id VARCHAR PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+  kitchen_id VARCHAR NOT NULL REFERENCES kitchens(id) ON DELETE CASCADE,
+  created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX kitchen_staff_kitchen_id_idx ON kitchen_staff(kitchen_id);
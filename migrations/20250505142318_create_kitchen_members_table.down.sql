@@ -0,0 +1,12 @@
+CREATE TABLE kitchen_staff (
+  user_id VARCHAR PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+  kitchen_id VARCHAR NOT NULL REFERENCES kitchens(id) ON DELETE CASCADE,
+  created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX kitchen_staff_kitchen_id_idx ON kitchen_staff(kitchen_id);
+
+INSERT INTO kitchen_staff (user_id, kitchen_id, created_at)
+SELECT user_id, kitchen_id, created_at FROM kitchen_members WHERE status = 'ACTIVE';
+
+DROP TABLE kitchen_members;
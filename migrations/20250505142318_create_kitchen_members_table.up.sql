@@ -0,0 +1,17 @@
+CREATE TABLE kitchen_members (
+  id VARCHAR PRIMARY KEY,
+  kitchen_id VARCHAR NOT NULL REFERENCES kitchens(id) ON DELETE CASCADE,
+  user_id VARCHAR NOT NULL UNIQUE REFERENCES users(id) ON DELETE CASCADE,
+  role VARCHAR NOT NULL,
+  status VARCHAR NOT NULL,
+  invited_by VARCHAR REFERENCES users(id) ON DELETE SET NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMP
+);
+
+CREATE INDEX kitchen_members_kitchen_id_idx ON kitchen_members(kitchen_id);
+
+INSERT INTO kitchen_members (id, kitchen_id, user_id, role, status, created_at)
+SELECT user_id, kitchen_id, user_id, 'STAFF', 'ACTIVE', created_at FROM kitchen_staff;
+
+DROP TABLE kitchen_staff;
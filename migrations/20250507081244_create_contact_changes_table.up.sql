@@ -0,0 +1,19 @@
+CREATE TABLE contact_changes (
+    id VARCHAR PRIMARY KEY NOT NULL,
+    user_id VARCHAR NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    kind VARCHAR NOT NULL,
+    previous_value VARCHAR NOT NULL,
+    new_value VARCHAR NOT NULL,
+    status VARCHAR NOT NULL,
+    token_hash VARCHAR UNIQUE,
+    current_verified_at TIMESTAMP,
+    new_verified_at TIMESTAMP,
+    ip_address VARCHAR,
+    user_agent VARCHAR,
+    expires_at TIMESTAMP NOT NULL,
+    committed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP
+);
+
+CREATE INDEX contact_changes_user_id_idx ON contact_changes (user_id);
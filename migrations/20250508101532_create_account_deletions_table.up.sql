@@ -0,0 +1,16 @@
+CREATE TABLE account_deletions (
+    id VARCHAR PRIMARY KEY NOT NULL,
+    user_id VARCHAR NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    status VARCHAR NOT NULL,
+    scheduled_for TIMESTAMP NOT NULL,
+    cancelled_at TIMESTAMP,
+    completed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP
+);
+
+CREATE INDEX account_deletions_user_id_idx ON account_deletions (user_id);
+
+CREATE UNIQUE INDEX account_deletions_pending_user_id_idx
+ON account_deletions (user_id)
+WHERE status = 'PENDING';
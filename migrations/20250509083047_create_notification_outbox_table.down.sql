@@ -0,0 +1,3 @@
+DROP TABLE notification_attempts;
+
+DROP TABLE notification_outbox;
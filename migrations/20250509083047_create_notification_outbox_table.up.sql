@@ -0,0 +1,24 @@
+CREATE TABLE notification_outbox (
+    id VARCHAR PRIMARY KEY NOT NULL,
+    notification JSONB NOT NULL,
+    backend VARCHAR NOT NULL,
+    status VARCHAR NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    sent_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP
+);
+
+CREATE INDEX notification_outbox_status_next_attempt_at_idx ON notification_outbox (status, next_attempt_at);
+
+CREATE TABLE notification_attempts (
+    id VARCHAR PRIMARY KEY NOT NULL,
+    outbox_id VARCHAR NOT NULL REFERENCES notification_outbox(id) ON DELETE CASCADE,
+    backend VARCHAR NOT NULL,
+    is_successful BOOLEAN NOT NULL,
+    error VARCHAR,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX notification_attempts_outbox_id_idx ON notification_attempts (outbox_id);
@@ -0,0 +1,10 @@
+DROP TABLE notification_quiet_hours;
+
+DROP TABLE notification_preferences;
+
+ALTER TABLE notification_outbox
+DROP COLUMN category;
+
+UPDATE notifications
+SET category = 'ACCOUNT'
+WHERE category = 'TRANSACTIONAL';
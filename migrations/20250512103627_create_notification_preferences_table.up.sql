@@ -0,0 +1,27 @@
+UPDATE notifications
+SET category = 'TRANSACTIONAL'
+WHERE category = 'ACCOUNT';
+
+ALTER TABLE notification_outbox
+ADD COLUMN category VARCHAR NOT NULL DEFAULT 'TRANSACTIONAL';
+
+CREATE TABLE notification_preferences (
+    id VARCHAR PRIMARY KEY NOT NULL,
+    user_id VARCHAR NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    category VARCHAR NOT NULL,
+    backend VARCHAR NOT NULL,
+    is_enabled BOOLEAN NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP,
+    UNIQUE (user_id, category, backend)
+);
+
+CREATE TABLE notification_quiet_hours (
+    id VARCHAR PRIMARY KEY NOT NULL,
+    user_id VARCHAR NOT NULL UNIQUE REFERENCES users(id) ON DELETE CASCADE,
+    starts_at TIME NOT NULL,
+    ends_at TIME NOT NULL,
+    timezone VARCHAR NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP
+);
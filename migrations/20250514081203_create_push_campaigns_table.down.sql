@@ -0,0 +1,3 @@
+DROP TABLE push_campaign_recipients;
+
+DROP TABLE push_campaigns;
@@ -0,0 +1,36 @@
+CREATE TABLE push_campaigns (
+    id VARCHAR PRIMARY KEY NOT NULL,
+    title VARCHAR NOT NULL,
+    body VARCHAR NOT NULL,
+    link VARCHAR,
+    segment JSONB NOT NULL,
+    status VARCHAR NOT NULL,
+    scheduled_for TIMESTAMP NOT NULL,
+    recipient_count INTEGER NOT NULL DEFAULT 0,
+    sent_count INTEGER NOT NULL DEFAULT 0,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    skipped_count INTEGER NOT NULL DEFAULT 0,
+    open_count INTEGER NOT NULL DEFAULT 0,
+    created_by VARCHAR NOT NULL REFERENCES users(id),
+    started_at TIMESTAMP,
+    completed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP
+);
+
+CREATE INDEX push_campaigns_status_scheduled_for_idx ON push_campaigns (status, scheduled_for);
+
+CREATE TABLE push_campaign_recipients (
+    id VARCHAR PRIMARY KEY NOT NULL,
+    campaign_id VARCHAR NOT NULL REFERENCES push_campaigns(id) ON DELETE CASCADE,
+    user_id VARCHAR NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    status VARCHAR NOT NULL,
+    next_attempt_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    sent_at TIMESTAMP,
+    opened_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP,
+    UNIQUE (campaign_id, user_id)
+);
+
+CREATE INDEX push_campaign_recipients_campaign_id_status_next_attempt_at_idx ON push_campaign_recipients (campaign_id, status, next_attempt_at);
@@ -0,0 +1,13 @@
+CREATE TABLE sms_messages (
+    id VARCHAR PRIMARY KEY NOT NULL,
+    user_id VARCHAR REFERENCES users(id) ON DELETE SET NULL,
+    phone_number VARCHAR NOT NULL,
+    provider VARCHAR NOT NULL,
+    reference VARCHAR,
+    segments INTEGER NOT NULL,
+    cost NUMERIC NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP
+);
+
+CREATE INDEX sms_messages_created_at_idx ON sms_messages (created_at);
@@ -0,0 +1,2 @@
+ALTER TABLE push_tokens
+ALTER COLUMN session_id DROP NOT NULL;
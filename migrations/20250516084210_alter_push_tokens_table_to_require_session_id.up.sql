@@ -0,0 +1,5 @@
+DELETE FROM push_tokens
+WHERE session_id IS NULL;
+
+ALTER TABLE push_tokens
+ALTER COLUMN session_id SET NOT NULL;
@@ -0,0 +1 @@
+-- kitchen staff are kitchen members now, the role can't be told apart from USER once it's gone
@@ -0,0 +1,3 @@
+UPDATE users
+SET role = 'USER'
+WHERE role = 'KITCHEN_STAFF';
@@ -0,0 +1,18 @@
+ALTER TABLE users
+ADD COLUMN email_verified_at TIMESTAMP;
+
+UPDATE users
+SET email_verified_at = NOW()
+WHERE
+    EXISTS (
+        SELECT 1 FROM magic_links
+        WHERE magic_links.user_id = users.id AND magic_links.consumed_at IS NOT NULL
+    )
+    OR EXISTS (
+        SELECT 1 FROM contact_changes
+        WHERE
+            contact_changes.user_id = users.id
+            AND contact_changes.kind = 'EMAIL'
+            AND contact_changes.status = 'COMMITTED'
+            AND contact_changes.new_value = users.email
+    );
@@ -0,0 +1,7 @@
+ALTER TABLE notification_outbox
+ADD COLUMN user_id VARCHAR;
+
+UPDATE notification_outbox
+SET user_id = notification->'user'->>'id';
+
+CREATE INDEX notification_outbox_user_id_idx ON notification_outbox (user_id);
@@ -0,0 +1,2 @@
+ALTER TABLE otps
+DROP COLUMN user_id;
@@ -0,0 +1,9 @@
+ALTER TABLE otps
+ADD COLUMN user_id VARCHAR REFERENCES users(id) ON DELETE CASCADE;
+
+UPDATE otps
+SET user_id = users.id
+FROM users
+WHERE otps.hash = ENCODE(SHA256(CONVERT_TO(otps.purpose || '-' || users.id, 'UTF8')), 'hex');
+
+CREATE INDEX otps_user_id_idx ON otps (user_id);
@@ -4,7 +4,7 @@ use axum::{
     http::{header, Method},
     Extension, Router,
 };
-use std::sync::Arc;
+use std::{net::SocketAddr, sync::Arc};
 use tokio::net::TcpListener;
 use tower_http::{cors, trace};
 
@@ -43,7 +43,13 @@ impl App {
             .await
             .unwrap();
 
-        axum::serve(listener, self.router).await.unwrap();
+        axum::serve(
+            listener,
+            self.router
+                .into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await
+        .unwrap();
 
         tracing::debug!(
             "App is running on {}:{}",
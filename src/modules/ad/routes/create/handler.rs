@@ -1,12 +1,15 @@
 use super::service::service;
 use super::types::request;
-use crate::{modules::auth::middleware::AdminAuth, types::Context};
+use crate::{
+    modules::auth::{middleware::RequirePermission, permission},
+    types::Context,
+};
 use axum::{extract::State, response::IntoResponse};
 use axum_typed_multipart::TypedMultipart;
 use std::sync::Arc;
 
 pub async fn handler(
-    _: AdminAuth,
+    _: RequirePermission<permission::ManageAds>,
     State(ctx): State<Arc<Context>>,
     TypedMultipart(payload): TypedMultipart<request::Payload>,
 ) -> impl IntoResponse {
@@ -18,9 +18,13 @@ pub async fn service(ctx: Arc<Context>, mut payload: request::Payload) -> respon
             response::Error::ImageUploadFailed
         })?;
 
-    let banner_image = storage::upload_file(ctx.storage.clone(), buf)
+    let banner_image = storage::upload_image(ctx.storage.clone(), buf)
         .await
-        .map_err(|_| response::Error::ImageUploadFailed)?;
+        .map_err(|err| match err {
+            storage::Error::UnsupportedImage => response::Error::UnsupportedImage,
+            storage::Error::InvalidImageDimensions => response::Error::InvalidImageDimensions,
+            _ => response::Error::ImageUploadFailed,
+        })?;
 
     repository::create(
         &ctx.db_conn.pool,
@@ -39,11 +39,23 @@ pub mod response {
     pub enum Error {
         ImageUploadFailed,
         AdCreationFailed,
+        UnsupportedImage,
+        InvalidImageDimensions,
     }
 
     impl IntoResponse for Error {
         fn into_response(self) -> axum::response::Response {
             match self {
+                Self::UnsupportedImage => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Only JPEG, PNG and WebP images are supported" })),
+                )
+                    .into_response(),
+                Self::InvalidImageDimensions => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Image must be between 64 and 6000 pixels wide and tall" })),
+                )
+                    .into_response(),
                 Self::AdCreationFailed => (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     Json(json!({ "error": "Ad creation failed"})),
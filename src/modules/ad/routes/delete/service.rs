@@ -11,7 +11,7 @@ pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::
         .map_err(|_| response::Error::FailedToDeleteAd)?
         .ok_or(response::Error::AdNotFound)?;
 
-    storage::delete_file(ctx.storage.clone(), ad.banner_image.clone())
+    storage::delete_image(ctx.storage.clone(), ad.banner_image.clone())
         .await
         .map_err(|_| response::Error::FailedToDeleteAd)?;
 
@@ -1,6 +1,9 @@
 use super::service::service;
 use super::types::request;
-use crate::{modules::auth::middleware::AdminAuth, types::Context};
+use crate::{
+    modules::auth::{middleware::RequirePermission, permission},
+    types::Context,
+};
 use axum::{
     extract::{Path, State},
     response::IntoResponse,
@@ -9,7 +12,7 @@ use axum_typed_multipart::TypedMultipart;
 use std::sync::Arc;
 
 pub async fn handler(
-    _: AdminAuth,
+    _: RequirePermission<permission::ManageAds>,
     State(ctx): State<Arc<Context>>,
     Path(id): Path<String>,
     TypedMultipart(body): TypedMultipart<request::Body>,
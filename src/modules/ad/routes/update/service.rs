@@ -23,9 +23,13 @@ async fn upload_banner_image(
         response::Error::FailedToUploadImage
     })?;
 
-    storage::update_file(storage, old_image, buf)
+    storage::update_image(storage, old_image, buf)
         .await
-        .map_err(|_| response::Error::FailedToUploadImage)
+        .map_err(|err| match err {
+            storage::Error::UnsupportedImage => response::Error::UnsupportedImage,
+            storage::Error::InvalidImageDimensions => response::Error::InvalidImageDimensions,
+            _ => response::Error::FailedToUploadImage,
+        })
 }
 
 pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
@@ -42,11 +42,23 @@ pub mod response {
         FailedToFetchAd,
         FailedToUploadImage,
         FailedToUpdateAd,
+        UnsupportedImage,
+        InvalidImageDimensions,
     }
 
     impl IntoResponse for Error {
         fn into_response(self) -> axum::response::Response {
             match self {
+                Self::UnsupportedImage => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Only JPEG, PNG and WebP images are supported" })),
+                )
+                    .into_response(),
+                Self::InvalidImageDimensions => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Image must be between 64 and 6000 pixels wide and tall" })),
+                )
+                    .into_response(),
                 Self::FailedToUpdateAd => (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     Json(json!({ "message": "Failed to update ad" })),
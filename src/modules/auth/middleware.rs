@@ -1,17 +1,26 @@
+use super::permission::{self, Permission, RequiredPermission};
 use super::service;
+use super::service::token::Claims;
 use crate::modules::user;
-use crate::modules::user::repository::User;
-use crate::types::Context;
-use axum::extract::FromRequestParts;
+use crate::modules::user::repository::{Role, User};
+use crate::types::{rate_limit, Context};
+use axum::body::Body;
+use axum::extract::{ConnectInfo, FromRequestParts, Request, State};
 use axum::http::{HeaderMap, StatusCode};
+use axum::middleware::Next;
 use axum::response::IntoResponse;
 use axum::RequestPartsExt;
 use axum::{async_trait, Json};
 use axum::{extract::Extension, http, http::request::Parts, response::Response};
 use serde::Serialize;
-use serde_json::json;
+use serde_json::{json, Value};
+use std::marker::PhantomData;
+use std::net::SocketAddr;
 use std::sync::Arc;
 
+// Only bodies of routes with a phone number bucket are buffered, and those are tiny
+const MAX_RATE_LIMITED_BODY_SIZE: usize = 1024 * 64;
+
 enum Error {
     InvalidSession,
 }
@@ -25,13 +34,15 @@ fn get_session_id_from_header(header: String) -> Result<String, Error> {
         .ok_or(Error::InvalidSession)
 }
 
-async fn get_user_from_header(ctx: Arc<Context>, header: String) -> Result<User, Error> {
-    let session_id = get_session_id_from_header(header)?;
-    let session = service::auth::verify_access_token(ctx.clone(), session_id)
+async fn get_claims_from_header(ctx: Arc<Context>, header: String) -> Result<Claims, Error> {
+    let access_token = get_session_id_from_header(header)?;
+    service::auth::verify_access_token(ctx, access_token)
         .await
-        .map_err(|_| Error::InvalidSession)?;
+        .map_err(|_| Error::InvalidSession)
+}
 
-    user::repository::find_by_id(&ctx.db_conn.pool, session.user_id)
+async fn get_user_from_claims(ctx: Arc<Context>, claims: &Claims) -> Result<User, Error> {
+    user::repository::find_by_id(&ctx.db_conn.pool, claims.sub.clone())
         .await
         .map_err(|_| Error::InvalidSession)?
         .ok_or(Error::InvalidSession)
@@ -64,16 +75,37 @@ async fn get_user_from_header(ctx: Arc<Context>, header: String) -> Result<User,
 //     }
 // }
 
+// What the access token tells us about its user, enough for most routes without a trip to the database
+#[derive(Serialize, Clone)]
+pub struct AuthUser {
+    pub id: String,
+    pub role: Role,
+}
+
+impl AuthUser {
+    pub fn has_permission(&self, permission: &Permission) -> bool {
+        permission::has_permission(&self.role, permission)
+    }
+}
+
 #[derive(Serialize, Clone)]
 pub struct Auth {
+    pub user: AuthUser,
+    pub session_id: String,
+}
+
+// Same as `Auth` but loads the full user, for routes that need more than the token's claims
+#[derive(Serialize, Clone)]
+pub struct UserAuth {
     pub user: User,
+    pub session_id: String,
 }
 
-async fn get_user_from_request<State: Send + Sync>(
+async fn get_claims_from_request<State: Send + Sync>(
     ctx: Arc<Context>,
     parts: &mut Parts,
     _: &State,
-) -> Result<User, Response> {
+) -> Result<Claims, Response> {
     let headers = parts.extract::<HeaderMap>().await.unwrap();
 
     let err = (
@@ -86,7 +118,7 @@ async fn get_user_from_request<State: Send + Sync>(
         .and_then(|header| header.to_str().ok())
         .ok_or(err.clone().into_response())?;
 
-    get_user_from_header(ctx.clone(), auth_header.to_string())
+    get_claims_from_header(ctx.clone(), auth_header.to_string())
         .await
         .map_err(|_| err.clone().into_response())
 }
@@ -97,25 +129,87 @@ impl<S: Send + Sync> FromRequestParts<S> for Auth {
 
     async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
         let Extension(ctx) = parts.extract::<Extension<Arc<Context>>>().await.unwrap();
-        get_user_from_request(ctx, parts, state)
+        get_claims_from_request(ctx, parts, state)
             .await
-            .map(|user| Self { user })
+            .map(|claims| Self {
+                user: AuthUser {
+                    id: claims.sub,
+                    role: claims.role,
+                },
+                session_id: claims.sid,
+            })
     }
 }
 
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for UserAuth {
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Extension(ctx) = parts.extract::<Extension<Arc<Context>>>().await.unwrap();
+        let claims = get_claims_from_request(ctx.clone(), parts, state).await?;
+
+        get_user_from_claims(ctx, &claims)
+            .await
+            .map(|user| Self {
+                user,
+                session_id: claims.sid,
+            })
+            .map_err(|_| {
+                (
+                    StatusCode::UNAUTHORIZED,
+                    Json(json!({"error": "Invalid session token"})),
+                )
+                    .into_response()
+            })
+    }
+}
+
+// Same as `UserAuth` but only for users who have verified their phone number, which every sign-in strategy but
+// the phone one can skip
 #[derive(Serialize, Clone)]
-pub struct AdminAuth {
+pub struct VerifiedUserAuth {
     pub user: User,
+    pub session_id: String,
 }
 
 #[async_trait]
-impl<S: Send + Sync> FromRequestParts<S> for AdminAuth {
+impl<S: Send + Sync> FromRequestParts<S> for VerifiedUserAuth {
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let auth = UserAuth::from_request_parts(parts, state).await?;
+
+        if !auth.user.is_verified {
+            return Err((
+                StatusCode::FORBIDDEN,
+                Json(json!({"error": "Phone number not verified"})),
+            )
+                .into_response());
+        }
+
+        Ok(Self {
+            user: auth.user,
+            session_id: auth.session_id,
+        })
+    }
+}
+
+// Rejects users whose role doesn't grant `P`, e.g. `RequirePermission<permission::ManageAds>`
+pub struct RequirePermission<P: RequiredPermission> {
+    pub user: AuthUser,
+    pub session_id: String,
+    permission: PhantomData<P>,
+}
+
+#[async_trait]
+impl<S: Send + Sync, P: RequiredPermission> FromRequestParts<S> for RequirePermission<P> {
     type Rejection = Response;
 
     async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
         let Extension(ctx) = parts.extract::<Extension<Arc<Context>>>().await.unwrap();
 
-        let user = get_user_from_request(ctx, parts, state)
+        let claims = get_claims_from_request(ctx, parts, state)
             .await
             .map_err(|_| {
                 (
@@ -125,12 +219,211 @@ impl<S: Send + Sync> FromRequestParts<S> for AdminAuth {
                     .into_response()
             })?;
 
-        if !user::repository::is_admin(&user) {
+        let user = AuthUser {
+            id: claims.sub,
+            role: claims.role,
+        };
+
+        if !user.has_permission(&P::PERMISSION) {
             return Err(
                 (StatusCode::FORBIDDEN, Json(json!({ "error": "Forbidden" }))).into_response(),
             );
         }
 
-        Ok(Self { user })
+        Ok(Self {
+            user,
+            session_id: claims.sid,
+            permission: PhantomData,
+        })
     }
 }
+
+#[derive(Serialize, Clone)]
+pub struct Guest {
+    pub token: String,
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for Guest {
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        let headers = parts.extract::<HeaderMap>().await.unwrap();
+
+        headers
+            .get("X-Guest-Token")
+            .and_then(|header| header.to_str().ok())
+            .map(|token| Self {
+                token: token.to_string(),
+            })
+            .ok_or(
+                (
+                    StatusCode::UNAUTHORIZED,
+                    Json(json!({ "error": "Invalid guest token" })),
+                )
+                    .into_response(),
+            )
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for service::auth::Device {
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self {
+            user_agent: parts
+                .headers
+                .get(http::header::USER_AGENT)
+                .and_then(|header| header.to_str().ok())
+                .map(|user_agent| user_agent.to_string()),
+            ip_address: get_client_ip(&parts.headers, &parts.extensions),
+        })
+    }
+}
+
+pub fn too_many_requests(retry_after: u64, message: &str) -> Response {
+    (
+        StatusCode::TOO_MANY_REQUESTS,
+        [(http::header::RETRY_AFTER, retry_after.to_string())],
+        Json(json!({ "error": message })),
+    )
+        .into_response()
+}
+
+// NOTE: clients can send whatever X-Forwarded-For they like and the proxy only appends to it, so it's either the
+// address fly's edge saw (Fly-Client-IP) or the right-most hop, never the first one
+fn get_client_ip(headers: &HeaderMap, extensions: &http::Extensions) -> Option<String> {
+    headers
+        .get("Fly-Client-IP")
+        .and_then(|header| header.to_str().ok())
+        .or_else(|| {
+            headers
+                .get("X-Forwarded-For")
+                .and_then(|header| header.to_str().ok())
+                .and_then(|header| header.rsplit(',').next())
+        })
+        .map(|ip| ip.trim().to_string())
+        .filter(|ip| !ip.is_empty())
+        .or_else(|| {
+            extensions
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| addr.ip().to_string())
+        })
+}
+
+// NOTE: this costs a denylist lookup, which is why only groups with a user bucket pay for it
+async fn get_user_id_from_headers(ctx: Arc<Context>, headers: &HeaderMap) -> Option<String> {
+    let auth_header = headers
+        .get(http::header::AUTHORIZATION)
+        .and_then(|header| header.to_str().ok())?;
+
+    get_claims_from_header(ctx, auth_header.to_string())
+        .await
+        .ok()
+        .map(|claims| claims.sub)
+}
+
+async fn get_phone_number_from_request(
+    req: Request,
+) -> Result<(Request, Option<String>), Response> {
+    let (parts, body) = req.into_parts();
+
+    let bytes = axum::body::to_bytes(body, MAX_RATE_LIMITED_BODY_SIZE)
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::PAYLOAD_TOO_LARGE,
+                Json(json!({ "error": "Request body is too large" })),
+            )
+                .into_response()
+        })?;
+
+    let phone_number = serde_json::from_slice::<Value>(&bytes)
+        .ok()
+        .and_then(|body| {
+            body.get("phone_number")
+                .and_then(|phone_number| phone_number.as_str())
+                .map(|phone_number| phone_number.trim().to_string())
+        })
+        .filter(|phone_number| !phone_number.is_empty());
+
+    Ok((Request::from_parts(parts, Body::from(bytes)), phone_number))
+}
+
+pub async fn rate_limit(
+    State(group): State<&'static str>,
+    Extension(ctx): Extension<Arc<Context>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let rules = ctx.rate_limit.policies.get(group);
+    if rules.is_empty() {
+        return next.run(req).await;
+    }
+
+    let ip = get_client_ip(req.headers(), req.extensions());
+
+    let user_id = if rules
+        .iter()
+        .any(|rule| rule.bucket == rate_limit::Bucket::User)
+    {
+        get_user_id_from_headers(ctx.clone(), req.headers()).await
+    } else {
+        None
+    };
+
+    let (req, phone_number) = if rules
+        .iter()
+        .any(|rule| rule.bucket == rate_limit::Bucket::PhoneNumber)
+    {
+        match get_phone_number_from_request(req).await {
+            Ok(res) => res,
+            Err(res) => return res,
+        }
+    } else {
+        (req, None)
+    };
+
+    let mut retry_after: Option<u64> = None;
+
+    for rule in rules.iter() {
+        let value = match rule.bucket {
+            rate_limit::Bucket::Ip => ip.clone(),
+            rate_limit::Bucket::PhoneNumber => phone_number.clone(),
+            rate_limit::Bucket::User => user_id.clone(),
+        };
+
+        let value = match value {
+            Some(value) => value,
+            None => continue,
+        };
+
+        match ctx
+            .rate_limit
+            .store
+            .hit(
+                &rate_limit::get_key(group, &rule.bucket, &value),
+                rule.window,
+            )
+            .await
+        {
+            Ok(hit) if hit.count > rule.limit => {
+                retry_after = Some(retry_after.unwrap_or(0).max(hit.reset_in));
+            }
+            Ok(_) => (),
+            // NOTE: we'd rather let requests through than lock everyone out while the store is down
+            Err(_) => tracing::warn!(
+                "Skipping {} rate limit for group {}",
+                rule.bucket.to_string(),
+                group
+            ),
+        }
+    }
+
+    if let Some(retry_after) = retry_after {
+        return too_many_requests(retry_after, "Too many requests, please try again later");
+    }
+
+    next.run(req).await
+}
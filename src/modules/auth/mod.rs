@@ -1,5 +1,6 @@
 mod routes;
 pub use routes::*;
 pub mod middleware;
+pub mod permission;
 pub mod repository;
 pub mod service;
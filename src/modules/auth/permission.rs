@@ -0,0 +1,96 @@
+use serde::Serialize;
+
+use crate::modules::user::repository::Role;
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub enum Permission {
+    #[serde(rename = "orders.view")]
+    ViewOrders,
+    #[serde(rename = "orders.refund")]
+    RefundOrders,
+    #[serde(rename = "transactions.view")]
+    ViewTransactions,
+    #[serde(rename = "kitchens.manage")]
+    ManageKitchens,
+    #[serde(rename = "ads.manage")]
+    ManageAds,
+    #[serde(rename = "analytics.view")]
+    ViewAnalytics,
+    #[serde(rename = "roles.manage")]
+    ManageRoles,
+    #[serde(rename = "integrations.manage")]
+    ManageIntegrations,
+    #[serde(rename = "notifications.manage")]
+    ManageNotifications,
+}
+
+impl ToString for Permission {
+    fn to_string(&self) -> String {
+        match self {
+            Permission::ViewOrders => String::from("orders.view"),
+            Permission::RefundOrders => String::from("orders.refund"),
+            Permission::ViewTransactions => String::from("transactions.view"),
+            Permission::ManageKitchens => String::from("kitchens.manage"),
+            Permission::ManageAds => String::from("ads.manage"),
+            Permission::ViewAnalytics => String::from("analytics.view"),
+            Permission::ManageRoles => String::from("roles.manage"),
+            Permission::ManageIntegrations => String::from("integrations.manage"),
+            Permission::ManageNotifications => String::from("notifications.manage"),
+        }
+    }
+}
+
+pub const ROLES: [Role; 4] = [Role::Admin, Role::Support, Role::Finance, Role::User];
+
+// NOTE: roles are fixed sets of permissions, handlers should check for a permission instead of a role
+pub fn get_permissions(role: &Role) -> Vec<Permission> {
+    match role {
+        Role::Admin => vec![
+            Permission::ViewOrders,
+            Permission::RefundOrders,
+            Permission::ViewTransactions,
+            Permission::ManageKitchens,
+            Permission::ManageAds,
+            Permission::ViewAnalytics,
+            Permission::ManageRoles,
+            Permission::ManageIntegrations,
+            Permission::ManageNotifications,
+        ],
+        Role::Support => vec![
+            Permission::ViewOrders,
+            Permission::RefundOrders,
+            Permission::ViewTransactions,
+        ],
+        Role::Finance => vec![Permission::ViewAnalytics],
+        Role::User => vec![],
+    }
+}
+
+pub fn has_permission(role: &Role, permission: &Permission) -> bool {
+    get_permissions(role).contains(permission)
+}
+
+// Type level permissions for `middleware::RequirePermission`
+pub trait RequiredPermission: Send + Sync {
+    const PERMISSION: Permission;
+}
+
+macro_rules! define_required_permission {
+    ($name:ident, $permission:ident) => {
+        pub struct $name;
+
+        impl RequiredPermission for $name {
+            const PERMISSION: Permission = Permission::$permission;
+        }
+    };
+}
+
+define_required_permission!(ViewOrders, ViewOrders);
+define_required_permission!(RefundOrders, RefundOrders);
+define_required_permission!(ViewTransactions, ViewTransactions);
+define_required_permission!(ManageKitchens, ManageKitchens);
+define_required_permission!(ManageAds, ManageAds);
+define_required_permission!(ViewAnalytics, ViewAnalytics);
+define_required_permission!(ManageRoles, ManageRoles);
+define_required_permission!(ManageIntegrations, ManageIntegrations);
+define_required_permission!(ManageNotifications, ManageNotifications);
@@ -0,0 +1,168 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::PgExecutor;
+use ulid::Ulid;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum IdentityProvider {
+    #[serde(rename = "GOOGLE")]
+    Google,
+    #[serde(rename = "APPLE")]
+    Apple,
+}
+
+impl From<String> for IdentityProvider {
+    fn from(value: String) -> Self {
+        match value.as_ref() {
+            "GOOGLE" => IdentityProvider::Google,
+            "APPLE" => IdentityProvider::Apple,
+            provider => unreachable!("Invalid identity provider: {}", provider),
+        }
+    }
+}
+
+impl ToString for IdentityProvider {
+    fn to_string(&self) -> String {
+        match self {
+            IdentityProvider::Google => String::from("GOOGLE"),
+            IdentityProvider::Apple => String::from("APPLE"),
+        }
+    }
+}
+
+// An external account a user can sign in with, `subject` is the provider's id for that account
+#[derive(Serialize, Clone, Debug)]
+pub struct Identity {
+    pub id: String,
+    pub user_id: String,
+    pub provider: IdentityProvider,
+    pub subject: String,
+    pub email: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+pub enum Error {
+    UnexpectedError,
+}
+
+pub struct CreateIdentityPayload {
+    pub user_id: String,
+    pub provider: IdentityProvider,
+    pub subject: String,
+    pub email: Option<String>,
+}
+
+pub async fn create<'e, E: PgExecutor<'e>>(
+    e: E,
+    payload: CreateIdentityPayload,
+) -> Result<Identity, Error> {
+    sqlx::query_as!(
+        Identity,
+        "
+        INSERT INTO user_identities (id, user_id, provider, subject, email)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING *
+        ",
+        Ulid::new().to_string(),
+        payload.user_id,
+        payload.provider.to_string(),
+        payload.subject,
+        payload.email
+    )
+    .fetch_one(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to link a {} identity to user with id {}: {}",
+            payload.provider.to_string(),
+            payload.user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_by_provider_and_subject<'e, E: PgExecutor<'e>>(
+    e: E,
+    provider: IdentityProvider,
+    subject: String,
+) -> Result<Option<Identity>, Error> {
+    sqlx::query_as!(
+        Identity,
+        "SELECT * FROM user_identities WHERE provider = $1 AND subject = $2",
+        provider.to_string(),
+        subject
+    )
+    .fetch_optional(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch {} identity by subject: {}",
+            provider.to_string(),
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_many_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<Vec<Identity>, Error> {
+    sqlx::query_as!(
+        Identity,
+        "SELECT * FROM user_identities WHERE user_id = $1 ORDER BY created_at ASC",
+        user_id
+    )
+    .fetch_all(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch identities of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn delete_by_id_and_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+    user_id: String,
+) -> Result<bool, Error> {
+    sqlx::query!(
+        "DELETE FROM user_identities WHERE id = $1 AND user_id = $2",
+        id,
+        user_id
+    )
+    .execute(e)
+    .await
+    .map(|result| result.rows_affected() > 0)
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to unlink identity with id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn delete_many_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<(), Error> {
+    sqlx::query!("DELETE FROM user_identities WHERE user_id = $1", user_id)
+        .execute(e)
+        .await
+        .map(|_| ())
+        .map_err(|err| {
+            tracing::error!(
+                "Error occurred while trying to unlink identities of user with id {}: {}",
+                user_id,
+                err
+            );
+            Error::UnexpectedError
+        })
+}
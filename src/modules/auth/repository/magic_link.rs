@@ -0,0 +1,108 @@
+use chrono::NaiveDateTime;
+use sqlx::PgExecutor;
+use ulid::Ulid;
+
+// Only the hash of the token that went out in the email is kept
+pub struct MagicLink {
+    pub id: String,
+    pub user_id: String,
+    pub token_hash: String,
+    pub expires_at: NaiveDateTime,
+    pub consumed_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+pub enum Error {
+    UnexpectedError,
+}
+
+pub struct CreateMagicLinkPayload {
+    pub user_id: String,
+    pub token_hash: String,
+    pub expires_at: NaiveDateTime,
+}
+
+pub async fn create<'e, E: PgExecutor<'e>>(
+    e: E,
+    payload: CreateMagicLinkPayload,
+) -> Result<MagicLink, Error> {
+    sqlx::query_as!(
+        MagicLink,
+        "
+        INSERT INTO magic_links (id, user_id, token_hash, expires_at)
+        VALUES ($1, $2, $3, $4)
+        RETURNING *
+        ",
+        Ulid::new().to_string(),
+        payload.user_id,
+        payload.token_hash,
+        payload.expires_at
+    )
+    .fetch_one(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to create a magic link for user with id {}: {}",
+            payload.user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_by_token_hash<'e, E: PgExecutor<'e>>(
+    e: E,
+    token_hash: String,
+) -> Result<Option<MagicLink>, Error> {
+    sqlx::query_as!(
+        MagicLink,
+        "SELECT * FROM magic_links WHERE token_hash = $1",
+        token_hash
+    )
+    .fetch_optional(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch magic link by hash: {}",
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+// NOTE: returns false when another request consumed the link first
+pub async fn consume_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<bool, Error> {
+    sqlx::query!(
+        "UPDATE magic_links SET consumed_at = NOW() WHERE id = $1 AND consumed_at IS NULL",
+        id
+    )
+    .execute(e)
+    .await
+    .map(|result| result.rows_affected() > 0)
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to consume magic link with id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn delete_many_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<(), Error> {
+    sqlx::query!("DELETE FROM magic_links WHERE user_id = $1", user_id)
+        .execute(e)
+        .await
+        .map(|_| ())
+        .map_err(|err| {
+            tracing::error!(
+                "Error occurred while trying to delete magic links of user with id {}: {}",
+                user_id,
+                err
+            );
+            Error::UnexpectedError
+        })
+}
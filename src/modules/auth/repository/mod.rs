@@ -1,2 +1,6 @@
+pub mod identity;
+pub mod magic_link;
 pub mod otp;
+pub mod refresh_token;
+pub mod security_event;
 pub mod session;
@@ -1,14 +1,79 @@
 use chrono::{Duration, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use sqlx::PgExecutor;
 use ulid::Ulid;
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum OtpChannel {
+    #[serde(rename = "sms")]
+    Sms,
+    #[serde(rename = "whatsapp")]
+    Whatsapp,
+    #[serde(rename = "voice")]
+    Voice,
+    #[serde(rename = "email")]
+    Email,
+}
+
+impl Default for OtpChannel {
+    fn default() -> Self {
+        Self::Sms
+    }
+}
+
+impl ToString for OtpChannel {
+    fn to_string(&self) -> String {
+        match self {
+            Self::Sms => String::from("sms"),
+            Self::Whatsapp => String::from("whatsapp"),
+            Self::Voice => String::from("voice"),
+            Self::Email => String::from("email"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum OtpDeliveryStatus {
+    #[serde(rename = "SENT")]
+    Sent,
+    #[serde(rename = "FAILED")]
+    Failed,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct OtpDeliveryAttempt {
+    pub channel: OtpChannel,
+    pub provider: String,
+    pub status: OtpDeliveryStatus,
+    pub error: Option<String>,
+    pub attempted_at: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct OtpDeliveryAttempts(pub Vec<OtpDeliveryAttempt>);
+
+impl Into<OtpDeliveryAttempts> for Value {
+    fn into(self) -> OtpDeliveryAttempts {
+        match serde_json::de::from_str::<Vec<OtpDeliveryAttempt>>(self.to_string().as_ref()) {
+            Ok(attempts) => OtpDeliveryAttempts(attempts),
+            Err(_) => OtpDeliveryAttempts(vec![]),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Otp {
     pub id: String,
+    pub user_id: Option<String>,
     pub otp: String,
     pub purpose: String,
     pub meta: String,
     pub hash: String,
+    pub provider: String,
+    pub attempts: i32,
+    pub sent_at: NaiveDateTime,
+    pub delivery_attempts: OtpDeliveryAttempts,
     pub expires_at: NaiveDateTime,
     pub created_at: NaiveDateTime,
     pub updated_at: Option<NaiveDateTime>,
@@ -20,10 +85,13 @@ pub enum Error {
 }
 
 pub struct CreateOtpPayload {
+    pub user_id: String,
     pub purpose: String,
     pub meta: String,
     pub hash: String,
     pub otp: String,
+    pub provider: String,
+    pub delivery_attempts: OtpDeliveryAttempts,
     pub validity: i32,
 }
 
@@ -32,14 +100,18 @@ pub async fn create<'e, E: PgExecutor<'e>>(e: E, payload: CreateOtpPayload) -> R
     sqlx::query_as!(
         Otp,
         "
-        INSERT INTO otps (id, purpose, meta, otp, hash, expires_at) VALUES ($1, $2, $3, $4, $5, $6)
+        INSERT INTO otps (id, user_id, purpose, meta, otp, hash, provider, delivery_attempts, expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
         RETURNING *
         ",
         Ulid::new().to_string(),
+        payload.user_id,
         payload.purpose,
         payload.meta,
         payload.otp,
         payload.hash,
+        payload.provider,
+        json!(payload.delivery_attempts),
         expires_at
     )
     .fetch_one(e)
@@ -65,7 +137,10 @@ pub struct UpdateOtpPayload {
     pub otp: String,
     pub hash: String,
     pub meta: Option<String>,
+    pub provider: String,
+    pub delivery_attempts: OtpDeliveryAttempts,
     pub validity: i32,
+    pub reset_attempts: bool,
 }
 
 pub async fn update_by_id<'e, E: PgExecutor<'e>>(
@@ -82,17 +157,24 @@ pub async fn update_by_id<'e, E: PgExecutor<'e>>(
                 otp = $2,
                 hash = $3,
                 meta = $4,
-                expires_at = $5,
+                provider = $5,
+                delivery_attempts = $6,
+                expires_at = $7,
+                attempts = CASE WHEN $8 THEN 0 ELSE attempts END,
+                sent_at = NOW(),
                 updated_at = NOW()
             WHERE
-                id = $6
+                id = $9
             RETURNING *
         ",
         payload.purpose,
         payload.otp,
         payload.hash,
         payload.meta,
+        payload.provider,
+        json!(payload.delivery_attempts),
         expires_at,
+        payload.reset_attempts,
         id
     )
     .fetch_one(e)
@@ -103,6 +185,30 @@ pub async fn update_by_id<'e, E: PgExecutor<'e>>(
     })
 }
 
+pub async fn increment_attempts_by_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+) -> Result<Otp, Error> {
+    sqlx::query_as!(
+        Otp,
+        "
+            UPDATE otps SET
+                attempts = attempts + 1,
+                updated_at = NOW()
+            WHERE
+                id = $1
+            RETURNING *
+        ",
+        id
+    )
+    .fetch_one(e)
+    .await
+    .map_err(|err| {
+        tracing::error!("Failed to increment attempts for otp by id {}: {}", id, err);
+        Error::UnexpectedError
+    })
+}
+
 pub async fn delete_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<(), Error> {
     sqlx::query!("DELETE FROM otps WHERE id = $1", id)
         .execute(e)
@@ -113,3 +219,34 @@ pub async fn delete_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<(),
         })
         .map(|_| {})
 }
+
+pub async fn find_many_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<Vec<Otp>, Error> {
+    sqlx::query_as!(
+        Otp,
+        "SELECT * FROM otps WHERE user_id = $1 ORDER BY created_at DESC",
+        user_id
+    )
+    .fetch_all(e)
+    .await
+    .map_err(|err| {
+        tracing::error!("Failed to fetch otps of user with id {}: {}", user_id, err);
+        Error::UnexpectedError
+    })
+}
+
+pub async fn delete_many_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<(), Error> {
+    sqlx::query!("DELETE FROM otps WHERE user_id = $1", user_id)
+        .execute(e)
+        .await
+        .map_err(|err| {
+            tracing::error!("Failed to delete otps of user with id {}: {}", user_id, err);
+            Error::UnexpectedError
+        })
+        .map(|_| {})
+}
@@ -0,0 +1,67 @@
+use chrono::NaiveDateTime;
+use sqlx::PgExecutor;
+use ulid::Ulid;
+
+// A refresh token that has already been swapped for a new one, kept so that replaying it can be detected
+pub struct RotatedRefreshToken {
+    pub id: String,
+    pub session_id: String,
+    pub token_hash: String,
+    pub rotated_at: NaiveDateTime,
+}
+
+pub enum Error {
+    UnexpectedError,
+}
+
+pub struct CreateRotatedRefreshTokenPayload {
+    pub session_id: String,
+    pub token_hash: String,
+}
+
+pub async fn create<'e, E: PgExecutor<'e>>(
+    e: E,
+    payload: CreateRotatedRefreshTokenPayload,
+) -> Result<RotatedRefreshToken, Error> {
+    sqlx::query_as!(
+        RotatedRefreshToken,
+        "
+        INSERT INTO rotated_refresh_tokens (id, session_id, token_hash)
+        VALUES ($1, $2, $3)
+        RETURNING *
+        ",
+        Ulid::new().to_string(),
+        payload.session_id,
+        payload.token_hash
+    )
+    .fetch_one(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to create a rotated refresh token for session with id {}: {}",
+            payload.session_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_by_token_hash<'e, E: PgExecutor<'e>>(
+    e: E,
+    token_hash: String,
+) -> Result<Option<RotatedRefreshToken>, Error> {
+    sqlx::query_as!(
+        RotatedRefreshToken,
+        "SELECT * FROM rotated_refresh_tokens WHERE token_hash = $1",
+        token_hash
+    )
+    .fetch_optional(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch rotated refresh token by hash: {}",
+            err
+        );
+        Error::UnexpectedError
+    })
+}
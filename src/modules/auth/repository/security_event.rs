@@ -0,0 +1,108 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::PgExecutor;
+use ulid::Ulid;
+
+#[derive(Serialize, Clone, Debug)]
+pub enum SecurityEventType {
+    #[serde(rename = "REFRESH_TOKEN_REUSE")]
+    RefreshTokenReuse,
+}
+
+impl ToString for SecurityEventType {
+    fn to_string(&self) -> String {
+        match self {
+            Self::RefreshTokenReuse => String::from("REFRESH_TOKEN_REUSE"),
+        }
+    }
+}
+
+// Events that need a human to look at them, `reviewed_at` is set once someone has
+#[derive(Serialize, Clone, Debug)]
+pub struct SecurityEvent {
+    pub id: String,
+    pub user_id: String,
+    pub r#type: String,
+    pub meta: Value,
+    pub reviewed_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+pub enum Error {
+    UnexpectedError,
+}
+
+pub struct CreateSecurityEventPayload {
+    pub user_id: String,
+    pub r#type: SecurityEventType,
+    pub meta: Value,
+}
+
+pub async fn create<'e, E: PgExecutor<'e>>(
+    e: E,
+    payload: CreateSecurityEventPayload,
+) -> Result<SecurityEvent, Error> {
+    sqlx::query_as!(
+        SecurityEvent,
+        "
+        INSERT INTO security_events (id, user_id, type, meta)
+        VALUES ($1, $2, $3, $4)
+        RETURNING *
+        ",
+        Ulid::new().to_string(),
+        payload.user_id,
+        payload.r#type.to_string(),
+        payload.meta
+    )
+    .fetch_one(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to create a security event for user with id {}: {}",
+            payload.user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_many_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<Vec<SecurityEvent>, Error> {
+    sqlx::query_as!(
+        SecurityEvent,
+        "SELECT * FROM security_events WHERE user_id = $1 ORDER BY created_at DESC",
+        user_id
+    )
+    .fetch_all(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch the security events of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+// NOTE: the meta holds the ip addresses and user agents involved, it goes along with the rest of the user's data
+pub async fn delete_many_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<(), Error> {
+    sqlx::query!("DELETE FROM security_events WHERE user_id = $1", user_id)
+        .execute(e)
+        .await
+        .map(|_| ())
+        .map_err(|err| {
+            tracing::error!(
+                "Error occurred while trying to delete the security events of user with id {}: {}",
+                user_id,
+                err
+            );
+            Error::UnexpectedError
+        })
+}
@@ -2,13 +2,16 @@ use chrono::{NaiveDateTime, Utc};
 use sqlx::PgExecutor;
 use ulid::Ulid;
 
+// NOTE: `refresh_token` holds a SHA-256 hash, the token itself is only ever handed to the client. Access tokens are signed and never stored
 pub struct Session {
     pub id: String,
     pub user_id: String,
-    pub access_token: String,
     pub refresh_token: String,
-    pub access_token_expires_at: NaiveDateTime,
     pub refresh_token_expires_at: NaiveDateTime,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
 }
 
 pub enum Error {
@@ -16,9 +19,10 @@ pub enum Error {
 }
 
 pub struct SessionCreationPayload {
-    pub access_token: String,
     pub refresh_token: String,
     pub user_id: String,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
 }
 
 pub async fn create<'e, E: PgExecutor<'e>>(
@@ -27,13 +31,13 @@ pub async fn create<'e, E: PgExecutor<'e>>(
 ) -> Result<Session, Error> {
     match sqlx::query_as!(
         Session,
-        "INSERT INTO sessions (id, user_id, access_token, refresh_token, access_token_expires_at, refresh_token_expires_at) VALUES ($1, $2, $3, $4, $5, $6) RETURNING *",
+        "INSERT INTO sessions (id, user_id, refresh_token, refresh_token_expires_at, user_agent, ip_address) VALUES ($1, $2, $3, $4, $5, $6) RETURNING *",
         Ulid::new().to_string(),
         payload.user_id,
-        payload.access_token,
         payload.refresh_token,
-        Utc::now().naive_utc() + chrono::Duration::days(15),
-        Utc::now().naive_utc() + chrono::Duration::days(30)
+        Utc::now().naive_utc() + chrono::Duration::days(30),
+        payload.user_agent,
+        payload.ip_address
     )
     .fetch_one(e)
     .await
@@ -51,85 +55,171 @@ pub async fn create<'e, E: PgExecutor<'e>>(
     }
 }
 
-pub async fn find_by_access_token<'e, E: PgExecutor<'e>>(
+// NOTE: locks the session until the transaction ends, a concurrent refresh with the same token waits and then finds
+// it already rotated
+pub async fn find_by_refresh_token<'e, E: PgExecutor<'e>>(
     e: E,
-    access_token: String,
+    refresh_token: String,
 ) -> Result<Option<Session>, Error> {
     sqlx::query_as!(
         Session,
-        "SELECT * FROM sessions WHERE access_token = $1",
-        access_token
+        "SELECT * FROM sessions WHERE refresh_token = $1 FOR UPDATE",
+        refresh_token
     )
     .fetch_optional(e)
     .await
     .map_err(|err| {
         tracing::error!(
-            "Error occurred while fetching session with access_token {}: {}",
-            access_token,
+            "Error occurred while fetching session with refresh_token {}: {}",
+            refresh_token,
             err
         );
         Error::UnexpectedError
     })
 }
 
-pub async fn find_by_refresh_token<'e, E: PgExecutor<'e>>(
+pub async fn find_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<Option<Session>, Error> {
+    sqlx::query_as!(Session, "SELECT * FROM sessions WHERE id = $1", id)
+        .fetch_optional(e)
+        .await
+        .map_err(|err| {
+            tracing::error!(
+                "Error occurred while fetching session with id {}: {}",
+                id,
+                err
+            );
+            Error::UnexpectedError
+        })
+}
+
+pub async fn find_many_active_by_user_id<'e, E: PgExecutor<'e>>(
     e: E,
-    refresh_token: String,
-) -> Result<Option<Session>, Error> {
+    user_id: String,
+) -> Result<Vec<Session>, Error> {
     sqlx::query_as!(
         Session,
-        "SELECT * FROM sessions WHERE refresh_token = $1",
-        refresh_token
+        "
+        SELECT * FROM sessions
+        WHERE
+            user_id = $1
+            AND refresh_token_expires_at > NOW()
+        ORDER BY COALESCE(updated_at, created_at) DESC
+        ",
+        user_id
     )
-    .fetch_optional(e)
+    .fetch_all(e)
     .await
     .map_err(|err| {
         tracing::error!(
-            "Error occurred while fetching session with refresh_token {}: {}",
-            refresh_token,
+            "Error occurred while fetching active sessions for user with id {}: {}",
+            user_id,
             err
         );
         Error::UnexpectedError
     })
 }
 
-pub struct UpdateSessionPayload {
-    pub access_token: String,
+pub struct RotateSessionTokensPayload {
+    pub previous_refresh_token: String,
     pub refresh_token: String,
+    pub ip_address: Option<String>,
 }
 
-pub async fn update_by_id<'e, E: PgExecutor<'e>>(
+// NOTE: only rotates if the refresh token is still the one we looked up, so two concurrent refreshes can't both win
+pub async fn rotate_tokens_by_id<'e, E: PgExecutor<'e>>(
     e: E,
     id: String,
-    payload: UpdateSessionPayload,
-) -> Result<Session, Error> {
+    payload: RotateSessionTokensPayload,
+) -> Result<Option<Session>, Error> {
     sqlx::query_as!(
         Session,
         "
         UPDATE sessions
         SET
-            access_token = $1,
-            refresh_token = $2,
-            access_token_expires_at = $3,
-            refresh_token_expires_at = $4
+            refresh_token = $1,
+            refresh_token_expires_at = $2,
+            ip_address = COALESCE($3, ip_address),
+            updated_at = NOW()
         WHERE
-            id = $5
+            id = $4
+            AND refresh_token = $5
         RETURNING *
         ",
-        payload.access_token,
         payload.refresh_token,
-        Utc::now().naive_utc() + chrono::Duration::days(15),
         Utc::now().naive_utc() + chrono::Duration::days(30),
-        id
+        payload.ip_address,
+        id,
+        payload.previous_refresh_token
     )
-    .fetch_one(e)
+    .fetch_optional(e)
     .await
     .map_err(|err| {
         tracing::error!(
-            "Error occurred while trying to update session by id: {} {}",
+            "Error occurred while trying to rotate tokens for session by id: {} {}",
             id,
             err
         );
         Error::UnexpectedError
     })
 }
+
+// NOTE: push tokens registered from a session are removed along with it by the `ON DELETE CASCADE` on `push_tokens.session_id`
+pub async fn delete_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<(), Error> {
+    sqlx::query!("DELETE FROM sessions WHERE id = $1", id)
+        .execute(e)
+        .await
+        .map(|_| ())
+        .map_err(|err| {
+            tracing::error!(
+                "Error occurred while trying to delete session by id {}: {}",
+                id,
+                err
+            );
+            Error::UnexpectedError
+        })
+}
+
+// Returns the ids of the deleted sessions so that their access tokens can be revoked
+pub async fn delete_many_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<Vec<String>, Error> {
+    sqlx::query!(
+        "DELETE FROM sessions WHERE user_id = $1 RETURNING id",
+        user_id
+    )
+    .fetch_all(e)
+    .await
+    .map(|records| records.into_iter().map(|record| record.id).collect())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to delete sessions for user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn delete_many_by_user_id_except<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+    session_id: String,
+) -> Result<Vec<String>, Error> {
+    sqlx::query!(
+        "DELETE FROM sessions WHERE user_id = $1 AND id != $2 RETURNING id",
+        user_id,
+        session_id
+    )
+    .fetch_all(e)
+    .await
+    .map(|records| records.into_iter().map(|record| record.id).collect())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to delete other sessions for user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
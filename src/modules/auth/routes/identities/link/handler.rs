@@ -0,0 +1,25 @@
+use super::service::service;
+use super::types::request;
+use crate::{modules::auth::middleware::Auth, types::Context};
+use axum::{
+    extract::{Json, Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+pub async fn handler(
+    State(ctx): State<Arc<Context>>,
+    auth: Auth,
+    Path(provider): Path<request::Provider>,
+    Json(body): Json<request::Body>,
+) -> impl IntoResponse {
+    service(
+        ctx,
+        request::Payload {
+            auth,
+            provider,
+            body,
+        },
+    )
+    .await
+}
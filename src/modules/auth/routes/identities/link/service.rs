@@ -0,0 +1,48 @@
+use super::types::{request, response};
+use crate::{
+    modules::auth::{repository::identity, service},
+    types::Context,
+};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let provider = identity::IdentityProvider::from(payload.provider);
+
+    let claims = service::id_token::verify(&ctx, &provider, &payload.body.id_token)
+        .await
+        .map_err(|err| match err {
+            service::id_token::Error::ProviderNotConfigured => {
+                response::Error::ProviderNotSupported
+            }
+            service::id_token::Error::ExpiredToken => response::Error::ExpiredIdToken,
+            service::id_token::Error::InvalidToken => response::Error::InvalidIdToken,
+            service::id_token::Error::UnexpectedError => response::Error::FailedToLinkIdentity,
+        })?;
+
+    if let Some(existing_identity) = identity::find_by_provider_and_subject(
+        &ctx.db_conn.pool,
+        provider.clone(),
+        claims.sub.clone(),
+    )
+    .await
+    .map_err(|_| response::Error::FailedToLinkIdentity)?
+    {
+        return match existing_identity.user_id == payload.auth.user.id {
+            true => Ok(response::Success::Identity(existing_identity)),
+            false => Err(response::Error::IdentityInUse),
+        };
+    }
+
+    identity::create(
+        &ctx.db_conn.pool,
+        identity::CreateIdentityPayload {
+            user_id: payload.auth.user.id,
+            provider,
+            subject: claims.sub,
+            email: claims.email,
+        },
+    )
+    .await
+    .map_err(|_| response::Error::FailedToLinkIdentity)
+    .map(response::Success::Identity)
+}
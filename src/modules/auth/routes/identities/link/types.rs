@@ -0,0 +1,91 @@
+pub mod request {
+    use crate::modules::auth::{middleware::Auth, repository::identity::IdentityProvider};
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Provider {
+        Google,
+        Apple,
+    }
+
+    impl From<Provider> for IdentityProvider {
+        fn from(value: Provider) -> Self {
+            match value {
+                Provider::Google => IdentityProvider::Google,
+                Provider::Apple => IdentityProvider::Apple,
+            }
+        }
+    }
+
+    #[derive(Deserialize)]
+    pub struct Body {
+        pub id_token: String,
+    }
+
+    pub struct Payload {
+        pub auth: Auth,
+        pub provider: Provider,
+        pub body: Body,
+    }
+}
+
+pub mod response {
+    use crate::modules::auth::repository::identity::Identity;
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        Identity(Identity),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::Identity(identity) => (StatusCode::OK, Json(json!(identity))).into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        ProviderNotSupported,
+        InvalidIdToken,
+        ExpiredIdToken,
+        IdentityInUse,
+        FailedToLinkIdentity,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::ProviderNotSupported => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Sign in with this provider is not supported" })),
+                )
+                    .into_response(),
+                Self::InvalidIdToken => (
+                    StatusCode::UNAUTHORIZED,
+                    Json(json!({ "error": "Invalid ID token" })),
+                )
+                    .into_response(),
+                Self::ExpiredIdToken => (
+                    StatusCode::UNAUTHORIZED,
+                    Json(json!({ "error": "Expired ID token" })),
+                )
+                    .into_response(),
+                Self::IdentityInUse => (
+                    StatusCode::CONFLICT,
+                    Json(json!({ "error": "This account is already linked to another user" })),
+                )
+                    .into_response(),
+                Self::FailedToLinkIdentity => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to link identity" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
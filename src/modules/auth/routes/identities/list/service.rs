@@ -0,0 +1,10 @@
+use super::types::{request, response};
+use crate::{modules::auth::repository, types::Context};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    repository::identity::find_many_by_user_id(&ctx.db_conn.pool, payload.auth.user.id)
+        .await
+        .map_err(|_| response::Error::FailedToFetchIdentities)
+        .map(response::Success::Identities)
+}
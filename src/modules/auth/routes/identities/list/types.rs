@@ -0,0 +1,45 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use crate::modules::auth::repository::identity::Identity;
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        Identities(Vec<Identity>),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::Identities(identities) => {
+                    (StatusCode::OK, Json(json!(identities))).into_response()
+                }
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToFetchIdentities,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToFetchIdentities => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch identities" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -0,0 +1,14 @@
+mod link;
+mod list;
+mod unlink;
+
+use crate::types::Context;
+use axum::routing::Router;
+use std::sync::Arc;
+
+pub fn get_router() -> Router<Arc<Context>> {
+    Router::new()
+        .nest("/", list::get_router())
+        .nest("/", link::get_router())
+        .nest("/", unlink::get_router())
+}
@@ -0,0 +1,18 @@
+use super::types::{request, response};
+use crate::{modules::auth::repository, types::Context};
+use std::sync::Arc;
+
+// NOTE: users can always fall back to their phone number, so every identity can be unlinked
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    match repository::identity::delete_by_id_and_user_id(
+        &ctx.db_conn.pool,
+        payload.id,
+        payload.auth.user.id,
+    )
+    .await
+    .map_err(|_| response::Error::FailedToUnlinkIdentity)?
+    {
+        true => Ok(response::Success::IdentityUnlinked),
+        false => Err(response::Error::IdentityNotFound),
+    }
+}
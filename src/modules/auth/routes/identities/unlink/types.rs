@@ -0,0 +1,53 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub auth: Auth,
+        pub id: String,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        IdentityUnlinked,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::IdentityUnlinked => (
+                    StatusCode::OK,
+                    Json(json!({ "message": "Identity unlinked" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        IdentityNotFound,
+        FailedToUnlinkIdentity,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::IdentityNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Identity not found" })),
+                )
+                    .into_response(),
+                Self::FailedToUnlinkIdentity => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to unlink identity" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -1,5 +1,8 @@
+mod identities;
 mod refresh;
+mod sessions;
 mod sign_in;
+mod sign_out;
 mod sign_up;
 mod verification;
 
@@ -13,4 +16,7 @@ pub fn get_router() -> Router<Arc<Context>> {
         .nest("/sign-up", sign_up::get_router())
         .nest("/verification", verification::get_router())
         .nest("/refresh", refresh::get_router())
+        .nest("/sign-out", sign_out::get_router())
+        .nest("/sessions", sessions::get_router())
+        .nest("/identities", identities::get_router())
 }
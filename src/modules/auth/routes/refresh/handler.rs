@@ -1,6 +1,6 @@
 use super::service::service;
 use super::types::request;
-use crate::types::Context;
+use crate::{modules::auth::service::auth::Device, types::Context};
 use axum::{
     extract::{Json, State},
     response::IntoResponse,
@@ -9,7 +9,8 @@ use std::sync::Arc;
 
 pub async fn handler(
     State(ctx): State<Arc<Context>>,
-    Json(payload): Json<request::Payload>,
+    device: Device,
+    Json(body): Json<request::Body>,
 ) -> impl IntoResponse {
-    service(ctx, payload).await
+    service(ctx, request::Payload { body, device }).await
 }
@@ -3,8 +3,14 @@ use crate::{modules::auth::service, types::Context};
 use std::sync::Arc;
 
 pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
-    service::auth::regenerate_tokens_for_session(ctx.clone(), payload.token)
+    service::auth::regenerate_tokens_for_session(ctx.clone(), payload.body.token, payload.device)
         .await
-        .map_err(|_| response::Error::FailedToRefreshTokens)
-        .map(|session| response::Success::Tokens((session.access_token, session.refresh_token)))
+        .map_err(|err| match err {
+            service::auth::Error::RefreshTokenReused => response::Error::RefreshTokenReused,
+            service::auth::Error::InvalidSession | service::auth::Error::ExpiredToken => {
+                response::Error::InvalidRefreshToken
+            }
+            service::auth::Error::UnexpectedError => response::Error::FailedToRefreshTokens,
+        })
+        .map(|tokens| response::Success::Tokens((tokens.access_token, tokens.refresh_token)))
 }
@@ -1,10 +1,16 @@
 pub mod request {
+    use crate::modules::auth::service::auth::Device;
     use serde::Deserialize;
 
     #[derive(Deserialize)]
-    pub struct Payload {
+    pub struct Body {
         pub token: String,
     }
+
+    pub struct Payload {
+        pub body: Body,
+        pub device: Device,
+    }
 }
 
 pub mod response {
@@ -32,6 +38,8 @@ pub mod response {
 
     pub enum Error {
         FailedToRefreshTokens,
+        InvalidRefreshToken,
+        RefreshTokenReused,
     }
 
     impl IntoResponse for Error {
@@ -42,6 +50,16 @@ pub mod response {
                     Json(json!({ "error": "Failed to refresh tokens" })),
                 )
                     .into_response(),
+                Self::InvalidRefreshToken => (
+                    StatusCode::UNAUTHORIZED,
+                    Json(json!({ "error": "Invalid or expired refresh token" })),
+                )
+                    .into_response(),
+                Self::RefreshTokenReused => (
+                    StatusCode::UNAUTHORIZED,
+                    Json(json!({ "error": "Session has been revoked, please sign in again" })),
+                )
+                    .into_response(),
             }
         }
     }
@@ -0,0 +1,59 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use crate::modules::auth::repository::session::Session;
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        // the sessions along with the id of the one making the request
+        Sessions(Vec<Session>, String),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                // NOTE: tokens are never sent back, only what the user needs to recognise the device
+                Self::Sessions(sessions, current_session_id) => (
+                    StatusCode::OK,
+                    Json(json!(sessions
+                        .into_iter()
+                        .map(|session| json!({
+                            "id": session.id,
+                            "user_agent": session.user_agent,
+                            "ip_address": session.ip_address,
+                            "is_current": session.id == current_session_id,
+                            "created_at": session.created_at,
+                            "updated_at": session.updated_at,
+                        }))
+                        .collect::<Vec<_>>())),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToFetchSessions,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToFetchSessions => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch sessions" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -0,0 +1,14 @@
+mod list;
+mod revoke;
+mod revoke_others;
+
+use crate::types::Context;
+use axum::routing::Router;
+use std::sync::Arc;
+
+pub fn get_router() -> Router<Arc<Context>> {
+    Router::new()
+        .nest("/", list::get_router())
+        .nest("/", revoke_others::get_router())
+        .nest("/", revoke::get_router())
+}
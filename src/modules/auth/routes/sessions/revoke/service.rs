@@ -0,0 +1,25 @@
+use super::types::{request, response};
+use crate::{
+    modules::auth::{repository, service},
+    types::Context,
+};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let session = repository::session::find_by_id(&ctx.db_conn.pool, payload.id)
+        .await
+        .map_err(|_| response::Error::FailedToFetchSession)?
+        .ok_or(response::Error::SessionNotFound)?;
+
+    if session.user_id != payload.auth.user.id {
+        return Err(response::Error::SessionNotFound);
+    }
+
+    repository::session::delete_by_id(&ctx.db_conn.pool, session.id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToRevokeSession)?;
+
+    service::auth::revoke_sessions(ctx, vec![session.id]).await;
+
+    Ok(response::Success::SessionRevoked)
+}
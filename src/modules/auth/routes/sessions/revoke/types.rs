@@ -0,0 +1,59 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub auth: Auth,
+        pub id: String,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        SessionRevoked,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::SessionRevoked => (
+                    StatusCode::OK,
+                    Json(json!({ "message": "Session revoked" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToFetchSession,
+        SessionNotFound,
+        FailedToRevokeSession,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToFetchSession => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch session" })),
+                )
+                    .into_response(),
+                Self::SessionNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Session not found" })),
+                )
+                    .into_response(),
+                Self::FailedToRevokeSession => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to revoke session" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -0,0 +1,20 @@
+use super::types::{request, response};
+use crate::{
+    modules::auth::{repository, service},
+    types::Context,
+};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let session_ids = repository::session::delete_many_by_user_id_except(
+        &ctx.db_conn.pool,
+        payload.auth.user.id,
+        payload.auth.session_id,
+    )
+    .await
+    .map_err(|_| response::Error::FailedToRevokeSessions)?;
+
+    service::auth::revoke_sessions(ctx, session_ids).await;
+
+    Ok(response::Success::SessionsRevoked)
+}
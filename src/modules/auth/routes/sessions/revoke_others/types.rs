@@ -0,0 +1,46 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        SessionsRevoked,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::SessionsRevoked => (
+                    StatusCode::OK,
+                    Json(json!({ "message": "Signed out of all other devices" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToRevokeSessions,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToRevokeSessions => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to revoke sessions" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -0,0 +1,24 @@
+use super::types::{request, response};
+use crate::{
+    modules::{auth::service, user},
+    types::Context,
+};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let user = user::repository::find_by_email(&ctx.db_conn.pool, payload.email.trim().to_string())
+        .await
+        .map_err(|_| response::Error::FailedToFetchUser)?
+        .filter(|user| user.deleted_at.is_none())
+        .ok_or(response::Error::UserNotFound)?;
+
+    if let Some(retry_after) = service::lockout::get_remaining_lock(ctx.clone(), &user).await {
+        return Err(response::Error::AccountLocked(retry_after));
+    }
+
+    service::magic_link::send(ctx, user)
+        .await
+        .map_err(|_| response::Error::FailedToSendMagicLink)?;
+
+    Ok(response::Success::CheckEmailForMagicLink)
+}
@@ -0,0 +1,65 @@
+pub mod request {
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    pub struct Payload {
+        pub email: String,
+    }
+}
+
+pub mod response {
+    use crate::modules::auth::middleware::too_many_requests;
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        CheckEmailForMagicLink,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::CheckEmailForMagicLink => (
+                    StatusCode::OK,
+                    Json(json!({"message": "Check your email for a sign in link"})),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToFetchUser,
+        UserNotFound,
+        FailedToSendMagicLink,
+        AccountLocked(u64),
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::AccountLocked(retry_after) => too_many_requests(
+                    retry_after,
+                    "Account temporarily locked due to too many failed attempts",
+                ),
+                Self::FailedToFetchUser => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch user" })),
+                )
+                    .into_response(),
+                Self::UserNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "User not found"})),
+                )
+                    .into_response(),
+                Self::FailedToSendMagicLink => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to send sign in link"})),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -1,6 +1,14 @@
-mod handler;
-mod router;
-mod service;
-mod types;
+mod email;
+mod phone;
+mod social;
 
-pub use router::get_router;
+use crate::types::Context;
+use axum::routing::Router;
+use std::sync::Arc;
+
+pub fn get_router() -> Router<Arc<Context>> {
+    Router::new()
+        .nest("/", phone::get_router())
+        .nest("/", email::get_router())
+        .nest("/", social::get_router())
+}
@@ -0,0 +1,42 @@
+use super::types::{request, response};
+use crate::{
+    modules::{auth::service, user},
+    types::Context,
+};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let user =
+        user::repository::find_by_phone_number(&ctx.db_conn.pool, payload.phone_number.clone())
+            .await
+            .map_err(|_| response::Error::FailedToFetchUser)?
+            .ok_or(response::Error::UserNotFound)?;
+
+    if let Some(retry_after) = service::lockout::get_remaining_lock(ctx.clone(), &user).await {
+        return Err(response::Error::AccountLocked(retry_after));
+    }
+
+    if !user.is_verified {
+        return Err(response::Error::UserNotVerified);
+    }
+
+    if user.deleted_at.is_some() {
+        return Err(response::Error::UserNotFound);
+    }
+
+    service::otp::send(
+        ctx.clone(),
+        user,
+        "auth.verification".to_string(),
+        payload.channel,
+    )
+    .await
+    .map_err(|err| match err {
+        service::otp::SendError::ResendCooldown(retry_after) => {
+            response::Error::OtpResendCooldown(retry_after)
+        }
+        _ => response::Error::FailedToSendOtp,
+    })?;
+
+    return Ok(response::Success::CheckPhoneForVerificationOtp);
+}
@@ -0,0 +1,25 @@
+use super::service::service;
+use super::types::request;
+use crate::{modules::auth::service::auth::Device, types::Context};
+use axum::{
+    extract::{Json, Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+pub async fn handler(
+    State(ctx): State<Arc<Context>>,
+    Path(provider): Path<request::Provider>,
+    device: Device,
+    Json(body): Json<request::Body>,
+) -> impl IntoResponse {
+    service(
+        ctx,
+        request::Payload {
+            provider,
+            body,
+            device,
+        },
+    )
+    .await
+}
@@ -0,0 +1,16 @@
+use super::handler;
+use crate::{
+    modules::auth::middleware,
+    types::{rate_limit, Context},
+};
+use axum::routing::{post, Router};
+use std::sync::Arc;
+
+pub fn get_router() -> Router<Arc<Context>> {
+    Router::new()
+        .route("/strategy/:provider", post(handler::handler))
+        .route_layer(axum::middleware::from_fn_with_state(
+            rate_limit::GROUP_AUTH_VERIFY,
+            middleware::rate_limit,
+        ))
+}
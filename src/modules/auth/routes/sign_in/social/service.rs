@@ -0,0 +1,191 @@
+use super::types::{request, response};
+use crate::{
+    modules::{
+        auth::{
+            repository::{identity, otp::OtpChannel},
+            service,
+        },
+        user::{self, repository::User},
+        wallet,
+    },
+    types::Context,
+};
+use sqlx::{Postgres, Transaction};
+use std::sync::Arc;
+use validator::Validate;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    payload.body.validate().map_err(|errors| {
+        tracing::warn!("Failed to validate payload: {errors}");
+        response::Error::FailedToValidate(errors)
+    })?;
+
+    let provider = identity::IdentityProvider::from(payload.provider);
+
+    let claims = service::id_token::verify(&ctx, &provider, &payload.body.id_token)
+        .await
+        .map_err(|err| match err {
+            service::id_token::Error::ProviderNotConfigured => {
+                response::Error::ProviderNotSupported
+            }
+            service::id_token::Error::ExpiredToken => response::Error::ExpiredIdToken,
+            service::id_token::Error::InvalidToken => response::Error::InvalidIdToken,
+            service::id_token::Error::UnexpectedError => response::Error::UnexpectedError,
+        })?;
+
+    let mut tx = ctx.db_conn.pool.begin().await.map_err(|err| {
+        tracing::error!("Failed to start database transaction: {}", err);
+        response::Error::UnexpectedError
+    })?;
+
+    let existing_identity =
+        identity::find_by_provider_and_subject(&mut *tx, provider.clone(), claims.sub.clone())
+            .await
+            .map_err(|_| response::Error::FailedToFetchUser)?;
+
+    let (user, is_new_user) = match existing_identity {
+        Some(existing_identity) => (
+            user::repository::find_by_id(&mut *tx, existing_identity.user_id)
+                .await
+                .map_err(|_| response::Error::FailedToFetchUser)?
+                .filter(|user| user.deleted_at.is_none())
+                .ok_or(response::Error::UserNotFound)?,
+            false,
+        ),
+        None => {
+            let existing_user = match claims.verified_email() {
+                Some(email) => user::repository::find_by_email(&mut *tx, email)
+                    .await
+                    .map_err(|_| response::Error::FailedToFetchUser)?
+                    .filter(|user| user.deleted_at.is_none()),
+                None => None,
+            };
+
+            // NOTE: the provider vouching for the email only links the accounts when the account's owner has proven it
+            // too, otherwise whoever signed up with someone else's email would get their sign ins. The user has to sign
+            // in and link the provider through `/auth/identities/link` instead
+            let (user, is_new_user) = match existing_user {
+                Some(user) if user.email_verified_at.is_some() => (user, false),
+                Some(_) => return Err(response::Error::SignInToLinkIdentity),
+                None => (create_user(&mut tx, &payload.body, &claims).await?, true),
+            };
+
+            identity::create(
+                &mut *tx,
+                identity::CreateIdentityPayload {
+                    user_id: user.id.clone(),
+                    provider,
+                    subject: claims.sub,
+                    email: claims.email,
+                },
+            )
+            .await
+            .map_err(|_| response::Error::FailedToLinkIdentity)?;
+
+            (user, is_new_user)
+        }
+    };
+
+    if let Some(retry_after) = service::lockout::get_remaining_lock(ctx.clone(), &user).await {
+        return Err(response::Error::AccountLocked(retry_after));
+    }
+
+    let tokens = service::auth::create_session(&ctx, &mut *tx, &user, payload.device)
+        .await
+        .map_err(|_| response::Error::FailedToCreateSession)?;
+
+    tx.commit().await.map_err(|err| {
+        tracing::error!("Failed to commit database transaction: {}", err);
+        response::Error::UnexpectedError
+    })?;
+
+    // NOTE: new users can sign in straight away, but they can't order anything until their phone number is verified
+    if is_new_user {
+        tokio::spawn(service::otp::send(
+            ctx.clone(),
+            user.clone(),
+            "auth.verification".to_string(),
+            OtpChannel::default(),
+        ));
+    }
+
+    if let Some(guest_token) = payload.body.guest_token {
+        service::auth::merge_guest_cart(ctx.clone(), user.id.clone(), guest_token).await;
+    }
+
+    Ok(response::Success::Tokens((
+        tokens.access_token,
+        tokens.refresh_token,
+        user.is_verified,
+    )))
+}
+
+async fn create_user(
+    tx: &mut Transaction<'_, Postgres>,
+    body: &request::Body,
+    claims: &service::id_token::Claims,
+) -> Result<User, response::Error> {
+    let email = claims
+        .verified_email()
+        .ok_or(response::Error::EmailRequired)?;
+    let phone_number = body
+        .phone_number
+        .clone()
+        .ok_or(response::Error::PhoneNumberRequired)?;
+    let first_name = body
+        .first_name
+        .clone()
+        .or(claims.given_name.clone())
+        .ok_or(response::Error::NameRequired)?;
+    let last_name = body
+        .last_name
+        .clone()
+        .or(claims.family_name.clone())
+        .ok_or(response::Error::NameRequired)?;
+
+    user::repository::find_by_email_or_phone_number(
+        &mut **tx,
+        user::repository::FindByEmailOrPhoneNumber {
+            email: email.clone(),
+            phone_number: phone_number.clone(),
+        },
+    )
+    .await
+    .map_err(|_| response::Error::FailedToFetchUser)?
+    .map(|user| -> Result<(), _> {
+        if user.email == email {
+            return Err(response::Error::EmailAlreadyInUse);
+        }
+        return Err(response::Error::PhoneNumberAlreadyInUse);
+    })
+    .transpose()?;
+
+    let user = user::repository::create(
+        &mut **tx,
+        user::repository::CreateUserPayload {
+            email,
+            phone_number,
+            first_name,
+            last_name,
+        },
+    )
+    .await
+    .map_err(|_| response::Error::SignupFailed)?;
+
+    // NOTE: the account's email is the one the provider verified
+    user::repository::verify_email_by_id(&mut **tx, user.id.clone())
+        .await
+        .map_err(|_| response::Error::SignupFailed)?;
+
+    wallet::repository::create(
+        &mut **tx,
+        wallet::repository::CreateWalletPayload {
+            owner_id: user.id.clone(),
+            is_kitchen_wallet: false,
+        },
+    )
+    .await
+    .map_err(|_| response::Error::FailedToCreateWallet)?;
+
+    Ok(user)
+}
@@ -0,0 +1,198 @@
+pub mod request {
+    use std::borrow::Cow;
+
+    use crate::modules::auth::{repository::identity::IdentityProvider, service::auth::Device};
+    use regex::Regex;
+    use serde::Deserialize;
+    use validator::{Validate, ValidationError};
+
+    fn validate_phone_number(phone_number: &str) -> Result<(), ValidationError> {
+        let regex = Regex::new(r"^\+234\d{10}$").expect("Invalid phone number regex");
+        match regex.is_match(phone_number) {
+            true => Ok(()),
+            false => Err(
+                ValidationError::new("INVALID_PHONE_NUMBER").with_message(Cow::from(
+                    r"Phone number must be a nigerian phone number in international format (e.g: +234...)",
+                )),
+            ),
+        }
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Provider {
+        Google,
+        Apple,
+    }
+
+    impl From<Provider> for IdentityProvider {
+        fn from(value: Provider) -> Self {
+            match value {
+                Provider::Google => IdentityProvider::Google,
+                Provider::Apple => IdentityProvider::Apple,
+            }
+        }
+    }
+
+    // NOTE: the phone number and names are only needed the first time someone signs in, when their account is created
+    #[derive(Deserialize, Validate)]
+    pub struct Body {
+        pub id_token: String,
+        #[validate(custom(code = "INVALID_PHONE_NUMBER", function = "validate_phone_number"))]
+        pub phone_number: Option<String>,
+        pub first_name: Option<String>,
+        pub last_name: Option<String>,
+        pub guest_token: Option<String>,
+    }
+
+    pub struct Payload {
+        pub provider: Provider,
+        pub body: Body,
+        pub device: Device,
+    }
+}
+
+pub mod response {
+    use crate::modules::auth::middleware::too_many_requests;
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+    use validator::ValidationErrors;
+
+    pub enum Success {
+        // access token, refresh token and whether the user's phone number has been verified
+        Tokens((String, String, bool)),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::Tokens((access_token, refresh_token, is_verified)) => (
+                    StatusCode::OK,
+                    Json(json!({
+                        "access_token": access_token,
+                        "refresh_token": refresh_token,
+                        "is_verified": is_verified,
+                    })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        ProviderNotSupported,
+        InvalidIdToken,
+        ExpiredIdToken,
+        FailedToFetchUser,
+        UserNotFound,
+        EmailRequired,
+        PhoneNumberRequired,
+        NameRequired,
+        EmailAlreadyInUse,
+        SignInToLinkIdentity,
+        PhoneNumberAlreadyInUse,
+        SignupFailed,
+        FailedToCreateWallet,
+        FailedToLinkIdentity,
+        FailedToCreateSession,
+        AccountLocked(u64),
+        FailedToValidate(ValidationErrors),
+        UnexpectedError,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::ProviderNotSupported => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Sign in with this provider is not supported" })),
+                )
+                    .into_response(),
+                Self::InvalidIdToken => (
+                    StatusCode::UNAUTHORIZED,
+                    Json(json!({ "error": "Invalid ID token" })),
+                )
+                    .into_response(),
+                Self::ExpiredIdToken => (
+                    StatusCode::UNAUTHORIZED,
+                    Json(json!({ "error": "Expired ID token" })),
+                )
+                    .into_response(),
+                Self::FailedToFetchUser => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch user" })),
+                )
+                    .into_response(),
+                Self::UserNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "User not found"})),
+                )
+                    .into_response(),
+                Self::EmailRequired => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "The account has no verified email address" })),
+                )
+                    .into_response(),
+                Self::PhoneNumberRequired => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "A phone number is required to create an account" })),
+                )
+                    .into_response(),
+                Self::NameRequired => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "A first and last name are required to create an account" })),
+                )
+                    .into_response(),
+                Self::EmailAlreadyInUse => (
+                    StatusCode::CONFLICT,
+                    Json(json!({ "error": "Email already in use" })),
+                )
+                    .into_response(),
+                Self::SignInToLinkIdentity => (
+                    StatusCode::CONFLICT,
+                    Json(json!({ "error": "An account with this email already exists, sign in to link this provider to it" })),
+                )
+                    .into_response(),
+                Self::PhoneNumberAlreadyInUse => (
+                    StatusCode::CONFLICT,
+                    Json(json!({ "error": "Phone number already in use" })),
+                )
+                    .into_response(),
+                Self::SignupFailed => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Sign up failed!" })),
+                )
+                    .into_response(),
+                Self::FailedToCreateWallet => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to create wallet" })),
+                )
+                    .into_response(),
+                Self::FailedToLinkIdentity => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to link identity" })),
+                )
+                    .into_response(),
+                Self::FailedToCreateSession => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to create session" })),
+                )
+                    .into_response(),
+                Self::AccountLocked(retry_after) => too_many_requests(
+                    retry_after,
+                    "Account temporarily locked due to too many failed attempts",
+                ),
+                Self::FailedToValidate(errors) => {
+                    (StatusCode::BAD_REQUEST, Json(json!({ "errors": errors }))).into_response()
+                }
+                Self::UnexpectedError => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Sorry an error occurred" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -0,0 +1,17 @@
+use super::types::{request, response};
+use crate::{
+    modules::auth::{repository, service},
+    types::Context,
+};
+use std::sync::Arc;
+
+// NOTE: deleting the session also removes the push tokens registered from it, so the device stops getting notifications
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    repository::session::delete_by_id(&ctx.db_conn.pool, payload.auth.session_id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToSignOut)?;
+
+    service::auth::revoke_sessions(ctx, vec![payload.auth.session_id]).await;
+
+    Ok(response::Success::SignedOut)
+}
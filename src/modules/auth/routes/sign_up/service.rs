@@ -3,7 +3,7 @@ use super::{
     types::{request, response},
 };
 use crate::{
-    modules::{user, wallet},
+    modules::{auth::repository::otp::OtpChannel, user, wallet},
     types::Context,
 };
 use std::sync::Arc;
@@ -76,6 +76,7 @@ pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::
         ctx.clone(),
         user,
         "auth.verification".to_string(),
+        OtpChannel::default(),
     ));
 
     return Ok(response::Success::CheckPhoneForVerificationOtp);
@@ -1,5 +1,6 @@
 mod router;
 mod send_otp;
+mod verify_magic_link;
 mod verify_otp;
 
 pub use router::get_router;
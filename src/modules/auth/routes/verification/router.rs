@@ -1,4 +1,5 @@
 use super::send_otp;
+use super::verify_magic_link;
 use super::verify_otp;
 
 use crate::types::Context;
@@ -9,4 +10,5 @@ pub fn get_router() -> Router<Arc<Context>> {
     Router::new()
         .nest("/send-otp", send_otp::get_router())
         .nest("/verify-otp", verify_otp::get_router())
+        .nest("/verify-magic-link", verify_magic_link::get_router())
 }
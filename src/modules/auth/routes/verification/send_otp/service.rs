@@ -12,15 +12,26 @@ pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::
             .map_err(|_| response::Error::FailedToFetchUser)?
             .ok_or(response::Error::UserNotFound)?;
 
+    if let Some(retry_after) = service::lockout::get_remaining_lock(ctx.clone(), &user).await {
+        return Err(response::Error::AccountLocked(retry_after));
+    }
+
     if user.is_verified {
         return Err(response::Error::UserAlreadyVerified);
     }
 
-    service::otp::send(ctx.clone(), user, "auth.verification".to_string())
-        .await
-        .map(|_| response::Success::CheckPhoneForVerificationOtp)
-        .map_err(|err| match err {
-            service::otp::SendError::NotExpired => response::Error::OtpNotExpired,
-            _ => response::Error::FailedToSendOtp,
-        })
+    service::otp::send(
+        ctx.clone(),
+        user,
+        "auth.verification".to_string(),
+        payload.channel,
+    )
+    .await
+    .map(|_| response::Success::CheckPhoneForVerificationOtp)
+    .map_err(|err| match err {
+        service::otp::SendError::ResendCooldown(retry_after) => {
+            response::Error::OtpResendCooldown(retry_after)
+        }
+        _ => response::Error::FailedToSendOtp,
+    })
 }
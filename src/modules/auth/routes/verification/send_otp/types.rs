@@ -1,13 +1,17 @@
 pub mod request {
+    use crate::modules::auth::repository::otp::OtpChannel;
     use serde::Deserialize;
 
     #[derive(Deserialize)]
     pub struct Payload {
         pub phone_number: String,
+        #[serde(default)]
+        pub channel: OtpChannel,
     }
 }
 
 pub mod response {
+    use crate::modules::auth::middleware::too_many_requests;
     use axum::{extract::Json, http::StatusCode, response::IntoResponse};
     use serde_json::json;
 
@@ -31,18 +35,21 @@ pub mod response {
         FailedToFetchUser,
         UserNotFound,
         FailedToSendOtp,
-        OtpNotExpired,
+        OtpResendCooldown(u64),
+        AccountLocked(u64),
         UserAlreadyVerified,
     }
 
     impl IntoResponse for Error {
         fn into_response(self) -> axum::response::Response {
             match self {
-                Self::OtpNotExpired => (
-                    StatusCode::BAD_REQUEST,
-                    Json(json!({"error": "OTP not expired"})),
-                )
-                    .into_response(),
+                Self::OtpResendCooldown(retry_after) => {
+                    too_many_requests(retry_after, "Please wait before requesting another OTP")
+                }
+                Self::AccountLocked(retry_after) => too_many_requests(
+                    retry_after,
+                    "Account temporarily locked due to too many failed attempts",
+                ),
                 Error::FailedToFetchUser => (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     Json(json!({ "error": "Failed to fetch user" })),
@@ -0,0 +1,16 @@
+use super::service::service;
+use super::types::request;
+use crate::{modules::auth::service::auth::Device, types::Context};
+use axum::{
+    extract::{Json, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+pub async fn handler(
+    State(ctx): State<Arc<Context>>,
+    device: Device,
+    Json(body): Json<request::Body>,
+) -> impl IntoResponse {
+    service(ctx, request::Payload { body, device }).await
+}
@@ -0,0 +1,45 @@
+use super::types::{request, response};
+use crate::{modules::auth::service, types::Context};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let mut tx = ctx
+        .db_conn
+        .pool
+        .begin()
+        .await
+        .map_err(|_| response::Error::UnexpectedError)?;
+
+    let user = service::magic_link::verify(&mut tx, payload.body.token)
+        .await
+        .map_err(|err| match err {
+            service::magic_link::VerificationError::ExpiredLink => response::Error::ExpiredLink,
+            service::magic_link::VerificationError::InvalidLink => response::Error::InvalidLink,
+            service::magic_link::VerificationError::UnexpectedError => {
+                response::Error::UnexpectedError
+            }
+        })?;
+
+    if let Some(retry_after) = service::lockout::get_remaining_lock(ctx.clone(), &user).await {
+        return Err(response::Error::AccountLocked(retry_after));
+    }
+
+    let tokens = service::auth::create_session(&ctx, &mut *tx, &user, payload.device)
+        .await
+        .map_err(|_| response::Error::FailedToCreateSession)?;
+
+    tx.commit().await.map_err(|err| {
+        tracing::error!("Failed to commit transaction: {}", err);
+        response::Error::UnexpectedError
+    })?;
+
+    if let Some(guest_token) = payload.body.guest_token {
+        service::auth::merge_guest_cart(ctx.clone(), user.id, guest_token).await;
+    }
+
+    Ok(response::Success::Tokens((
+        tokens.access_token,
+        tokens.refresh_token,
+        user.is_verified,
+    )))
+}
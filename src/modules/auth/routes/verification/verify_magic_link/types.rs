@@ -0,0 +1,83 @@
+pub mod request {
+    use crate::modules::auth::service::auth::Device;
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    pub struct Body {
+        pub token: String,
+        pub guest_token: Option<String>,
+    }
+
+    pub struct Payload {
+        pub body: Body,
+        pub device: Device,
+    }
+}
+
+pub mod response {
+    use crate::modules::auth::middleware::too_many_requests;
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        // access token, refresh token and whether the user's phone number has been verified
+        Tokens((String, String, bool)),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::Tokens((access_token, refresh_token, is_verified)) => (
+                    StatusCode::OK,
+                    Json(json!({
+                        "access_token": access_token,
+                        "refresh_token": refresh_token,
+                        "is_verified": is_verified,
+                    })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        InvalidLink,
+        ExpiredLink,
+        AccountLocked(u64),
+        FailedToCreateSession,
+        UnexpectedError,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::InvalidLink => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Invalid sign in link" })),
+                )
+                    .into_response(),
+                Self::ExpiredLink => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Sign in link has expired" })),
+                )
+                    .into_response(),
+                Self::AccountLocked(retry_after) => too_many_requests(
+                    retry_after,
+                    "Account temporarily locked due to too many failed attempts",
+                ),
+                Self::FailedToCreateSession => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to create session" })),
+                )
+                    .into_response(),
+                Self::UnexpectedError => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Sorry an error occurred" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -13,22 +13,49 @@ pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::
         .await
         .map_err(|_| response::Error::UnexpectedError)?;
 
-    let user = user::repository::find_by_phone_number(&mut *tx, payload.phone_number.clone())
+    let user = user::repository::find_by_phone_number(&mut *tx, payload.body.phone_number.clone())
         .await
         .map_err(|_| response::Error::FailedToFetchUser)?
         .ok_or(response::Error::UserNotFound)?;
 
-    service::otp::verify(
+    if let Some(retry_after) = service::lockout::get_remaining_lock(ctx.clone(), &user).await {
+        return Err(response::Error::AccountLocked(retry_after));
+    }
+
+    if let Err(err) = service::otp::verify(
         ctx.clone(),
         &mut tx,
         user.clone(),
         "auth.verification".to_string(),
-        payload.otp.clone(),
+        payload.body.otp.clone(),
     )
     .await
-    .map_err(|_| response::Error::InvalidOrExpiredOtp)?;
+    {
+        if let service::otp::VerificationError::InvalidOtp
+        | service::otp::VerificationError::TooManyAttempts = err
+        {
+            // NOTE: keeps the failed attempt, see `otp::verify`
+            if let Err(err) = tx.commit().await {
+                tracing::error!("Failed to commit transaction: {}", err);
+            }
+
+            if let Some(retry_after) =
+                service::lockout::record_failed_attempt(ctx.clone(), &user).await
+            {
+                return Err(response::Error::AccountLocked(retry_after));
+            }
+        }
+
+        return Err(match err {
+            service::otp::VerificationError::TooManyAttempts => response::Error::TooManyOtpAttempts,
+            service::otp::VerificationError::UnexpectedError => response::Error::UnexpectedError,
+            _ => response::Error::InvalidOrExpiredOtp,
+        });
+    }
 
-    user::repository::verify_by_phone_number(&mut *tx, payload.phone_number)
+    service::lockout::clear_failed_attempts(ctx.clone(), &user).await;
+
+    user::repository::verify_by_phone_number(&mut *tx, payload.body.phone_number)
         .await
         .map_err(|_| response::Error::OtpVerificationFailed)?;
 
@@ -38,15 +65,21 @@ pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::
             .map_err(|_| response::Error::UnexpectedError)?;
     }
 
-    let session = service::auth::create_session(&mut *tx, user.id)
+    let tokens = service::auth::create_session(&ctx, &mut *tx, &user, payload.device)
         .await
         .map_err(|_| response::Error::FailedToCreateSession)?;
 
-    tx.commit()
-        .await
-        .map(|_| response::Success::Tokens((session.access_token, session.refresh_token)))
-        .map_err(|err| {
-            tracing::error!("Failed to commit transaction: {}", err);
-            response::Error::UnexpectedError
-        })
+    tx.commit().await.map_err(|err| {
+        tracing::error!("Failed to commit transaction: {}", err);
+        response::Error::UnexpectedError
+    })?;
+
+    if let Some(guest_token) = payload.body.guest_token {
+        service::auth::merge_guest_cart(ctx.clone(), user.id, guest_token).await;
+    }
+
+    Ok(response::Success::Tokens((
+        tokens.access_token,
+        tokens.refresh_token,
+    )))
 }
@@ -1,14 +1,22 @@
 pub mod request {
+    use crate::modules::auth::service::auth::Device;
     use serde::Deserialize;
 
     #[derive(Deserialize)]
-    pub struct Payload {
+    pub struct Body {
         pub phone_number: String,
         pub otp: String,
+        pub guest_token: Option<String>,
+    }
+
+    pub struct Payload {
+        pub body: Body,
+        pub device: Device,
     }
 }
 
 pub mod response {
+    use crate::modules::auth::middleware::too_many_requests;
     use axum::{extract::Json, http::StatusCode, response::IntoResponse};
     use serde_json::json;
 
@@ -36,6 +44,8 @@ pub mod response {
         UserNotFound,
         FailedToCreateSession,
         InvalidOrExpiredOtp,
+        TooManyOtpAttempts,
+        AccountLocked(u64),
         OtpVerificationFailed,
         UnexpectedError,
     }
@@ -68,6 +78,15 @@ pub mod response {
                     Json(json!({ "error" : "Invalid or expired OTP"})),
                 )
                     .into_response(),
+                Error::TooManyOtpAttempts => (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    Json(json!({ "error": "Too many attempts, please request a new OTP" })),
+                )
+                    .into_response(),
+                Error::AccountLocked(retry_after) => too_many_requests(
+                    retry_after,
+                    "Account temporarily locked due to too many failed attempts",
+                ),
                 Error::UnexpectedError => (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     Json(json!({ "error": "Sorry an error occurred" })),
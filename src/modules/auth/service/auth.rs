@@ -1,9 +1,19 @@
 use chrono::Utc;
+use rand::RngCore;
+use serde_json::json;
+use sha2::Digest;
 use sqlx::{PgExecutor, Postgres, Transaction};
-use ulid::Ulid;
 
 use super::super::repository;
-use crate::{modules::auth::repository::session::Session, types::Context};
+use super::token;
+use crate::{
+    modules::{
+        auth::repository::session::Session,
+        cart,
+        user::{self, repository::User},
+    },
+    types::Context,
+};
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -11,71 +21,268 @@ pub enum Error {
     UnexpectedError,
     InvalidSession,
     ExpiredToken,
+    RefreshTokenReused,
 }
 
 type Result<T> = std::result::Result<T, Error>;
 
-pub async fn create_session<'e, E: PgExecutor<'e>>(e: E, user_id: String) -> Result<Session> {
-    let access_token = Ulid::new().to_string();
-    let refresh_token = Ulid::new().to_string();
-    repository::session::create(
+// Metadata about the device a session was created from, see `middleware::Device`'s extractor
+#[derive(Clone, Debug, Default)]
+pub struct Device {
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+// The plaintext refresh token only exists here, the session itself only knows its hash
+pub struct SessionTokens {
+    pub session: Session,
+    pub access_token: String,
+    pub refresh_token: String,
+}
+
+pub fn generate_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    base16ct::lower::encode_string(&bytes)
+}
+
+pub fn hash_token(token: &str) -> String {
+    let mut hasher = sha2::Sha256::new();
+    hasher.update(token);
+    base16ct::lower::encode_string(&hasher.finalize())
+}
+
+fn get_revoked_session_key(session_id: &str) -> String {
+    format!("auth:revoked_session:{}", session_id)
+}
+
+pub async fn create_session<'e, E: PgExecutor<'e>>(
+    ctx: &Context,
+    e: E,
+    user: &User,
+    device: Device,
+) -> Result<SessionTokens> {
+    let refresh_token = generate_token();
+
+    let session = repository::session::create(
         e,
         repository::session::SessionCreationPayload {
-            user_id,
-            access_token,
-            refresh_token,
+            user_id: user.id.clone(),
+            refresh_token: hash_token(&refresh_token),
+            user_agent: device.user_agent,
+            ip_address: device.ip_address,
         },
     )
     .await
-    .map_err(|_| Error::UnexpectedError)
+    .map_err(|_| Error::UnexpectedError)?;
+
+    let access_token = token::sign(ctx, user.id.clone(), user.role.clone(), session.id.clone());
+
+    Ok(SessionTokens {
+        session,
+        access_token,
+        refresh_token,
+    })
 }
 
+// NOTE: a session is a refresh token family, every rotated token is kept so that replaying one revokes the whole family
 pub async fn regenerate_tokens_for_session(
     ctx: Arc<Context>,
     refresh_token: String,
-) -> Result<Session> {
-    let session = verify_refresh_token(ctx.clone(), refresh_token)
+    device: Device,
+) -> Result<SessionTokens> {
+    let refresh_token_hash = hash_token(&refresh_token);
+
+    let mut tx = ctx.db_conn.pool.begin().await.map_err(|err| {
+        tracing::error!("Failed to start database transaction: {}", err);
+        Error::UnexpectedError
+    })?;
+
+    let session =
+        match repository::session::find_by_refresh_token(&mut *tx, refresh_token_hash.clone())
+            .await
+            .map_err(|_| Error::UnexpectedError)?
+        {
+            Some(session) => session,
+            None => {
+                return match repository::refresh_token::find_by_token_hash(
+                    &mut *tx,
+                    refresh_token_hash,
+                )
+                .await
+                .map_err(|_| Error::UnexpectedError)?
+                {
+                    Some(rotated_refresh_token) => {
+                        revoke_reused_family(ctx, tx, rotated_refresh_token, device).await
+                    }
+                    None => Err(Error::InvalidSession),
+                }
+            }
+        };
+
+    if session.refresh_token_expires_at < Utc::now().naive_utc() {
+        return Err(Error::ExpiredToken);
+    };
+
+    let user = user::repository::find_by_id(&mut *tx, session.user_id.clone())
         .await
-        .map_err(|_| Error::UnexpectedError)?;
-    tracing::info!("got past here xxxy");
+        .map_err(|_| Error::UnexpectedError)?
+        .filter(|user| user.deleted_at.is_none())
+        .ok_or(Error::InvalidSession)?;
+
+    repository::refresh_token::create(
+        &mut *tx,
+        repository::refresh_token::CreateRotatedRefreshTokenPayload {
+            session_id: session.id.clone(),
+            token_hash: refresh_token_hash.clone(),
+        },
+    )
+    .await
+    .map_err(|_| Error::UnexpectedError)?;
 
-    let access_token = Ulid::new().to_string();
-    let refresh_token = Ulid::new().to_string();
+    let refresh_token = generate_token();
 
-    repository::session::update_by_id(
-        &ctx.db_conn.pool,
+    let session = repository::session::rotate_tokens_by_id(
+        &mut *tx,
         session.id,
-        repository::session::UpdateSessionPayload {
-            access_token,
-            refresh_token,
+        repository::session::RotateSessionTokensPayload {
+            previous_refresh_token: refresh_token_hash,
+            refresh_token: hash_token(&refresh_token),
+            ip_address: device.ip_address,
         },
     )
     .await
-    .map_err(|_| Error::UnexpectedError)
+    .map_err(|_| Error::UnexpectedError)?
+    .ok_or(Error::InvalidSession)?;
+
+    tx.commit().await.map_err(|err| {
+        tracing::error!("Failed to commit database transaction: {}", err);
+        Error::UnexpectedError
+    })?;
+
+    let access_token = token::sign(&ctx, user.id, user.role, session.id.clone());
+
+    Ok(SessionTokens {
+        session,
+        access_token,
+        refresh_token,
+    })
 }
 
-pub async fn verify_access_token(ctx: Arc<Context>, access_token: String) -> Result<Session> {
-    let session = repository::session::find_by_access_token(&ctx.db_conn.pool, access_token)
+async fn revoke_reused_family(
+    ctx: Arc<Context>,
+    mut tx: Transaction<'_, Postgres>,
+    rotated_refresh_token: repository::refresh_token::RotatedRefreshToken,
+    device: Device,
+) -> Result<SessionTokens> {
+    let session =
+        match repository::session::find_by_id(&mut *tx, rotated_refresh_token.session_id.clone())
+            .await
+            .map_err(|_| Error::UnexpectedError)?
+        {
+            Some(session) => session,
+            None => return Err(Error::RefreshTokenReused),
+        };
+
+    tracing::warn!(
+        "Refresh token reuse detected for session {}, revoking it",
+        session.id
+    );
+
+    repository::session::delete_by_id(&mut *tx, session.id.clone())
         .await
-        .map_err(|_| Error::UnexpectedError)?
-        .ok_or(Error::InvalidSession)?;
+        .map_err(|_| Error::UnexpectedError)?;
 
-    if session.access_token_expires_at < Utc::now().naive_utc() {
-        return Err(Error::ExpiredToken);
-    };
+    let session_id = session.id.clone();
 
-    Ok(session)
+    repository::security_event::create(
+        &mut *tx,
+        repository::security_event::CreateSecurityEventPayload {
+            user_id: session.user_id,
+            r#type: repository::security_event::SecurityEventType::RefreshTokenReuse,
+            meta: json!({
+                "session_id": session.id,
+                "rotated_at": rotated_refresh_token.rotated_at,
+                "session_user_agent": session.user_agent,
+                "session_ip_address": session.ip_address,
+                "user_agent": device.user_agent,
+                "ip_address": device.ip_address,
+            }),
+        },
+    )
+    .await
+    .map_err(|_| Error::UnexpectedError)?;
+
+    tx.commit().await.map_err(|err| {
+        tracing::error!("Failed to commit database transaction: {}", err);
+        Error::UnexpectedError
+    })?;
+
+    revoke_sessions(ctx, vec![session_id]).await;
+
+    Err(Error::RefreshTokenReused)
 }
 
-pub async fn verify_refresh_token(ctx: Arc<Context>, refresh_token: String) -> Result<Session> {
-    let session = repository::session::find_by_refresh_token(&ctx.db_conn.pool, refresh_token)
+// NOTE: signatures and expiry are checked locally, the database is only touched when the denylist can't be reached
+pub async fn verify_access_token(ctx: Arc<Context>, access_token: String) -> Result<token::Claims> {
+    let claims = token::verify(&ctx, &access_token).map_err(|err| match err {
+        token::Error::ExpiredToken => Error::ExpiredToken,
+        token::Error::InvalidToken => Error::InvalidSession,
+    })?;
+
+    match ctx
+        .auth
+        .denylist
+        .peek(&get_revoked_session_key(&claims.sid))
         .await
-        .map_err(|_| Error::UnexpectedError)?
-        .ok_or(Error::InvalidSession)?;
+    {
+        Ok(Some(_)) => Err(Error::InvalidSession),
+        Ok(None) => Ok(claims),
+        Err(_) => repository::session::find_by_id(&ctx.db_conn.pool, claims.sid.clone())
+            .await
+            .map_err(|_| Error::UnexpectedError)?
+            .map(|_| claims)
+            .ok_or(Error::InvalidSession),
+    }
+}
 
-    if session.refresh_token_expires_at < Utc::now().naive_utc() {
-        return Err(Error::ExpiredToken);
+// Access tokens outlive their sessions until they expire, so sessions that end early are denylisted for that long
+pub async fn revoke_sessions(ctx: Arc<Context>, session_ids: Vec<String>) {
+    for session_id in session_ids {
+        if ctx
+            .auth
+            .denylist
+            .hit(
+                &get_revoked_session_key(&session_id),
+                ctx.auth.access_token_ttl as u64,
+            )
+            .await
+            .is_err()
+        {
+            tracing::error!("Failed to revoke access tokens for session {}", session_id);
+        }
+    }
+}
+
+// NOTE: a failed merge should never stop the user from signing in, so errors are only logged
+pub async fn merge_guest_cart(ctx: Arc<Context>, user_id: String, guest_token: String) {
+    let mut tx = match ctx.db_conn.pool.begin().await {
+        Ok(tx) => tx,
+        Err(err) => {
+            tracing::error!("Failed to start database transaction: {}", err);
+            return;
+        }
     };
 
-    Ok(session)
+    if cart::service::merge_guest_cart(&mut tx, user_id.clone(), guest_token)
+        .await
+        .is_err()
+    {
+        tracing::error!("Failed to merge guest cart for user {}", user_id);
+        return;
+    }
+
+    if let Err(err) = tx.commit().await {
+        tracing::error!("Failed to commit transaction: {}", err);
+    }
 }
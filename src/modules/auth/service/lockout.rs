@@ -0,0 +1,65 @@
+use crate::{
+    modules::user::repository::User,
+    types::{rate_limit, Context},
+};
+use std::sync::Arc;
+
+const GROUP: &str = "auth.lockout";
+
+fn get_failures_key(user: &User) -> String {
+    rate_limit::get_key(GROUP, &rate_limit::Bucket::User, &user.id)
+}
+
+fn get_lock_key(user: &User) -> String {
+    format!("lockout:user:{}", user.id)
+}
+
+// Returns the number of seconds left on the lock, if the account is locked
+pub async fn get_remaining_lock(ctx: Arc<Context>, user: &User) -> Option<u64> {
+    match ctx.rate_limit.store.peek(&get_lock_key(user)).await {
+        Ok(lock) => lock.map(|lock| lock.reset_in.max(1)),
+        Err(_) => None,
+    }
+}
+
+// Returns the lock duration once the user runs into the lockout threshold
+pub async fn record_failed_attempt(ctx: Arc<Context>, user: &User) -> Option<u64> {
+    let failures = ctx
+        .rate_limit
+        .store
+        .hit(&get_failures_key(user), ctx.rate_limit.lockout_duration)
+        .await
+        .ok()?;
+
+    if failures.count < ctx.rate_limit.lockout_threshold {
+        return None;
+    }
+
+    tracing::warn!(
+        "Locking user {} after {} failed verification attempts",
+        user.id,
+        failures.count
+    );
+
+    ctx.rate_limit
+        .store
+        .hit(&get_lock_key(user), ctx.rate_limit.lockout_duration)
+        .await
+        .ok()?;
+
+    ctx.rate_limit
+        .store
+        .clear(&get_failures_key(user))
+        .await
+        .ok();
+
+    Some(ctx.rate_limit.lockout_duration)
+}
+
+pub async fn clear_failed_attempts(ctx: Arc<Context>, user: &User) {
+    ctx.rate_limit
+        .store
+        .clear(&get_failures_key(user))
+        .await
+        .ok();
+}
@@ -0,0 +1,93 @@
+use chrono::{Duration, Utc};
+use sqlx::{Postgres, Transaction};
+
+use super::auth::{generate_token, hash_token};
+use crate::{
+    modules::{
+        auth::repository,
+        notification::service::{self as notification, Backend, Category, Notification},
+        user::{self, repository::User},
+    },
+    types::Context,
+};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub enum SendError {
+    NotSent,
+}
+
+pub enum VerificationError {
+    InvalidLink,
+    ExpiredLink,
+    UnexpectedError,
+}
+
+fn get_link(ctx: &Context, token: &str) -> String {
+    let separator = match ctx.auth.magic_link_url.contains('?') {
+        true => "&",
+        false => "?",
+    };
+    format!("{}{}token={}", ctx.auth.magic_link_url, separator, token)
+}
+
+pub async fn send(ctx: Arc<Context>, user: User) -> Result<(), SendError> {
+    let token = generate_token();
+    let validity = ctx.auth.magic_link_validity;
+
+    repository::magic_link::create(
+        &ctx.db_conn.pool,
+        repository::magic_link::CreateMagicLinkPayload {
+            user_id: user.id.clone(),
+            token_hash: hash_token(&token),
+            expires_at: Utc::now().naive_utc() + Duration::minutes(validity.into()),
+        },
+    )
+    .await
+    .map_err(|_| SendError::NotSent)?;
+
+    notification::send(
+        ctx.clone(),
+        Notification::magic_link_requested(user, get_link(&ctx, &token), validity),
+        Category::Transactional,
+        Backend::Email,
+    )
+    .await
+    .map_err(|_| SendError::NotSent)
+}
+
+// NOTE: a link works exactly once, even when two requests race for it
+pub async fn verify(
+    tx: &mut Transaction<'_, Postgres>,
+    token: String,
+) -> Result<User, VerificationError> {
+    let magic_link = repository::magic_link::find_by_token_hash(&mut **tx, hash_token(&token))
+        .await
+        .map_err(|_| VerificationError::UnexpectedError)?
+        .filter(|magic_link| magic_link.consumed_at.is_none())
+        .ok_or(VerificationError::InvalidLink)?;
+
+    if magic_link.expires_at < Utc::now().naive_utc() {
+        return Err(VerificationError::ExpiredLink);
+    }
+
+    if !repository::magic_link::consume_by_id(&mut **tx, magic_link.id)
+        .await
+        .map_err(|_| VerificationError::UnexpectedError)?
+    {
+        return Err(VerificationError::InvalidLink);
+    }
+
+    let user = user::repository::find_by_id(&mut **tx, magic_link.user_id)
+        .await
+        .map_err(|_| VerificationError::UnexpectedError)?
+        .filter(|user| user.deleted_at.is_none())
+        .ok_or(VerificationError::InvalidLink)?;
+
+    // NOTE: the link only ever goes to the user's email, so following it proves they can read it
+    user::repository::verify_email_by_id(&mut **tx, user.id.clone())
+        .await
+        .map_err(|_| VerificationError::UnexpectedError)?;
+
+    Ok(user)
+}
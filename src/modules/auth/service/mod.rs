@@ -1,2 +1,6 @@
-pub mod otp;
 pub mod auth;
+pub mod id_token;
+pub mod lockout;
+pub mod magic_link;
+pub mod otp;
+pub mod token;
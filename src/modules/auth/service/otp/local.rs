@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use rand::Rng;
+use sha2::Sha256;
+use ulid::Ulid;
+
+use super::{OtpChannel, OtpProvider, SendError, VerificationError};
+use crate::{
+    modules::{
+        notification::service::{self as notification, Backend, Category, Notification},
+        user::repository::User,
+    },
+    types::Context,
+};
+use std::sync::Arc;
+
+pub const NAME: &str = "LOCAL";
+
+pub struct LocalOtpProvider;
+
+fn get_mac(salt: &str, code: &str) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(salt.as_bytes()).expect("Invalid hmac key");
+    mac.update(code.as_bytes());
+    mac
+}
+
+#[async_trait]
+impl OtpProvider for LocalOtpProvider {
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    fn supports(&self, channel: &OtpChannel) -> bool {
+        matches!(channel, OtpChannel::Sms | OtpChannel::Email)
+    }
+
+    // NOTE: only the salted hash of the code is kept, the reference takes the form `salt:hash`
+    async fn send(
+        &self,
+        ctx: Arc<Context>,
+        user: &User,
+        channel: &OtpChannel,
+        validity: i32,
+    ) -> Result<String, SendError> {
+        let backend = match channel {
+            OtpChannel::Sms => Backend::Sms,
+            OtpChannel::Email => Backend::Email,
+            _ => return Err(SendError::UnsupportedChannel),
+        };
+
+        let code = format!("{:04}", rand::thread_rng().gen_range(0..10000));
+        let salt = Ulid::new().to_string();
+
+        notification::send(
+            ctx.clone(),
+            Notification::verification_otp_requested(user.clone(), code.clone(), validity),
+            Category::Transactional,
+            backend,
+        )
+        .await
+        .map_err(|_| SendError::NotSent)?;
+
+        let hash = hex::encode(get_mac(&salt, &code).finalize().into_bytes());
+
+        Ok(format!("{}:{}", salt, hash))
+    }
+
+    async fn verify(
+        &self,
+        _ctx: Arc<Context>,
+        reference: &str,
+        code: &str,
+    ) -> Result<bool, VerificationError> {
+        let (salt, hash) = reference
+            .split_once(':')
+            .ok_or(VerificationError::UnexpectedError)?;
+
+        let hash = hex::decode(hash).map_err(|_| VerificationError::UnexpectedError)?;
+
+        // NOTE: compared in constant time, so how long a wrong code takes says nothing about how close it was
+        Ok(get_mac(salt, code).verify_slice(&hash).is_ok())
+    }
+}
@@ -0,0 +1,320 @@
+mod local;
+mod termii;
+mod testing;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use sha2::Digest;
+use sqlx::{Postgres, Transaction};
+
+use crate::{
+    modules::{
+        auth::repository::{
+            self,
+            otp::{OtpChannel, OtpDeliveryAttempt, OtpDeliveryAttempts, OtpDeliveryStatus},
+        },
+        user::repository::User,
+    },
+    types::{AppEnvironment, Context, OtpProviderKind},
+};
+use std::sync::Arc;
+
+pub use local::LocalOtpProvider;
+pub use termii::TermiiOtpProvider;
+pub use testing::TestOtpProvider;
+
+#[derive(Eq, PartialEq, Debug)]
+pub enum SendError {
+    NotSent,
+    UnsupportedChannel,
+    // seconds left on the cooldown
+    ResendCooldown(u64),
+}
+
+impl ToString for SendError {
+    fn to_string(&self) -> String {
+        match self {
+            Self::NotSent => String::from("NOT_SENT"),
+            Self::UnsupportedChannel => String::from("UNSUPPORTED_CHANNEL"),
+            Self::ResendCooldown(_) => String::from("RESEND_COOLDOWN"),
+        }
+    }
+}
+
+pub enum VerificationError {
+    Expired,
+    InvalidOtp,
+    TooManyAttempts,
+    UnexpectedError,
+}
+
+#[async_trait]
+pub trait OtpProvider: Send + Sync {
+    // NOTE: stored alongside every OTP so that codes are verified by the provider that sent them
+    fn name(&self) -> &'static str;
+
+    fn supports(&self, channel: &OtpChannel) -> bool;
+
+    // Returns the reference we store in `otps.otp` and hand back on verification
+    async fn send(
+        &self,
+        ctx: Arc<Context>,
+        user: &User,
+        channel: &OtpChannel,
+        validity: i32,
+    ) -> Result<String, SendError>;
+
+    async fn verify(
+        &self,
+        ctx: Arc<Context>,
+        reference: &str,
+        code: &str,
+    ) -> Result<bool, VerificationError>;
+}
+
+pub fn get_provider(ctx: &Context) -> Box<dyn OtpProvider> {
+    match ctx.otp.provider {
+        OtpProviderKind::Termii => Box::new(TermiiOtpProvider),
+        OtpProviderKind::Local => Box::new(LocalOtpProvider),
+        OtpProviderKind::Test => Box::new(TestOtpProvider),
+    }
+}
+
+fn get_provider_by_name(ctx: &Context, name: &str) -> Box<dyn OtpProvider> {
+    match name {
+        termii::NAME => Box::new(TermiiOtpProvider),
+        local::NAME => Box::new(LocalOtpProvider),
+        testing::NAME => Box::new(TestOtpProvider),
+        _ => get_provider(ctx),
+    }
+}
+
+// NOTE: channels the configured provider can't handle are delivered with a locally generated code instead
+fn get_provider_for_channel(ctx: &Context, channel: &OtpChannel) -> Option<Box<dyn OtpProvider>> {
+    let provider = get_provider(ctx);
+    if provider.supports(channel) {
+        return Some(provider);
+    }
+
+    if LocalOtpProvider.supports(channel) {
+        return Some(Box::new(LocalOtpProvider));
+    }
+
+    None
+}
+
+// The preferred channel goes first, followed by the rest in their default order
+fn get_channels(preferred_channel: OtpChannel) -> Vec<OtpChannel> {
+    let mut channels = vec![preferred_channel.clone()];
+    channels.extend(
+        [
+            OtpChannel::Sms,
+            OtpChannel::Whatsapp,
+            OtpChannel::Voice,
+            OtpChannel::Email,
+        ]
+        .into_iter()
+        .filter(|channel| *channel != preferred_channel),
+    );
+    channels
+}
+
+// How long (in minutes) after a code expires before its failed attempts stop counting against the next one
+const ATTEMPTS_RESET_AFTER: i64 = 60;
+
+pub fn get_validity(ctx: &Context) -> i32 {
+    match ctx.app.environment {
+        AppEnvironment::Production => 5,
+        AppEnvironment::Development => 3,
+    }
+}
+
+fn generate_hash(purpose: &str, user: &User) -> String {
+    let mut hasher = sha2::Sha256::new();
+    hasher.update(format!("{}-{}", purpose, user.id.clone()));
+    let hash = hasher.finalize();
+    let hash = base16ct::lower::encode_string(&hash);
+    tracing::debug!("hash: {}", hash.clone());
+    hash
+}
+
+pub async fn send(
+    ctx: Arc<Context>,
+    user: User,
+    purpose: String,
+    preferred_channel: OtpChannel,
+) -> Result<repository::otp::Otp, SendError> {
+    send_over_channels(ctx, user, purpose, get_channels(preferred_channel)).await
+}
+
+// Same as `send` but never falls back to a channel outside of `channels`, e.g when the OTP has to reach a specific
+// phone number
+pub async fn send_over_channels(
+    ctx: Arc<Context>,
+    user: User,
+    purpose: String,
+    channels: Vec<OtpChannel>,
+) -> Result<repository::otp::Otp, SendError> {
+    let hash = generate_hash(&purpose, &user);
+
+    let existing_otp = repository::otp::find_by_hash(&ctx.db_conn.pool, hash.clone())
+        .await
+        .map_err(|_| SendError::NotSent)?;
+
+    if let Some(existing_otp) = existing_otp.clone() {
+        let cooldown_ends_at = existing_otp.sent_at + Duration::seconds(ctx.otp.resend_cooldown);
+        let now = Utc::now().naive_utc();
+        if now < cooldown_ends_at {
+            return Err(SendError::ResendCooldown(
+                (cooldown_ends_at - now).num_seconds().max(1) as u64,
+            ));
+        }
+    }
+
+    let mut delivery_attempts = vec![];
+    let mut delivery = None;
+
+    for channel in channels {
+        let provider = match get_provider_for_channel(&ctx, &channel) {
+            Some(provider) => provider,
+            None => continue,
+        };
+
+        let result = provider
+            .send(ctx.clone(), &user, &channel, get_validity(&ctx))
+            .await;
+
+        delivery_attempts.push(OtpDeliveryAttempt {
+            channel: channel.clone(),
+            provider: provider.name().to_string(),
+            status: match result {
+                Ok(_) => OtpDeliveryStatus::Sent,
+                Err(_) => OtpDeliveryStatus::Failed,
+            },
+            error: result.as_ref().err().map(|err| err.to_string()),
+            attempted_at: Utc::now().naive_utc(),
+        });
+
+        match result {
+            Ok(reference) => {
+                delivery = Some((reference, provider.name()));
+                break;
+            }
+            Err(_) => {
+                tracing::warn!(
+                    "Failed to deliver OTP to {} over {}, falling back to the next channel",
+                    user.id,
+                    channel.to_string()
+                );
+            }
+        }
+    }
+
+    // NOTE: nothing is stored when every channel fails, a refreshed `sent_at` would put the user on a cooldown for a
+    // code that never reached them
+    let (reference, provider) = match delivery {
+        Some(delivery) => delivery,
+        None => {
+            tracing::error!(
+                "Failed to deliver OTP to {} over any channel: {:?}",
+                user.id,
+                delivery_attempts
+            );
+            return Err(SendError::NotSent);
+        }
+    };
+    let validity = get_validity(&ctx);
+    let delivery_attempts = OtpDeliveryAttempts(delivery_attempts);
+
+    let otp = match existing_otp {
+        Some(existing_otp) => {
+            // NOTE: a resend keeps the failed attempts, see `ATTEMPTS_RESET_AFTER`
+            let reset_attempts = Utc::now().naive_utc()
+                > existing_otp.expires_at + Duration::minutes(ATTEMPTS_RESET_AFTER);
+
+            repository::otp::update_by_id(
+                &ctx.db_conn.pool,
+                existing_otp.id.clone(),
+                repository::otp::UpdateOtpPayload {
+                    hash: hash.clone(),
+                    otp: reference,
+                    purpose: purpose.clone(),
+                    meta: Some("".to_string()),
+                    provider: provider.to_string(),
+                    delivery_attempts,
+                    validity,
+                    reset_attempts,
+                },
+            )
+            .await
+        }
+        None => {
+            repository::otp::create(
+                &ctx.db_conn.pool,
+                repository::otp::CreateOtpPayload {
+                    user_id: user.id.clone(),
+                    purpose,
+                    meta: "".to_string(),
+                    hash,
+                    otp: reference,
+                    provider: provider.to_string(),
+                    delivery_attempts,
+                    validity,
+                },
+            )
+            .await
+        }
+    }
+    .map_err(|_| SendError::NotSent)?;
+
+    Ok(otp)
+}
+
+pub async fn verify(
+    ctx: Arc<Context>,
+    tx: &mut Transaction<'_, Postgres>,
+    user: User,
+    purpose: String,
+    code: String,
+) -> Result<(), VerificationError> {
+    let hash = generate_hash(&purpose, &user);
+
+    let existing_otp = repository::otp::find_by_hash(&mut **tx, hash.clone())
+        .await
+        .map_err(|_| VerificationError::UnexpectedError)?
+        .ok_or(VerificationError::InvalidOtp)?;
+
+    if Utc::now().naive_utc() > existing_otp.expires_at {
+        tracing::info!("otp has expired");
+        return Err(VerificationError::Expired);
+    }
+
+    if existing_otp.attempts >= ctx.otp.max_attempts {
+        return Err(VerificationError::TooManyAttempts);
+    }
+
+    // NOTE: counting the attempt locks the OTP until the caller's transaction ends, so concurrent guesses can't slip
+    // past the limit. The caller has to commit that transaction when verification fails or the attempt is lost
+    let existing_otp =
+        repository::otp::increment_attempts_by_id(&mut **tx, existing_otp.id.clone())
+            .await
+            .map_err(|_| VerificationError::UnexpectedError)?;
+
+    if existing_otp.attempts > ctx.otp.max_attempts {
+        return Err(VerificationError::TooManyAttempts);
+    }
+
+    let provider = get_provider_by_name(&ctx, &existing_otp.provider);
+    if !provider
+        .verify(ctx.clone(), &existing_otp.otp, &code)
+        .await?
+    {
+        return Err(VerificationError::InvalidOtp);
+    }
+
+    repository::otp::delete_by_id(&mut **tx, existing_otp.id)
+        .await
+        .ok();
+
+    Ok(())
+}
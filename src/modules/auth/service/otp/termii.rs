@@ -0,0 +1,186 @@
+use async_trait::async_trait;
+use hyper::HeaderMap;
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Deserializer};
+use serde_json::{json, Value};
+
+use super::{OtpChannel, OtpProvider, SendError, VerificationError};
+use crate::{modules::user::repository::User, types::Context};
+use std::sync::Arc;
+
+pub const NAME: &str = "TERMII";
+
+pub struct TermiiOtpProvider;
+
+#[derive(Deserialize)]
+struct SendEndpointPayload {
+    #[serde(rename = "pinId")]
+    pin_id: String,
+    // NOTE: the voice endpoint doesn't include a status
+    status: Option<String>,
+}
+
+// Putting this here because for some reason, the OTP service returns a boolean if everything works out well but then it returns a string if it doesn't
+enum VerifiedEndpointStatus {
+    Successful,
+    Error(String),
+}
+
+impl<'de> Deserialize<'de> for VerifiedEndpointStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+
+        match value {
+            Value::Bool(true) => Ok(VerifiedEndpointStatus::Successful),
+            Value::Bool(false) => Ok(VerifiedEndpointStatus::Error("Invalid OTP".to_string())),
+            Value::String(s) => Ok(VerifiedEndpointStatus::Error(s)),
+            _ => Err(serde::de::Error::custom("Invalid verified endpoint status")),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct VerificationEndpointPayload {
+    verified: VerifiedEndpointStatus,
+    #[serde(rename = "pinId")]
+    pin_id: String,
+}
+
+async fn hit_up_endpoint(endpoint: String, body: String) -> Result<(StatusCode, String), ()> {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        "Content-Type",
+        "application/json"
+            .try_into()
+            .expect("Invalid content type header value"),
+    );
+
+    let res = Client::new()
+        .post(endpoint)
+        .headers(headers)
+        .body(body)
+        .send()
+        .await
+        .map_err(|err| {
+            tracing::error!("Failed to send request {}", err);
+        })?;
+
+    let status = res.status();
+    let text = res.text().await.map_err(|err| {
+        tracing::error!("Failed to get response text {}", err);
+    })?;
+
+    Ok((status, text))
+}
+
+#[async_trait]
+impl OtpProvider for TermiiOtpProvider {
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    fn supports(&self, channel: &OtpChannel) -> bool {
+        matches!(
+            channel,
+            OtpChannel::Sms | OtpChannel::Whatsapp | OtpChannel::Voice
+        )
+    }
+
+    async fn send(
+        &self,
+        ctx: Arc<Context>,
+        user: &User,
+        channel: &OtpChannel,
+        validity: i32,
+    ) -> Result<String, SendError> {
+        let (endpoint, body) = match channel {
+            OtpChannel::Sms | OtpChannel::Whatsapp => (
+                ctx.otp.send_endpoint.clone(),
+                json!({
+                    "api_key": ctx.otp.api_key.clone(),
+                    "message_type": "NUMERIC",
+                    "to": user.phone_number.clone(),
+                    "from": ctx.otp.app_id.clone(),
+                    "channel": match channel {
+                        OtpChannel::Whatsapp => "WhatsApp",
+                        _ => "dnd",
+                    },
+                    "pin_attempts": ctx.otp.max_attempts,
+                    "pin_time_to_live": validity,
+                    "pin_length": 4,
+                    "pin_placeholder": "$PIN",
+                    "message_text": format!("Your FoodHut app verification pin is $PIN\nPin will expire in {} minutes, do not share it with anyone", validity),
+                    "pin_type": "NUMERIC"
+                }),
+            ),
+            OtpChannel::Voice => (
+                ctx.otp.voice_endpoint.clone(),
+                json!({
+                    "api_key": ctx.otp.api_key.clone(),
+                    "phone_number": user.phone_number.clone(),
+                    "pin_attempts": ctx.otp.max_attempts,
+                    "pin_time_to_live": validity,
+                    "pin_length": 4
+                }),
+            ),
+            OtpChannel::Email => return Err(SendError::UnsupportedChannel),
+        };
+
+        let (status, text) = hit_up_endpoint(endpoint, body.to_string())
+            .await
+            .map_err(|_| SendError::NotSent)?;
+
+        if status != StatusCode::OK {
+            tracing::error!("Failed to send OTP over {}: {}", channel.to_string(), text);
+            return Err(SendError::NotSent);
+        }
+
+        let res = serde_json::from_str::<SendEndpointPayload>(&text).map_err(|err| {
+            tracing::error!("Failed to deserialize termii response: {}", err);
+            SendError::NotSent
+        })?;
+
+        if let Some(status) = res.status {
+            if status != "200" {
+                tracing::error!("Got an unexpected status response: {}", text);
+                return Err(SendError::NotSent);
+            }
+        }
+
+        Ok(res.pin_id)
+    }
+
+    async fn verify(
+        &self,
+        ctx: Arc<Context>,
+        reference: &str,
+        code: &str,
+    ) -> Result<bool, VerificationError> {
+        let (_, text) = hit_up_endpoint(
+            ctx.otp.verify_endpoint.clone(),
+            json!({
+                "api_key": ctx.otp.api_key.clone(),
+                "pin_id": reference,
+                "pin": code
+            })
+            .to_string(),
+        )
+        .await
+        .map_err(|_| VerificationError::UnexpectedError)?;
+
+        let res = serde_json::from_str::<VerificationEndpointPayload>(&text).map_err(|err| {
+            tracing::error!("Failed to deserialize termii response: {}", err);
+            VerificationError::UnexpectedError
+        })?;
+
+        if let VerifiedEndpointStatus::Error(err) = res.verified {
+            tracing::debug!("Got an error response when verifying OTP: {}", err);
+            return Ok(false);
+        }
+
+        Ok(res.pin_id == reference)
+    }
+}
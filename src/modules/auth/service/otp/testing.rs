@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+
+use super::{OtpChannel, OtpProvider, SendError, VerificationError};
+use crate::{modules::user::repository::User, types::Context};
+use std::sync::Arc;
+
+pub const NAME: &str = "TEST";
+
+// Accepts the configured `OTP_TEST_CODE` for every user and never sends anything
+pub struct TestOtpProvider;
+
+#[async_trait]
+impl OtpProvider for TestOtpProvider {
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    fn supports(&self, _channel: &OtpChannel) -> bool {
+        true
+    }
+
+    async fn send(
+        &self,
+        _ctx: Arc<Context>,
+        user: &User,
+        channel: &OtpChannel,
+        _validity: i32,
+    ) -> Result<String, SendError> {
+        tracing::debug!(
+            "Skipping OTP delivery to {} over {} (test provider)",
+            user.id,
+            channel.to_string()
+        );
+        Ok(NAME.to_string())
+    }
+
+    async fn verify(
+        &self,
+        ctx: Arc<Context>,
+        _reference: &str,
+        code: &str,
+    ) -> Result<bool, VerificationError> {
+        Ok(code == ctx.otp.test_code)
+    }
+}
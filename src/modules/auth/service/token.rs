@@ -0,0 +1,272 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD as BASE64_URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::{
+    modules::user::repository::Role,
+    types::{AuthSigningKey, Context},
+};
+
+const ALGORITHM: &str = "HS256";
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidToken,
+    ExpiredToken,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Header {
+    alg: String,
+    typ: String,
+    kid: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Claims {
+    // user id
+    pub sub: String,
+    pub role: Role,
+    // session id
+    pub sid: String,
+    pub iat: i64,
+    pub exp: i64,
+}
+
+fn encode_segment<T: Serialize>(value: &T) -> String {
+    BASE64_URL_SAFE_NO_PAD.encode(serde_json::to_vec(value).expect("Failed to serialize token"))
+}
+
+fn decode_segment<T: for<'de> Deserialize<'de>>(segment: &str) -> Result<T, Error> {
+    let bytes = BASE64_URL_SAFE_NO_PAD
+        .decode(segment)
+        .map_err(|_| Error::InvalidToken)?;
+    serde_json::from_slice::<T>(&bytes).map_err(|_| Error::InvalidToken)
+}
+
+fn get_mac(key: &AuthSigningKey) -> Hmac<Sha256> {
+    Hmac::<Sha256>::new_from_slice(key.secret.as_bytes()).expect("Invalid signing key")
+}
+
+fn get_signing_key<'a>(keys: &'a [AuthSigningKey], id: &str) -> Option<&'a AuthSigningKey> {
+    keys.iter().find(|key| key.id == id)
+}
+
+// Signs a short-lived access token (a compact HS256 JWT) with the active key
+pub fn sign(ctx: &Context, user_id: String, role: Role, session_id: String) -> String {
+    let key = get_signing_key(&ctx.auth.signing_keys, &ctx.auth.active_signing_key_id)
+        .expect("Active signing key not found");
+
+    let now = Utc::now().timestamp();
+    sign_with_key(
+        key,
+        &Claims {
+            sub: user_id,
+            role,
+            sid: session_id,
+            iat: now,
+            exp: now + ctx.auth.access_token_ttl,
+        },
+    )
+}
+
+fn sign_with_key(key: &AuthSigningKey, claims: &Claims) -> String {
+    let header = encode_segment(&Header {
+        alg: ALGORITHM.to_string(),
+        typ: "JWT".to_string(),
+        kid: key.id.clone(),
+    });
+    let claims = encode_segment(claims);
+
+    let mut mac = get_mac(key);
+    mac.update(format!("{}.{}", header, claims).as_bytes());
+    let signature = BASE64_URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+
+    format!("{}.{}.{}", header, claims, signature)
+}
+
+// NOTE: the `kid` picks the key, so tokens signed before a rotation keep working for as long as their key stays configured
+pub fn verify(ctx: &Context, token: &str) -> Result<Claims, Error> {
+    verify_with_keys(&ctx.auth.signing_keys, token)
+}
+
+fn verify_with_keys(keys: &[AuthSigningKey], token: &str) -> Result<Claims, Error> {
+    let mut segments = token.split('.');
+    let (header, claims, signature) = match (
+        segments.next(),
+        segments.next(),
+        segments.next(),
+        segments.next(),
+    ) {
+        (Some(header), Some(claims), Some(signature), None) => (header, claims, signature),
+        _ => return Err(Error::InvalidToken),
+    };
+
+    let decoded_header = decode_segment::<Header>(header)?;
+    if decoded_header.alg != ALGORITHM {
+        return Err(Error::InvalidToken);
+    }
+
+    let key = get_signing_key(keys, &decoded_header.kid).ok_or(Error::InvalidToken)?;
+    let signature = BASE64_URL_SAFE_NO_PAD
+        .decode(signature)
+        .map_err(|_| Error::InvalidToken)?;
+
+    let mut mac = get_mac(key);
+    mac.update(format!("{}.{}", header, claims).as_bytes());
+    mac.verify_slice(&signature)
+        .map_err(|_| Error::InvalidToken)?;
+
+    let claims = decode_segment::<Claims>(claims)?;
+    if claims.exp < Utc::now().timestamp() {
+        return Err(Error::ExpiredToken);
+    }
+
+    Ok(claims)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn get_key(id: &str) -> AuthSigningKey {
+        AuthSigningKey {
+            id: id.to_string(),
+            secret: format!("{}-0123456789abcdef0123456789abcdef", id),
+        }
+    }
+
+    fn get_claims(exp: i64) -> Claims {
+        let now = Utc::now().timestamp();
+
+        Claims {
+            sub: String::from("user"),
+            role: Role::User,
+            sid: String::from("session"),
+            iat: now,
+            exp: now + exp,
+        }
+    }
+
+    // re-encodes the header without touching the rest, the signature no longer matters once `alg` is wrong
+    fn replace_header(token: &str, alg: &str, kid: &str) -> String {
+        let (_, rest) = token.split_once('.').unwrap();
+        let header = encode_segment(&Header {
+            alg: alg.to_string(),
+            typ: "JWT".to_string(),
+            kid: kid.to_string(),
+        });
+
+        format!("{}.{}", header, rest)
+    }
+
+    #[test]
+    fn should_verify_a_signed_token() {
+        let key = get_key("current");
+        let token = sign_with_key(&key, &get_claims(900));
+
+        let claims = verify_with_keys(&[key], &token).unwrap();
+
+        assert_eq!(claims.sub, "user");
+        assert_eq!(claims.sid, "session");
+    }
+
+    #[test]
+    fn should_reject_a_tampered_payload() {
+        let key = get_key("current");
+        let token = sign_with_key(&key, &get_claims(900));
+        let segments = token.split('.').collect::<Vec<_>>();
+
+        let mut claims = get_claims(900);
+        claims.sub = String::from("someone-else");
+        let token = format!(
+            "{}.{}.{}",
+            segments[0],
+            encode_segment(&claims),
+            segments[2]
+        );
+
+        assert!(matches!(
+            verify_with_keys(&[key], &token),
+            Err(Error::InvalidToken)
+        ));
+    }
+
+    #[test]
+    fn should_reject_a_tampered_signature() {
+        let key = get_key("current");
+        let token = sign_with_key(&key, &get_claims(900));
+        let (unsigned, _) = token.rsplit_once('.').unwrap();
+
+        let token = format!("{}.{}", unsigned, BASE64_URL_SAFE_NO_PAD.encode([0u8; 32]));
+
+        assert!(matches!(
+            verify_with_keys(&[key], &token),
+            Err(Error::InvalidToken)
+        ));
+    }
+
+    #[test]
+    fn should_reject_other_algorithms() {
+        let key = get_key("current");
+        let token = sign_with_key(&key, &get_claims(900));
+
+        for alg in ["none", "HS512", "RS256"] {
+            let token = replace_header(&token, alg, &key.id);
+            assert!(matches!(
+                verify_with_keys(std::slice::from_ref(&key), &token),
+                Err(Error::InvalidToken)
+            ));
+        }
+
+        // an unsigned token, the way `none` is usually sent
+        let token = replace_header(&token, "none", &key.id);
+        let (unsigned, _) = token.rsplit_once('.').unwrap();
+        assert!(matches!(
+            verify_with_keys(&[key], &format!("{}.", unsigned)),
+            Err(Error::InvalidToken)
+        ));
+    }
+
+    #[test]
+    fn should_reject_an_unknown_key() {
+        let token = sign_with_key(&get_key("unknown"), &get_claims(900));
+
+        assert!(matches!(
+            verify_with_keys(&[get_key("current")], &token),
+            Err(Error::InvalidToken)
+        ));
+    }
+
+    #[test]
+    fn should_reject_an_expired_token() {
+        let key = get_key("current");
+        let token = sign_with_key(&key, &get_claims(-60));
+
+        assert!(matches!(
+            verify_with_keys(&[key], &token),
+            Err(Error::ExpiredToken)
+        ));
+    }
+
+    #[test]
+    fn should_verify_tokens_signed_before_a_rotation() {
+        let previous_key = get_key("previous");
+        let current_key = get_key("current");
+        let previous_token = sign_with_key(&previous_key, &get_claims(900));
+        let current_token = sign_with_key(&current_key, &get_claims(900));
+
+        let keys = [current_key.clone(), previous_key];
+        assert!(verify_with_keys(&keys, &previous_token).is_ok());
+        assert!(verify_with_keys(&keys, &current_token).is_ok());
+
+        // once the previous key is dropped its tokens stop working
+        assert!(matches!(
+            verify_with_keys(&[current_key], &previous_token),
+            Err(Error::InvalidToken)
+        ));
+    }
+}
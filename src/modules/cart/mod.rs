@@ -1,3 +1,4 @@
 mod routes;
 pub use routes::*;
 pub mod repository;
+pub mod service;
@@ -1,3 +1,4 @@
+use bigdecimal::BigDecimal;
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
@@ -44,6 +45,8 @@ impl ToString for CartStatus {
 pub struct CartItem {
     pub meal_id: String,
     pub quantity: i32,
+    #[serde(default)]
+    pub price: Option<BigDecimal>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -88,6 +91,8 @@ define_paginated!(DatabasePaginatedCart, Cart);
 pub struct FullCartItem {
     pub meal_id: String,
     pub quantity: i32,
+    #[serde(default)]
+    pub price: Option<BigDecimal>,
     pub meal: Meal,
     pub kitchen: Kitchen,
 }
@@ -135,6 +140,24 @@ pub struct CreateCartPayload {
     pub owner_id: String,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct GuestCart {
+    pub id: String,
+    pub token: String,
+    pub items: CartItems,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct FullGuestCart {
+    pub id: String,
+    pub token: String,
+    pub items: FullCartItems,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
 #[derive(Debug)]
 pub enum Error {
     UnexpectedError,
@@ -191,6 +214,27 @@ pub async fn find_active_cart_by_owner_id<'e, E: PgExecutor<'e>>(
     })
 }
 
+pub async fn find_many_by_owner_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    owner_id: String,
+) -> Result<Vec<Cart>, Error> {
+    sqlx::query_as!(
+        Cart,
+        "SELECT * FROM carts WHERE owner_id = $1 ORDER BY created_at DESC",
+        owner_id
+    )
+    .fetch_all(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch carts by owner id {}: {}",
+            owner_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
 pub async fn find_active_full_cart_by_owner_id<'e, E: PgExecutor<'e>>(
     e: E,
     owner_id: String,
@@ -206,12 +250,13 @@ pub async fn find_active_full_cart_by_owner_id<'e, E: PgExecutor<'e>>(
                 x.*
             FROM
                 filtered_carts,
-                JSONB_TO_RECORDSET(filtered_carts.items::JSONB) AS x(meal_id TEXT, quantity INTEGER)
+                JSONB_TO_RECORDSET(filtered_carts.items::JSONB) AS x(meal_id TEXT, quantity INTEGER, price NUMERIC)
         ),
         cart_items AS (
             SELECT
                 cart_line_items.meal_id,
                 cart_line_items.quantity,
+                cart_line_items.price,
                 TO_JSONB(meals) AS meal,
                 TO_JSONB(kitchens) || JSONB_BUILD_OBJECT('city', kitchen_cities) AS kitchen
             FROM 
@@ -294,3 +339,149 @@ pub async fn update_by_id<'e, E: PgExecutor<'e>>(
         Error::UnexpectedError
     })
 }
+
+pub async fn create_guest<'e, E: PgExecutor<'e>>(e: E) -> Result<GuestCart, Error> {
+    sqlx::query_as!(
+        GuestCart,
+        "
+        INSERT INTO guest_carts (
+            id,
+            token,
+            items
+        )
+        VALUES ($1, $2, $3)
+        RETURNING *
+    ",
+        Ulid::new().to_string(),
+        Ulid::new().to_string(),
+        json!([]),
+    )
+    .fetch_one(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to create a guest cart: {}",
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_guest_by_token<'e, E: PgExecutor<'e>>(
+    e: E,
+    token: String,
+) -> Result<Option<GuestCart>, Error> {
+    sqlx::query_as!(
+        GuestCart,
+        "SELECT * FROM guest_carts WHERE token = $1",
+        token,
+    )
+    .fetch_optional(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch guest cart by token: {}",
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_full_guest_cart_by_token<'e, E: PgExecutor<'e>>(
+    e: E,
+    token: String,
+) -> Result<Option<FullGuestCart>, Error> {
+    sqlx::query_as!(
+        FullGuestCart,
+        r#"
+        WITH filtered_carts AS (
+            SELECT * FROM guest_carts WHERE token = $1
+        ),
+        cart_line_items AS (
+            SELECT
+                x.*
+            FROM
+                filtered_carts,
+                JSONB_TO_RECORDSET(filtered_carts.items::JSONB) AS x(meal_id TEXT, quantity INTEGER, price NUMERIC)
+        ),
+        cart_items AS (
+            SELECT
+                cart_line_items.meal_id,
+                cart_line_items.quantity,
+                cart_line_items.price,
+                TO_JSONB(meals) AS meal,
+                TO_JSONB(kitchens) || JSONB_BUILD_OBJECT('city', kitchen_cities) AS kitchen
+            FROM 
+                filtered_carts,
+                cart_line_items
+            INNER JOIN meals ON meals.id = cart_line_items.meal_id
+            INNER JOIN kitchens ON kitchens.id = meals.kitchen_id
+            INNER JOIN kitchen_cities ON kitchen_cities.id = kitchens.city_id
+        )
+        SELECT 
+            filtered_carts.id,
+            filtered_carts.token,
+            filtered_carts.created_at,
+            filtered_carts.updated_at,
+            JSONB_AGG(
+                cart_items
+            ) AS items
+        FROM
+            filtered_carts,
+            cart_items
+        GROUP BY
+            filtered_carts.id,
+            filtered_carts.token,
+            filtered_carts.created_at,
+            filtered_carts.updated_at
+        "#,
+        token,
+    )
+    .fetch_optional(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch full guest cart by token: {}",
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn update_guest_by_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+    items: CartItems,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "UPDATE guest_carts SET items = $1::json, updated_at = NOW() WHERE id = $2",
+        json!(items).to_string(),
+        id.clone(),
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to update guest cart by id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn delete_guest_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<(), Error> {
+    sqlx::query!("DELETE FROM guest_carts WHERE id = $1", id.clone())
+        .execute(e)
+        .await
+        .map(|_| ())
+        .map_err(|err| {
+            tracing::error!(
+                "Error occurred while trying to delete guest cart by id {}: {}",
+                id,
+                err
+            );
+            Error::UnexpectedError
+        })
+}
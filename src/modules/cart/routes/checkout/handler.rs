@@ -0,0 +1,16 @@
+use super::service::service;
+use super::types::request;
+use crate::{modules::auth::middleware::VerifiedUserAuth, types::Context};
+use axum::{
+    extract::{Json, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+pub async fn handler(
+    auth: VerifiedUserAuth,
+    State(ctx): State<Arc<Context>>,
+    Json(body): Json<request::Body>,
+) -> impl IntoResponse {
+    service(ctx, auth, request::Payload { body }).await
+}
@@ -0,0 +1,121 @@
+use super::types::{request, response};
+use crate::{
+    modules::{
+        auth::middleware::VerifiedUserAuth,
+        cart::{repository, service as cart_service},
+        order,
+    },
+    types::Context,
+};
+use itertools::Itertools;
+use std::sync::Arc;
+
+pub async fn service(
+    ctx: Arc<Context>,
+    auth: VerifiedUserAuth,
+    payload: request::Payload,
+) -> response::Response {
+    let parsed_delivery_date = payload
+        .body
+        .delivery_date
+        .map(|d| chrono::NaiveDateTime::parse_from_str(&d.to_string(), "%s"))
+        .transpose()
+        .map_err(|err| response::Error::InvalidDate(err.to_string()))?;
+
+    parsed_delivery_date
+        .clone()
+        .map(|delivery_date| {
+            if delivery_date < chrono::Utc::now().naive_utc() {
+                Err(response::Error::InvalidDate(String::from(
+                    "Delivery date cannot be in the past",
+                )))
+            } else {
+                Ok(())
+            }
+        })
+        .transpose()?;
+
+    let cart =
+        repository::find_active_full_cart_by_owner_id(&ctx.db_conn.pool, auth.user.id.clone())
+            .await
+            .map_err(|_| response::Error::FailedToFindCart)?
+            .ok_or(response::Error::CartNotFound)?;
+
+    if cart.items.len() == 0 {
+        return Err(response::Error::NoItemsToCheckout);
+    }
+
+    let warnings = cart_service::validate(&cart);
+    if cart_service::has_blocking_warnings(&warnings) {
+        return Err(response::Error::CartHasUnavailableItems(warnings));
+    }
+
+    if !cart_service::is_quote_valid(&payload.body.quote_id, cart.id.clone(), &cart.items.0) {
+        let quote = cart_service::generate_quote(cart.id.clone(), &cart.items.0);
+        return Err(response::Error::StaleQuote(warnings, quote));
+    }
+
+    let total = cart_service::calculate_total(&cart.items.0);
+
+    let items_by_kitchen = cart
+        .items
+        .0
+        .into_iter()
+        .into_group_map_by(|item| item.kitchen.id.clone());
+
+    let mut tx = ctx.db_conn.clone().pool.begin().await.map_err(|err| {
+        tracing::error!("Failed to start database transaction: {}", err);
+        response::Error::FailedToCheckoutCart
+    })?;
+
+    let group = order::repository::create_group(
+        &mut *tx,
+        order::repository::CreateOrderGroupPayload {
+            payment_method: payload.body.payment_method.clone(),
+            total,
+            owner_id: auth.user.id.clone(),
+        },
+    )
+    .await
+    .map_err(|_| response::Error::FailedToCheckoutCart)?;
+
+    let mut orders = vec![];
+    for (kitchen_id, items) in items_by_kitchen {
+        let order = order::repository::create(
+            &mut *tx,
+            order::repository::CreateOrderPayload {
+                items,
+                payment_method: payload.body.payment_method.clone(),
+                delivery_address: payload.body.delivery_address.clone(),
+                delivery_date: parsed_delivery_date.clone(),
+                dispatch_rider_note: payload.body.dispatch_rider_note.clone(),
+                kitchen_id,
+                owner_id: auth.user.id.clone(),
+                group_id: Some(group.id.clone()),
+            },
+        )
+        .await
+        .map_err(|_| response::Error::FailedToCheckoutCart)?;
+
+        orders.push(order);
+    }
+
+    repository::update_by_id(
+        &mut *tx,
+        cart.id.clone(),
+        repository::UpdateCartPayload {
+            items: None,
+            status: Some(repository::CartStatus::CheckedOut),
+        },
+    )
+    .await
+    .map_err(|_| response::Error::FailedToCheckoutCart)?;
+
+    tx.commit()
+        .await
+        .map_err(|err| {
+            tracing::error!("Failed to commit transaction: {}", err);
+            response::Error::FailedToCheckoutCart
+        })
+        .map(|_| response::Success::CheckoutSuccessful(group, orders))
+}
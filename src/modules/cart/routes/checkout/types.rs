@@ -0,0 +1,107 @@
+pub mod request {
+    use crate::modules::order::repository::PaymentMethod;
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    pub struct Body {
+        pub payment_method: PaymentMethod,
+        pub delivery_address: String,
+        pub delivery_date: Option<u64>,
+        pub dispatch_rider_note: String,
+        pub quote_id: String,
+    }
+
+    pub struct Payload {
+        pub body: Body,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    use crate::modules::{
+        cart::service::{CartWarning, Quote},
+        order::repository::{Order, OrderGroup},
+    };
+
+    pub enum Success {
+        CheckoutSuccessful(OrderGroup, Vec<Order>),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::CheckoutSuccessful(group, orders) => (
+                    StatusCode::CREATED,
+                    Json(json!({
+                        "message": "Cart checkedout successfully",
+                        "id": group.id,
+                        "total": group.total,
+                        "order_ids": orders.into_iter().map(|order| order.id).collect::<Vec<_>>(),
+                    })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        InvalidDate(String),
+        CartNotFound,
+        FailedToFindCart,
+        NoItemsToCheckout,
+        CartHasUnavailableItems(Vec<CartWarning>),
+        StaleQuote(Vec<CartWarning>, Quote),
+        FailedToCheckoutCart,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToCheckoutCart => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to checkout cart" })),
+                )
+                    .into_response(),
+                Self::NoItemsToCheckout => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "No items to checkout!" })),
+                )
+                    .into_response(),
+                Self::CartHasUnavailableItems(warnings) => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({
+                        "error": "Cart contains items that can no longer be ordered",
+                        "warnings": warnings,
+                    })),
+                )
+                    .into_response(),
+                Self::StaleQuote(warnings, quote) => (
+                    StatusCode::CONFLICT,
+                    Json(json!({
+                        "error": "Cart has changed since it was quoted",
+                        "warnings": warnings,
+                        "quote": quote,
+                    })),
+                )
+                    .into_response(),
+                Self::FailedToFindCart => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({"error": "Failed to find cart"})),
+                )
+                    .into_response(),
+                Self::CartNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({"error": "Cart not found"})),
+                )
+                    .into_response(),
+                Self::InvalidDate(err) => {
+                    (StatusCode::BAD_REQUEST, Json(json!({ "error": err}))).into_response()
+                }
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
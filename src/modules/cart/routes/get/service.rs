@@ -4,7 +4,9 @@ use super::types::{
 };
 use crate::{
     modules::{
-        auth::middleware::Auth, cart::repository, kitchen::repository::Kitchen,
+        auth::middleware::Auth,
+        cart::{repository, service as cart_service},
+        kitchen::repository::Kitchen,
         meal::repository::MealWithCartStatus,
     },
     types::Context,
@@ -18,6 +20,9 @@ pub async fn service(ctx: Arc<Context>, auth: Auth) -> response::Response {
         .map_err(|_| response::Error::FailedToFetchActiveCart)?
         .ok_or(response::Error::CartNotFound)
         .map(|cart| {
+            let warnings = cart_service::validate(&cart);
+            let quote = cart_service::generate_quote(cart.id.clone(), &cart.items.0);
+
             let kitchen_id_to_kitchen_map = cart
                 .items
                 .0
@@ -31,12 +36,17 @@ pub async fn service(ctx: Arc<Context>, auth: Auth) -> response::Response {
             let items = kitchen_id_to_kitchen_map
                 .into_iter()
                 .map(|(id, kitchen)| {
-                    let meals = cart
+                    let kitchen_items = cart
                         .items
                         .0
                         .clone()
                         .into_iter()
                         .filter(|item| item.kitchen.id == id)
+                        .collect::<Vec<_>>();
+                    let quote = cart_service::generate_quote(cart.id.clone(), &kitchen_items);
+
+                    let meals = kitchen_items
+                        .into_iter()
                         .map(|item| MealWithQuantity {
                             quantity: item.quantity,
                             meal: MealWithCartStatus {
@@ -58,10 +68,18 @@ pub async fn service(ctx: Arc<Context>, auth: Auth) -> response::Response {
                         })
                         .collect::<Vec<_>>();
 
-                    KitchenWithMeals { kitchen, meals }
+                    KitchenWithMeals {
+                        kitchen,
+                        meals,
+                        quote,
+                    }
                 })
                 .collect::<Vec<_>>();
 
-            response::Success::Items(items)
+            response::Success::Cart {
+                items,
+                warnings,
+                quote,
+            }
         })
 }
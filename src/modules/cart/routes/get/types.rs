@@ -5,7 +5,11 @@ pub mod response {
     use serde::Serialize;
     use serde_json::json;
 
-    use crate::modules::{kitchen::repository::Kitchen, meal::repository::MealWithCartStatus};
+    use crate::modules::{
+        cart::service::{CartWarning, Quote},
+        kitchen::repository::Kitchen,
+        meal::repository::MealWithCartStatus,
+    };
 
     #[derive(Debug, Serialize)]
     pub struct MealWithQuantity {
@@ -17,16 +21,33 @@ pub mod response {
     pub struct KitchenWithMeals {
         pub kitchen: Kitchen,
         pub meals: Vec<MealWithQuantity>,
+        pub quote: Quote,
     }
 
     pub enum Success {
-        Items(Vec<KitchenWithMeals>),
+        Cart {
+            items: Vec<KitchenWithMeals>,
+            warnings: Vec<CartWarning>,
+            quote: Quote,
+        },
     }
 
     impl IntoResponse for Success {
         fn into_response(self) -> axum::response::Response {
             match self {
-                Self::Items(items) => (StatusCode::OK, Json(json!(items))).into_response(),
+                Self::Cart {
+                    items,
+                    warnings,
+                    quote,
+                } => (
+                    StatusCode::OK,
+                    Json(json!({
+                        "items": items,
+                        "warnings": warnings,
+                        "quote": quote,
+                    })),
+                )
+                    .into_response(),
             }
         }
     }
@@ -44,7 +65,11 @@ pub mod response {
                     Json(json!({ "error": "Failed to fetch active cart" })),
                 )
                     .into_response(),
-                Self::CartNotFound => (StatusCode::OK, Json(json!([]))).into_response(),
+                Self::CartNotFound => (
+                    StatusCode::OK,
+                    Json(json!({ "items": [], "warnings": [], "quote": null })),
+                )
+                    .into_response(),
             }
         }
     }
@@ -0,0 +1,10 @@
+use super::types::response;
+use crate::{modules::cart::repository, types::Context};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>) -> response::Response {
+    repository::create_guest(&ctx.db_conn.pool)
+        .await
+        .map_err(|_| response::Error::FailedToCreateGuestCart)
+        .map(|cart| response::Success::GuestCartCreated(cart.token))
+}
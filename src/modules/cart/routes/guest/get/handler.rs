@@ -0,0 +1,9 @@
+use super::service::service;
+use crate::modules::auth::middleware::Guest;
+use crate::types::Context;
+use axum::{extract::State, response::IntoResponse};
+use std::sync::Arc;
+
+pub async fn handler(State(ctx): State<Arc<Context>>, guest: Guest) -> impl IntoResponse {
+    service(ctx, guest).await
+}
@@ -0,0 +1,55 @@
+use super::types::response::{self, KitchenWithMeals, MealWithQuantity};
+use crate::{
+    modules::{
+        auth::middleware::Guest,
+        cart::{repository, service as cart_service},
+        meal::repository::MealWithCartStatus,
+    },
+    types::Context,
+};
+use itertools::Itertools;
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, guest: Guest) -> response::Response {
+    let cart = repository::find_full_guest_cart_by_token(&ctx.db_conn.pool, guest.token)
+        .await
+        .map_err(|_| response::Error::FailedToFetchGuestCart)?
+        .ok_or(response::Error::GuestCartNotFound)?;
+
+    let warnings = cart_service::validate_items(&cart.items.0);
+
+    let items = cart
+        .items
+        .0
+        .into_iter()
+        .into_group_map_by(|item| item.kitchen.id.clone())
+        .into_values()
+        .map(|items| KitchenWithMeals {
+            kitchen: items[0].kitchen.clone(),
+            meals: items
+                .into_iter()
+                .map(|item| MealWithQuantity {
+                    quantity: item.quantity,
+                    meal: MealWithCartStatus {
+                        id: item.meal.id,
+                        name: item.meal.name,
+                        description: item.meal.description,
+                        rating: item.meal.rating,
+                        original_price: item.meal.original_price,
+                        price: item.meal.price,
+                        likes: item.meal.likes,
+                        cover_image: item.meal.cover_image,
+                        is_available: item.meal.is_available,
+                        kitchen_id: item.meal.kitchen_id,
+                        created_at: item.meal.created_at,
+                        updated_at: item.meal.updated_at,
+                        deleted_at: item.meal.deleted_at,
+                        in_cart: true,
+                    },
+                })
+                .collect::<Vec<_>>(),
+        })
+        .collect::<Vec<_>>();
+
+    Ok(response::Success::Cart { items, warnings })
+}
@@ -0,0 +1,63 @@
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde::Serialize;
+    use serde_json::json;
+
+    use crate::modules::{
+        cart::service::CartWarning, kitchen::repository::Kitchen,
+        meal::repository::MealWithCartStatus,
+    };
+
+    #[derive(Debug, Serialize)]
+    pub struct MealWithQuantity {
+        pub meal: MealWithCartStatus,
+        pub quantity: i32,
+    }
+
+    #[derive(Debug, Serialize)]
+    pub struct KitchenWithMeals {
+        pub kitchen: Kitchen,
+        pub meals: Vec<MealWithQuantity>,
+    }
+
+    pub enum Success {
+        Cart {
+            items: Vec<KitchenWithMeals>,
+            warnings: Vec<CartWarning>,
+        },
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::Cart { items, warnings } => (
+                    StatusCode::OK,
+                    Json(json!({ "items": items, "warnings": warnings })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToFetchGuestCart,
+        GuestCartNotFound,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToFetchGuestCart => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch guest cart" })),
+                )
+                    .into_response(),
+                Self::GuestCartNotFound => {
+                    (StatusCode::OK, Json(json!({ "items": [], "warnings": [] }))).into_response()
+                }
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
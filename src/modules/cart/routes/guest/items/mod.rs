@@ -0,0 +1,15 @@
+mod remove_meal;
+mod set_meal;
+
+use crate::types::Context;
+use axum::routing::Router;
+use std::sync::Arc;
+
+pub fn get_router() -> Router<Arc<Context>> {
+    Router::new().nest(
+        "/items",
+        Router::new()
+            .nest("/", set_meal::get_router())
+            .nest("/", remove_meal::get_router()),
+    )
+}
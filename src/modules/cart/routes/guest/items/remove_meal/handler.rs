@@ -0,0 +1,16 @@
+use super::service::service;
+use super::types::request;
+use crate::{modules::auth::middleware::Guest, types::Context};
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+pub async fn handler(
+    guest: Guest,
+    State(ctx): State<Arc<Context>>,
+    Path(meal_id): Path<String>,
+) -> impl IntoResponse {
+    service(ctx, guest, request::Payload { meal_id }).await
+}
@@ -0,0 +1,32 @@
+use super::types::{request, response};
+use crate::{
+    modules::{
+        auth::middleware::Guest,
+        cart::repository::{self, CartItems},
+    },
+    types::Context,
+};
+use std::sync::Arc;
+
+pub async fn service(
+    ctx: Arc<Context>,
+    guest: Guest,
+    payload: request::Payload,
+) -> response::Response {
+    let cart = repository::find_guest_by_token(&ctx.db_conn.pool, guest.token)
+        .await
+        .map_err(|_| response::Error::FailedToFindGuestCart)?
+        .ok_or(response::Error::GuestCartNotFound)?;
+
+    let mut items = cart.items.0;
+    let index = items
+        .iter()
+        .position(|item| item.meal_id == payload.meal_id)
+        .ok_or(response::Error::MealNotFoundInCart)?;
+    items.remove(index);
+
+    repository::update_guest_by_id(&ctx.db_conn.pool, cart.id, CartItems(items))
+        .await
+        .map_err(|_| response::Error::FailedToRemoveMealFromGuestCart)
+        .map(|_| response::Success::MealRemovedFromGuestCart)
+}
@@ -0,0 +1,49 @@
+use super::types::{request, response};
+use crate::{
+    modules::{
+        auth::middleware::Guest,
+        cart::repository::{self, CartItem, CartItems},
+        meal,
+    },
+    types::Context,
+};
+use std::sync::Arc;
+
+pub async fn service(
+    ctx: Arc<Context>,
+    guest: Guest,
+    payload: request::Payload,
+) -> response::Response {
+    let cart = repository::find_guest_by_token(&ctx.db_conn.pool, guest.token)
+        .await
+        .map_err(|_| response::Error::FailedToFindGuestCart)?
+        .ok_or(response::Error::GuestCartNotFound)?;
+
+    let meal = meal::repository::find_by_id(&ctx.db_conn.pool, payload.meal_id)
+        .await
+        .map_err(|_| response::Error::FailedToFetchMeal)?
+        .ok_or(response::Error::MealNotFound)?;
+
+    let mut items = cart.items.0;
+    match items.iter().position(|item| item.meal_id == meal.id) {
+        Some(index) if payload.body.quantity == 0 => {
+            items.remove(index);
+        }
+        Some(index) => {
+            items[index].quantity = payload.body.quantity;
+            items[index].price = Some(meal.price.clone());
+        }
+        // nothing to remove, and pushing it would leave a zero quantity line in the cart
+        None if payload.body.quantity == 0 => return Ok(response::Success::GuestCartUpdated),
+        None => items.push(CartItem {
+            meal_id: meal.id.clone(),
+            quantity: payload.body.quantity,
+            price: Some(meal.price.clone()),
+        }),
+    }
+
+    repository::update_guest_by_id(&ctx.db_conn.pool, cart.id, CartItems(items))
+        .await
+        .map_err(|_| response::Error::FailedToUpdateGuestCart)
+        .map(|_| response::Success::GuestCartUpdated)
+}
@@ -0,0 +1,76 @@
+pub mod request {
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    pub struct Body {
+        pub quantity: i32,
+    }
+
+    pub struct Payload {
+        pub meal_id: String,
+        pub body: Body,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        GuestCartUpdated,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::GuestCartUpdated => (
+                    StatusCode::OK,
+                    Json(json!({ "message": "Cart updated successfully" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        MealNotFound,
+        FailedToFetchMeal,
+        GuestCartNotFound,
+        FailedToFindGuestCart,
+        FailedToUpdateGuestCart,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToUpdateGuestCart => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to update cart" })),
+                )
+                    .into_response(),
+                Self::FailedToFindGuestCart => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to find cart" })),
+                )
+                    .into_response(),
+                Self::GuestCartNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Cart not found" })),
+                )
+                    .into_response(),
+                Self::FailedToFetchMeal => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch meal" })),
+                )
+                    .into_response(),
+                Self::MealNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Meal not found" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -0,0 +1,17 @@
+mod create;
+mod get;
+mod items;
+
+use crate::types::Context;
+use axum::routing::Router;
+use std::sync::Arc;
+
+pub fn get_router() -> Router<Arc<Context>> {
+    Router::new().nest(
+        "/guest",
+        Router::new()
+            .nest("/", create::get_router())
+            .nest("/", get::get_router())
+            .nest("/", items::get_router()),
+    )
+}
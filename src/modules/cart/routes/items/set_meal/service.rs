@@ -43,6 +43,7 @@ pub async fn service(
         if item.meal_id == meal.id.clone() {
             found = true;
             item.quantity = payload.body.quantity;
+            item.price = Some(meal.price.clone());
             break;
         }
         index += 1;
@@ -52,6 +53,7 @@ pub async fn service(
         items.push(CartItem {
             meal_id: meal.id.clone(),
             quantity: payload.body.quantity,
+            price: Some(meal.price.clone()),
         });
     } else {
         if payload.body.quantity == 0 {
@@ -1,6 +1,10 @@
 use super::types::{request, response};
 use crate::{
-    modules::{auth::middleware::Auth, cart::repository, order},
+    modules::{
+        auth::middleware::Auth,
+        cart::{repository, service as cart_service},
+        order,
+    },
     types::Context,
 };
 use std::sync::Arc;
@@ -47,6 +51,16 @@ pub async fn service(
         return Err(response::Error::NoItemsToCheckout);
     }
 
+    let warnings = cart_service::validate_items(&items_to_checkout);
+    if cart_service::has_blocking_warnings(&warnings) {
+        return Err(response::Error::CartHasUnavailableItems(warnings));
+    }
+
+    if !cart_service::is_quote_valid(&payload.body.quote_id, cart.id.clone(), &items_to_checkout) {
+        let quote = cart_service::generate_quote(cart.id.clone(), &items_to_checkout);
+        return Err(response::Error::StaleQuote(warnings, quote));
+    }
+
     let mut tx = ctx.db_conn.clone().pool.begin().await.map_err(|err| {
         tracing::error!("Failed to start database transaction: {}", err);
         response::Error::FailedToCheckoutCart
@@ -62,6 +76,7 @@ pub async fn service(
             dispatch_rider_note: payload.body.dispatch_rider_note.clone(),
             kitchen_id: payload.kitchen_id,
             owner_id: auth.user.id.clone(),
+            group_id: None,
         },
     )
     .await
@@ -8,6 +8,7 @@ pub mod request {
         pub delivery_address: String,
         pub delivery_date: Option<u64>,
         pub dispatch_rider_note: String,
+        pub quote_id: String,
     }
 
     #[derive(Deserialize)]
@@ -21,7 +22,10 @@ pub mod response {
     use axum::{extract::Json, http::StatusCode, response::IntoResponse};
     use serde_json::json;
 
-    use crate::modules::order::repository::Order;
+    use crate::modules::{
+        cart::service::{CartWarning, Quote},
+        order::repository::Order,
+    };
 
     pub enum Success {
         CheckoutSuccessful(Order),
@@ -46,6 +50,8 @@ pub mod response {
         CartNotFound,
         FailedToFindCart,
         NoItemsToCheckout,
+        CartHasUnavailableItems(Vec<CartWarning>),
+        StaleQuote(Vec<CartWarning>, Quote),
         FailedToCheckoutCart,
     }
 
@@ -62,6 +68,23 @@ pub mod response {
                     Json(json!({ "error": "No items to checkout!" })),
                 )
                     .into_response(),
+                Self::CartHasUnavailableItems(warnings) => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({
+                        "error": "Cart contains items that can no longer be ordered",
+                        "warnings": warnings,
+                    })),
+                )
+                    .into_response(),
+                Self::StaleQuote(warnings, quote) => (
+                    StatusCode::CONFLICT,
+                    Json(json!({
+                        "error": "Cart has changed since it was quoted",
+                        "warnings": warnings,
+                        "quote": quote,
+                    })),
+                )
+                    .into_response(),
                 Self::FailedToFindCart => (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     Json(json!({"error": "Failed to find cart"})),
@@ -27,6 +27,7 @@ pub async fn service(
         .map(|item| CartItem {
             meal_id: item.meal.id,
             quantity: item.quantity,
+            price: item.price,
         })
         .collect::<Vec<_>>();
 
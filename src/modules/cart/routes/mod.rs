@@ -1,4 +1,6 @@
+mod checkout;
 mod get;
+mod guest;
 mod items;
 mod kitchens;
 
@@ -9,6 +11,8 @@ use std::sync::Arc;
 pub fn get_router() -> Router<Arc<Context>> {
     Router::new()
         .nest("/", get::get_router())
+        .nest("/", guest::get_router())
+        .nest("/", checkout::get_router())
         .nest("/", items::get_router())
         .nest("/", kitchens::get_router())
 }
@@ -0,0 +1,231 @@
+use bigdecimal::BigDecimal;
+use itertools::Itertools;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use sqlx::{Postgres, Transaction};
+
+use super::repository::{self, CartItem, CartItems, FullCart, FullCartItem};
+use crate::modules::{kitchen, meal};
+
+pub enum Error {
+    UnexpectedError,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub enum UnavailableCartItemReason {
+    #[serde(rename = "MEAL_DELETED")]
+    MealDeleted,
+    #[serde(rename = "MEAL_UNAVAILABLE")]
+    MealUnavailable,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub enum ClosedKitchenReason {
+    #[serde(rename = "UNAVAILABLE")]
+    Unavailable,
+    #[serde(rename = "OUTSIDE_OPENING_HOURS")]
+    OutsideOpeningHours,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(tag = "type")]
+pub enum CartWarning {
+    #[serde(rename = "ITEM_UNAVAILABLE")]
+    ItemUnavailable {
+        meal_id: String,
+        name: String,
+        reason: UnavailableCartItemReason,
+    },
+    #[serde(rename = "PRICE_CHANGED")]
+    PriceChanged {
+        meal_id: String,
+        name: String,
+        previous_price: BigDecimal,
+        current_price: BigDecimal,
+    },
+    #[serde(rename = "KITCHEN_CLOSED")]
+    KitchenClosed {
+        kitchen_id: String,
+        name: String,
+        reason: ClosedKitchenReason,
+    },
+}
+
+impl CartWarning {
+    // NOTE: price changes and opening hours are surfaced to the client but do not stop a checkout
+    pub fn is_blocking(&self) -> bool {
+        match self {
+            CartWarning::ItemUnavailable { .. } => true,
+            CartWarning::KitchenClosed { reason, .. } => match reason {
+                ClosedKitchenReason::Unavailable => true,
+                ClosedKitchenReason::OutsideOpeningHours => false,
+            },
+            CartWarning::PriceChanged { .. } => false,
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct Quote {
+    pub id: String,
+    pub total: BigDecimal,
+}
+
+pub fn validate_items(items: &Vec<FullCartItem>) -> Vec<CartWarning> {
+    let now = chrono::Utc::now().naive_utc();
+    let mut warnings = vec![];
+
+    for item in items.iter() {
+        if item.meal.deleted_at.is_some() {
+            warnings.push(CartWarning::ItemUnavailable {
+                meal_id: item.meal_id.clone(),
+                name: item.meal.name.clone(),
+                reason: UnavailableCartItemReason::MealDeleted,
+            });
+            continue;
+        }
+
+        if !meal::repository::is_orderable(&item.meal) {
+            warnings.push(CartWarning::ItemUnavailable {
+                meal_id: item.meal_id.clone(),
+                name: item.meal.name.clone(),
+                reason: UnavailableCartItemReason::MealUnavailable,
+            });
+            continue;
+        }
+
+        if let Some(price) = item.price.clone() {
+            if price != item.meal.price {
+                warnings.push(CartWarning::PriceChanged {
+                    meal_id: item.meal_id.clone(),
+                    name: item.meal.name.clone(),
+                    previous_price: price,
+                    current_price: item.meal.price.clone(),
+                });
+            }
+        }
+    }
+
+    for kitchen in items
+        .iter()
+        .map(|item| &item.kitchen)
+        .unique_by(|kitchen| kitchen.id.clone())
+    {
+        if !kitchen::repository::is_accepting_orders(kitchen) {
+            warnings.push(CartWarning::KitchenClosed {
+                kitchen_id: kitchen.id.clone(),
+                name: kitchen.name.clone(),
+                reason: ClosedKitchenReason::Unavailable,
+            });
+        } else if !kitchen::repository::is_open_at(kitchen, now) {
+            warnings.push(CartWarning::KitchenClosed {
+                kitchen_id: kitchen.id.clone(),
+                name: kitchen.name.clone(),
+                reason: ClosedKitchenReason::OutsideOpeningHours,
+            });
+        }
+    }
+
+    warnings
+}
+
+pub fn validate(cart: &FullCart) -> Vec<CartWarning> {
+    validate_items(&cart.items.0)
+}
+
+pub fn has_blocking_warnings(warnings: &Vec<CartWarning>) -> bool {
+    warnings.iter().any(|warning| warning.is_blocking())
+}
+
+pub fn calculate_total(items: &Vec<FullCartItem>) -> BigDecimal {
+    items.iter().fold(BigDecimal::from(0), |acc, item| {
+        acc + (item.meal.price.clone() * BigDecimal::from(item.quantity))
+    })
+}
+
+// NOTE: the quote id is derived from the live prices so that it goes stale as soon as anything in the cart changes
+pub fn generate_quote(cart_id: String, items: &Vec<FullCartItem>) -> Quote {
+    let lines = items
+        .iter()
+        .map(|item| format!("{}:{}:{}", item.meal_id, item.quantity, item.meal.price))
+        .sorted()
+        .join(",");
+
+    let mut hasher = Sha256::new();
+    hasher.update(format!("{}|{}", cart_id, lines));
+    let hash = hasher.finalize();
+
+    Quote {
+        id: base16ct::lower::encode_string(&hash),
+        total: calculate_total(items),
+    }
+}
+
+pub fn is_quote_valid(quote_id: &String, cart_id: String, items: &Vec<FullCartItem>) -> bool {
+    generate_quote(cart_id, items).id == *quote_id
+}
+
+// NOTE: when both carts contain the same meal the larger quantity wins, so merging never silently drops something the user picked
+pub fn merge_items(items: CartItems, guest_items: CartItems) -> CartItems {
+    let mut merged = items.0;
+
+    for guest_item in guest_items.0.into_iter() {
+        match merged
+            .iter_mut()
+            .find(|item| item.meal_id == guest_item.meal_id)
+        {
+            Some(item) => {
+                if guest_item.quantity > item.quantity {
+                    item.quantity = guest_item.quantity;
+                    item.price = guest_item.price;
+                }
+            }
+            None => merged.push(CartItem {
+                meal_id: guest_item.meal_id,
+                quantity: guest_item.quantity,
+                price: guest_item.price,
+            }),
+        }
+    }
+
+    CartItems(merged)
+}
+
+pub async fn merge_guest_cart(
+    tx: &mut Transaction<'_, Postgres>,
+    owner_id: String,
+    token: String,
+) -> Result<(), Error> {
+    let guest_cart = match repository::find_guest_by_token(&mut **tx, token)
+        .await
+        .map_err(|_| Error::UnexpectedError)?
+    {
+        Some(guest_cart) => guest_cart,
+        None => return Ok(()),
+    };
+
+    let cart = match repository::find_active_cart_by_owner_id(&mut **tx, owner_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?
+    {
+        Some(cart) => cart,
+        None => repository::create(&mut **tx, repository::CreateCartPayload { owner_id })
+            .await
+            .map_err(|_| Error::UnexpectedError)?,
+    };
+
+    repository::update_by_id(
+        &mut **tx,
+        cart.id,
+        repository::UpdateCartPayload {
+            items: Some(merge_items(cart.items, guest_cart.items)),
+            status: None,
+        },
+    )
+    .await
+    .map_err(|_| Error::UnexpectedError)?;
+
+    repository::delete_guest_by_id(&mut **tx, guest_cart.id)
+        .await
+        .map_err(|_| Error::UnexpectedError)
+}
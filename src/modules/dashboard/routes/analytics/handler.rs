@@ -2,12 +2,16 @@ use super::{
     service::service,
     types::{request, response},
 };
-use crate::{modules::auth::middleware::AdminAuth, types::Context, utils::pagination::Pagination};
+use crate::{
+    modules::auth::{middleware::RequirePermission, permission},
+    types::Context,
+    utils::pagination::Pagination,
+};
 use axum::extract::{Query, State};
 use std::sync::Arc;
 
 pub async fn handler(
-    _: AdminAuth,
+    _: RequirePermission<permission::ViewAnalytics>,
     State(ctx): State<Arc<Context>>,
     Query(filters): Query<request::Filters>,
     pagination: Pagination,
@@ -12,8 +12,13 @@ pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::
             .map_err(|_| response::Error::FailedToFetchUser)?
             .ok_or(response::Error::UserNotFound)?;
 
-    auth::service::auth::create_session(&ctx.db_conn.pool, user.id)
-        .await
-        .map_err(|_| response::Error::FailedToCreateSession)
-        .map(response::Success::Tokens)
+    auth::service::auth::create_session(
+        &ctx,
+        &ctx.db_conn.pool,
+        &user,
+        auth::service::auth::Device::default(),
+    )
+    .await
+    .map_err(|_| response::Error::FailedToCreateSession)
+    .map(response::Success::Tokens)
 }
@@ -12,20 +12,20 @@ pub mod response {
     use axum::{extract::Json, http::StatusCode, response::IntoResponse};
     use serde_json::json;
 
-    use crate::modules::auth::repository::session::Session;
+    use crate::modules::auth::service::auth::SessionTokens;
 
     pub enum Success {
-        Tokens(Session),
+        Tokens(SessionTokens),
     }
 
     impl IntoResponse for Success {
         fn into_response(self) -> axum::response::Response {
             match self {
-                Self::Tokens(session) => (
+                Self::Tokens(tokens) => (
                     StatusCode::OK,
                     Json(json!({
-                        "access_token": session.access_token,
-                        "refresh_token": session.refresh_token,
+                        "access_token": tokens.access_token,
+                        "refresh_token": tokens.refresh_token,
                     })),
                 )
                     .into_response(),
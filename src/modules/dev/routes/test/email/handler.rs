@@ -1,8 +1,8 @@
 use super::service::service;
-use crate::{modules::auth::middleware::Auth, types::Context};
+use crate::{modules::auth::middleware::UserAuth, types::Context};
 use axum::{extract::State, response::IntoResponse};
 use std::sync::Arc;
 
-pub async fn handler(auth: Auth, State(ctx): State<Arc<Context>>) -> impl IntoResponse {
+pub async fn handler(auth: UserAuth, State(ctx): State<Arc<Context>>) -> impl IntoResponse {
     service(ctx, auth).await
 }
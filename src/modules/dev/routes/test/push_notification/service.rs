@@ -1,14 +1,15 @@
 use super::types::response;
 use crate::{
-    modules::{auth::middleware::Auth, notification},
+    modules::{auth::middleware::UserAuth, notification},
     types::Context,
 };
 use std::sync::Arc;
 
-pub async fn service(ctx: Arc<Context>, auth: Auth) -> response::Response {
+pub async fn service(ctx: Arc<Context>, auth: UserAuth) -> response::Response {
     notification::service::send(
         ctx,
         notification::service::Notification::registered(auth.user),
+        notification::service::Category::Transactional,
         notification::service::Backend::Push,
     )
     .await
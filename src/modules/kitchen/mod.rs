@@ -3,3 +3,4 @@ pub mod types;
 
 pub use routes::*;
 pub mod repository;
+pub mod service;
@@ -58,6 +58,9 @@ pub struct Kitchen {
     pub likes: i32,
     pub city_id: String,
     pub city: KitchenCity,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub delivery_radius: Option<f64>,
     pub is_available: bool,
     pub is_blocked: bool,
     pub is_verified: bool,
@@ -298,6 +301,258 @@ impl ToString for QueryerRole {
     }
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum KitchenMemberRole {
+    #[serde(rename = "MANAGER")]
+    Manager,
+    #[serde(rename = "STAFF")]
+    Staff,
+}
+
+impl From<String> for KitchenMemberRole {
+    fn from(value: String) -> Self {
+        match value.as_ref() {
+            "MANAGER" => KitchenMemberRole::Manager,
+            "STAFF" => KitchenMemberRole::Staff,
+            role => unreachable!("Invalid kitchen member role: {}", role),
+        }
+    }
+}
+
+impl ToString for KitchenMemberRole {
+    fn to_string(&self) -> String {
+        match self {
+            KitchenMemberRole::Manager => String::from("MANAGER"),
+            KitchenMemberRole::Staff => String::from("STAFF"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum KitchenMemberStatus {
+    #[serde(rename = "INVITED")]
+    Invited,
+    #[serde(rename = "ACTIVE")]
+    Active,
+}
+
+impl From<String> for KitchenMemberStatus {
+    fn from(value: String) -> Self {
+        match value.as_ref() {
+            "INVITED" => KitchenMemberStatus::Invited,
+            "ACTIVE" => KitchenMemberStatus::Active,
+            status => unreachable!("Invalid kitchen member status: {}", status),
+        }
+    }
+}
+
+impl ToString for KitchenMemberStatus {
+    fn to_string(&self) -> String {
+        match self {
+            KitchenMemberStatus::Invited => String::from("INVITED"),
+            KitchenMemberStatus::Active => String::from("ACTIVE"),
+        }
+    }
+}
+
+// NOTE: a user can only belong to one kitchen at a time, which is what `kitchen_members.user_id` being unique enforces
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KitchenMember {
+    pub id: String,
+    pub kitchen_id: String,
+    pub user_id: String,
+    pub role: KitchenMemberRole,
+    pub status: KitchenMemberStatus,
+    pub invited_by: Option<String>,
+    pub user: User,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+pub struct CreateKitchenMemberPayload {
+    pub kitchen_id: String,
+    pub user_id: String,
+    pub role: KitchenMemberRole,
+    pub status: KitchenMemberStatus,
+    pub invited_by: Option<String>,
+}
+
+pub async fn create_member<'e, E: PgExecutor<'e>>(
+    e: E,
+    payload: CreateKitchenMemberPayload,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "
+        INSERT INTO kitchen_members (id, kitchen_id, user_id, role, status, invited_by)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ",
+        Ulid::new().to_string(),
+        payload.kitchen_id,
+        payload.user_id,
+        payload.role.to_string(),
+        payload.status.to_string(),
+        payload.invited_by
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to add user {} to kitchen {}: {}",
+            payload.user_id,
+            payload.kitchen_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_member_by_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+) -> Result<Option<KitchenMember>, Error> {
+    sqlx::query_as!(
+        KitchenMember,
+        r#"
+        SELECT
+            kitchen_members.*,
+            TO_JSONB(users) AS "user!: sqlx::types::Json<User>"
+        FROM
+            kitchen_members,
+            users
+        WHERE
+            kitchen_members.id = $1
+            AND kitchen_members.user_id = users.id
+        "#,
+        id
+    )
+    .fetch_optional(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch kitchen member by id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_member_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<Option<KitchenMember>, Error> {
+    sqlx::query_as!(
+        KitchenMember,
+        r#"
+        SELECT
+            kitchen_members.*,
+            TO_JSONB(users) AS "user!: sqlx::types::Json<User>"
+        FROM
+            kitchen_members,
+            users
+        WHERE
+            kitchen_members.user_id = $1
+            AND kitchen_members.user_id = users.id
+        "#,
+        user_id
+    )
+    .fetch_optional(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch kitchen membership of user {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_many_members_by_kitchen_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    kitchen_id: String,
+) -> Result<Vec<KitchenMember>, Error> {
+    sqlx::query_as!(
+        KitchenMember,
+        r#"
+        SELECT
+            kitchen_members.*,
+            TO_JSONB(users) AS "user!: sqlx::types::Json<User>"
+        FROM
+            kitchen_members,
+            users
+        WHERE
+            kitchen_members.kitchen_id = $1
+            AND kitchen_members.user_id = users.id
+        ORDER BY kitchen_members.created_at DESC
+        "#,
+        kitchen_id
+    )
+    .fetch_all(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch members of kitchen {}: {}",
+            kitchen_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn activate_member_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<(), Error> {
+    sqlx::query!(
+        "UPDATE kitchen_members SET status = $1, updated_at = NOW() WHERE id = $2",
+        KitchenMemberStatus::Active.to_string(),
+        id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to activate kitchen member by id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn delete_member_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<(), Error> {
+    sqlx::query!("DELETE FROM kitchen_members WHERE id = $1", id)
+        .execute(e)
+        .await
+        .map(|_| ())
+        .map_err(|err| {
+            tracing::error!(
+                "Error occurred while trying to delete kitchen member by id {}: {}",
+                id,
+                err
+            );
+            Error::UnexpectedError
+        })
+}
+
+pub async fn delete_member_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<(), Error> {
+    sqlx::query!("DELETE FROM kitchen_members WHERE user_id = $1", user_id)
+        .execute(e)
+        .await
+        .map(|_| ())
+        .map_err(|err| {
+            tracing::error!(
+                "Error occurred while trying to delete kitchen membership of user {}: {}",
+                user_id,
+                err
+            );
+            Error::UnexpectedError
+        })
+}
+
 pub struct FindManyFilters {
     pub r#type: Option<String>,
     pub search: Option<String>,
@@ -481,6 +736,9 @@ pub struct UpdateKitchenPayload {
     pub cover_image: Option<storage::UploadedMedia>,
     pub rating: Option<BigDecimal>,
     pub likes: Option<i32>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub delivery_radius: Option<f64>,
     pub is_available: Option<bool>,
 }
 
@@ -507,9 +765,12 @@ pub async fn update_by_id<'e, E: PgExecutor<'e>>(
                 rating = COALESCE($10, rating),
                 likes = COALESCE($11, likes),
                 is_available = COALESCE($12, is_available),
+                latitude = COALESCE($13, latitude),
+                longitude = COALESCE($14, longitude),
+                delivery_radius = COALESCE($15, delivery_radius),
                 updated_at = NOW()
             WHERE
-                id = $13
+                id = $16
         ",
         payload.name,
         payload.address,
@@ -523,6 +784,9 @@ pub async fn update_by_id<'e, E: PgExecutor<'e>>(
         payload.rating,
         payload.likes,
         payload.is_available,
+        payload.latitude,
+        payload.longitude,
+        payload.delivery_radius,
         id,
     )
     .execute(e)
@@ -716,3 +980,26 @@ pub async fn unverify_by_id<'e, E: PgExecutor<'e>>(executor: E, id: String) -> R
 pub fn is_owner(user: &User, kitchen: &Kitchen) -> bool {
     kitchen.owner_id == user.id
 }
+
+pub fn is_accepting_orders(kitchen: &Kitchen) -> bool {
+    kitchen.is_available && !kitchen.is_blocked && kitchen.is_verified
+}
+
+// NOTE: opening and closing times are stored as "HH:MM" in West Africa Time (UTC+1)
+pub fn is_open_at(kitchen: &Kitchen, time: NaiveDateTime) -> bool {
+    let local_time = (time + chrono::Duration::hours(1)).time();
+
+    let (opening_time, closing_time) = match (
+        chrono::NaiveTime::parse_from_str(&kitchen.opening_time, "%H:%M"),
+        chrono::NaiveTime::parse_from_str(&kitchen.closing_time, "%H:%M"),
+    ) {
+        (Ok(opening_time), Ok(closing_time)) => (opening_time, closing_time),
+        _ => return true,
+    };
+
+    if opening_time <= closing_time {
+        local_time >= opening_time && local_time < closing_time
+    } else {
+        local_time >= opening_time || local_time < closing_time
+    }
+}
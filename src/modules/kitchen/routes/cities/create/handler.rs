@@ -1,6 +1,9 @@
 use super::service::service;
 use super::types::request;
-use crate::{modules::auth::middleware::AdminAuth, types::Context};
+use crate::{
+    modules::auth::{middleware::RequirePermission, permission},
+    types::Context,
+};
 use axum::{
     extract::{Json, State},
     response::IntoResponse,
@@ -8,7 +11,7 @@ use axum::{
 use std::sync::Arc;
 
 pub async fn handler(
-    _: AdminAuth,
+    _: RequirePermission<permission::ManageKitchens>,
     State(ctx): State<Arc<Context>>,
     Json(payload): Json<request::Payload>,
 ) -> impl IntoResponse {
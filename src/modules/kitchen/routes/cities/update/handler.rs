@@ -1,5 +1,8 @@
 use super::{service::service, types::request};
-use crate::{modules::auth::middleware::AdminAuth, types::Context};
+use crate::{
+    modules::auth::{middleware::RequirePermission, permission},
+    types::Context,
+};
 use axum::{
     extract::{Json, Path, State},
     response::IntoResponse,
@@ -7,7 +10,7 @@ use axum::{
 use std::sync::Arc;
 
 pub async fn handler(
-    _: AdminAuth,
+    _: RequirePermission<permission::ManageKitchens>,
     State(ctx): State<Arc<Context>>,
     Path(id): Path<String>,
     Json(body): Json<request::Body>,
@@ -57,6 +57,7 @@ pub async fn service(
             first_name: None,
             phone_number: None,
             profile_picture: None,
+            locale: None,
         },
     )
     .await
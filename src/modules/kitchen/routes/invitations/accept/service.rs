@@ -0,0 +1,30 @@
+use super::types::{request, response};
+use crate::{modules::kitchen::repository, types::Context};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let member = repository::find_member_by_id(&ctx.db_conn.pool, payload.id)
+        .await
+        .map_err(|_| response::Error::FailedToAcceptInvitation)?
+        .ok_or(response::Error::InvitationNotFound)?;
+
+    if member.user_id != payload.auth.user.id
+        || member.status != repository::KitchenMemberStatus::Invited
+    {
+        return Err(response::Error::InvitationNotFound);
+    }
+
+    // NOTE: the user might have created a kitchen of their own since they were invited
+    if repository::find_by_owner_id(&ctx.db_conn.pool, payload.auth.user.id)
+        .await
+        .map_err(|_| response::Error::FailedToAcceptInvitation)?
+        .is_some()
+    {
+        return Err(response::Error::UserOwnsKitchen);
+    }
+
+    repository::activate_member_by_id(&ctx.db_conn.pool, member.id)
+        .await
+        .map_err(|_| response::Error::FailedToAcceptInvitation)
+        .map(|_| response::Success::InvitationAccepted)
+}
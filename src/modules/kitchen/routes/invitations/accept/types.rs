@@ -0,0 +1,59 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub id: String,
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        InvitationAccepted,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::InvitationAccepted => (
+                    StatusCode::OK,
+                    Json(json!({ "message": "Invitation accepted" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToAcceptInvitation,
+        InvitationNotFound,
+        UserOwnsKitchen,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToAcceptInvitation => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to accept invitation" })),
+                )
+                    .into_response(),
+                Self::InvitationNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Invitation not found" })),
+                )
+                    .into_response(),
+                Self::UserOwnsKitchen => (
+                    StatusCode::CONFLICT,
+                    Json(json!({ "error": "You cannot join another kitchen while you own one" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -0,0 +1,21 @@
+use super::types::{request, response};
+use crate::{modules::kitchen::repository, types::Context};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let member = repository::find_member_by_id(&ctx.db_conn.pool, payload.id)
+        .await
+        .map_err(|_| response::Error::FailedToDeclineInvitation)?
+        .ok_or(response::Error::InvitationNotFound)?;
+
+    if member.user_id != payload.auth.user.id
+        || member.status != repository::KitchenMemberStatus::Invited
+    {
+        return Err(response::Error::InvitationNotFound);
+    }
+
+    repository::delete_member_by_id(&ctx.db_conn.pool, member.id)
+        .await
+        .map_err(|_| response::Error::FailedToDeclineInvitation)
+        .map(|_| response::Success::InvitationDeclined)
+}
@@ -0,0 +1,53 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub id: String,
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        InvitationDeclined,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::InvitationDeclined => (
+                    StatusCode::OK,
+                    Json(json!({ "message": "Invitation declined" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToDeclineInvitation,
+        InvitationNotFound,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToDeclineInvitation => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to decline invitation" })),
+                )
+                    .into_response(),
+                Self::InvitationNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Invitation not found" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
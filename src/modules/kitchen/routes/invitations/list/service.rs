@@ -0,0 +1,24 @@
+use super::types::{request, response};
+use crate::{modules::kitchen::repository, types::Context};
+use std::sync::Arc;
+
+// NOTE: a user belongs to at most one kitchen, so there is at most one pending invitation
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let member = match repository::find_member_by_user_id(&ctx.db_conn.pool, payload.auth.user.id)
+        .await
+        .map_err(|_| response::Error::FailedToFetchInvitations)?
+    {
+        Some(member) if member.status == repository::KitchenMemberStatus::Invited => member,
+        _ => return Ok(response::Success::Invitations(vec![])),
+    };
+
+    let kitchen = repository::find_by_id(&ctx.db_conn.pool, member.kitchen_id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToFetchInvitations)?;
+
+    Ok(response::Success::Invitations(
+        kitchen
+            .map(|kitchen| vec![response::Invitation { member, kitchen }])
+            .unwrap_or_default(),
+    ))
+}
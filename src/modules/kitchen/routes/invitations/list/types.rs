@@ -0,0 +1,54 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde::Serialize;
+    use serde_json::json;
+
+    use crate::modules::kitchen::repository::{Kitchen, KitchenMember};
+
+    #[derive(Serialize)]
+    pub struct Invitation {
+        #[serde(flatten)]
+        pub member: KitchenMember,
+        pub kitchen: Kitchen,
+    }
+
+    pub enum Success {
+        Invitations(Vec<Invitation>),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::Invitations(invitations) => {
+                    (StatusCode::OK, Json(json!(invitations))).into_response()
+                }
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToFetchInvitations,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToFetchInvitations => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch invitations" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
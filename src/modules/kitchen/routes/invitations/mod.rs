@@ -0,0 +1,17 @@
+mod accept;
+mod decline;
+mod list;
+
+use crate::types::Context;
+use axum::routing::Router;
+use std::sync::Arc;
+
+pub fn get_router() -> Router<Arc<Context>> {
+    Router::new().nest(
+        "/invitations",
+        Router::new()
+            .nest("/", list::get_router())
+            .nest("/", accept::get_router())
+            .nest("/", decline::get_router()),
+    )
+}
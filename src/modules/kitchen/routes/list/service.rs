@@ -1,6 +1,9 @@
 use super::types::{request, response};
 use crate::{
-    modules::{auth::middleware::Auth, kitchen::repository, user},
+    modules::{
+        auth::{middleware::Auth, permission::Permission},
+        kitchen::repository,
+    },
     types::Context,
 };
 use std::sync::Arc;
@@ -16,7 +19,12 @@ pub async fn service(
         queryer_role: repository::QueryerRole::User,
     };
 
-    if auth.is_some() && user::repository::is_admin(&auth.unwrap().user) {
+    if auth.is_some()
+        && auth
+            .unwrap()
+            .user
+            .has_permission(&Permission::ManageKitchens)
+    {
         filters.queryer_role = repository::QueryerRole::Admin;
     }
 
@@ -2,6 +2,7 @@ mod block;
 mod cities;
 mod create;
 mod get;
+mod invitations;
 mod like;
 mod list;
 mod profile;
@@ -33,4 +34,5 @@ pub fn get_router() -> Router<Arc<Context>> {
         .nest("/", cities::get_router())
         .nest("/", verify::get_router())
         .nest("/", unverify::get_router())
+        .nest("/", invitations::get_router())
 }
@@ -1,11 +1,11 @@
 use super::types::{request, response};
-use crate::{modules::kitchen::repository, types::Context};
+use crate::{modules::kitchen::service, types::Context};
 use std::sync::Arc;
 
 pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
-    repository::find_by_owner_id(&ctx.db_conn.pool, payload.auth.user.id)
+    service::find_access_by_user_id(ctx, payload.auth.user.id)
         .await
         .map_err(|_| response::Error::FailedToFetchKitchen)?
         .ok_or(response::Error::KitchenNotFound)
-        .map(response::Success::Kitchen)
+        .map(|access| response::Success::Kitchen(access.kitchen))
 }
@@ -0,0 +1,90 @@
+use super::types::{request, response};
+use crate::{
+    modules::{
+        kitchen::{self, repository},
+        notification, user,
+    },
+    types::Context,
+};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let access =
+        kitchen::service::find_access_by_user_id(ctx.clone(), payload.auth.user.id.clone())
+            .await
+            .map_err(|_| response::Error::FailedToInviteMember)?
+            .ok_or(response::Error::KitchenNotFound)?;
+
+    if !access.is_owner() {
+        return Err(response::Error::NotAllowed);
+    }
+
+    let invitee =
+        user::repository::find_by_phone_number(&ctx.db_conn.pool, payload.body.phone_number)
+            .await
+            .map_err(|_| response::Error::FailedToInviteMember)?
+            .ok_or(response::Error::UserNotFound)?;
+
+    if invitee.id == payload.auth.user.id {
+        return Err(response::Error::CannotInviteSelf);
+    }
+
+    if repository::find_by_owner_id(&ctx.db_conn.pool, invitee.id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToInviteMember)?
+        .is_some()
+    {
+        return Err(response::Error::UserOwnsKitchen);
+    }
+
+    if repository::find_member_by_user_id(&ctx.db_conn.pool, invitee.id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToInviteMember)?
+        .is_some()
+    {
+        return Err(response::Error::UserAlreadyMember);
+    }
+
+    let mut tx = ctx.db_conn.pool.begin().await.map_err(|err| {
+        tracing::error!("Failed to start database transaction: {}", err);
+        response::Error::FailedToInviteMember
+    })?;
+
+    repository::create_member(
+        &mut *tx,
+        repository::CreateKitchenMemberPayload {
+            kitchen_id: access.kitchen.id.clone(),
+            user_id: invitee.id.clone(),
+            role: payload.body.role.clone(),
+            status: repository::KitchenMemberStatus::Invited,
+            invited_by: Some(payload.auth.user.id),
+        },
+    )
+    .await
+    .map_err(|_| response::Error::FailedToInviteMember)?;
+
+    let notification = notification::service::Notification::kitchen_invitation_received(
+        invitee,
+        access.kitchen,
+        payload.body.role,
+    );
+
+    notification::service::enqueue(
+        &mut *tx,
+        notification,
+        notification::service::Category::Transactional,
+        vec![
+            notification::service::Backend::Push,
+            notification::service::Backend::Sms,
+        ],
+    )
+    .await
+    .map_err(|_| response::Error::FailedToInviteMember)?;
+
+    tx.commit().await.map_err(|err| {
+        tracing::error!("Failed to commit database transaction: {}", err);
+        response::Error::FailedToInviteMember
+    })?;
+
+    Ok(response::Success::MemberInvited)
+}
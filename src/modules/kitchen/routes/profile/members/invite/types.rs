@@ -0,0 +1,90 @@
+pub mod request {
+    use crate::modules::{auth::middleware::Auth, kitchen::repository::KitchenMemberRole};
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    pub struct Body {
+        pub phone_number: String,
+        pub role: KitchenMemberRole,
+    }
+
+    pub struct Payload {
+        pub auth: Auth,
+        pub body: Body,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        MemberInvited,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::MemberInvited => (
+                    StatusCode::CREATED,
+                    Json(json!({ "message": "Invitation sent" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToInviteMember,
+        KitchenNotFound,
+        NotAllowed,
+        UserNotFound,
+        CannotInviteSelf,
+        UserOwnsKitchen,
+        UserAlreadyMember,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToInviteMember => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to invite member" })),
+                )
+                    .into_response(),
+                Self::KitchenNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Kitchen not found" })),
+                )
+                    .into_response(),
+                Self::NotAllowed => (
+                    StatusCode::FORBIDDEN,
+                    Json(json!({ "error": "Only the kitchen's owner can invite members" })),
+                )
+                    .into_response(),
+                Self::UserNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "No user with that phone number" })),
+                )
+                    .into_response(),
+                Self::CannotInviteSelf => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "You cannot invite yourself" })),
+                )
+                    .into_response(),
+                Self::UserOwnsKitchen => (
+                    StatusCode::CONFLICT,
+                    Json(json!({ "error": "User already owns a kitchen" })),
+                )
+                    .into_response(),
+                Self::UserAlreadyMember => (
+                    StatusCode::CONFLICT,
+                    Json(json!({ "error": "User already belongs to or has been invited to a kitchen" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
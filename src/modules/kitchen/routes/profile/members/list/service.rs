@@ -0,0 +1,22 @@
+use super::types::{request, response};
+use crate::{
+    modules::kitchen::{repository, service},
+    types::Context,
+};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let access = service::find_access_by_user_id(ctx.clone(), payload.auth.user.id)
+        .await
+        .map_err(|_| response::Error::FailedToFetchMembers)?
+        .ok_or(response::Error::KitchenNotFound)?;
+
+    if !access.can_view_members() {
+        return Err(response::Error::NotAllowed);
+    }
+
+    repository::find_many_members_by_kitchen_id(&ctx.db_conn.pool, access.kitchen.id)
+        .await
+        .map_err(|_| response::Error::FailedToFetchMembers)
+        .map(response::Success::Members)
+}
@@ -0,0 +1,56 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    use crate::modules::kitchen::repository::KitchenMember;
+
+    pub enum Success {
+        Members(Vec<KitchenMember>),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::Members(members) => (StatusCode::OK, Json(json!(members))).into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToFetchMembers,
+        KitchenNotFound,
+        NotAllowed,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToFetchMembers => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch kitchen members" })),
+                )
+                    .into_response(),
+                Self::KitchenNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Kitchen not found" })),
+                )
+                    .into_response(),
+                Self::NotAllowed => (
+                    StatusCode::FORBIDDEN,
+                    Json(json!({ "error": "Only the kitchen's owner and managers can view its members" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
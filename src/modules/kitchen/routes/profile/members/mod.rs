@@ -0,0 +1,17 @@
+mod invite;
+mod list;
+mod remove;
+
+use crate::types::Context;
+use axum::routing::Router;
+use std::sync::Arc;
+
+pub fn get_router() -> Router<Arc<Context>> {
+    Router::new().nest(
+        "/members",
+        Router::new()
+            .nest("/", list::get_router())
+            .nest("/", invite::get_router())
+            .nest("/", remove::get_router()),
+    )
+}
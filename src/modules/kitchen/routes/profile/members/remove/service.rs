@@ -0,0 +1,31 @@
+use super::types::{request, response};
+use crate::{
+    modules::kitchen::{repository, service},
+    types::Context,
+};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let access = service::find_access_by_user_id(ctx.clone(), payload.auth.user.id)
+        .await
+        .map_err(|_| response::Error::FailedToRemoveMember)?
+        .ok_or(response::Error::KitchenNotFound)?;
+
+    if !access.is_owner() {
+        return Err(response::Error::NotAllowed);
+    }
+
+    let member = repository::find_member_by_id(&ctx.db_conn.pool, payload.id)
+        .await
+        .map_err(|_| response::Error::FailedToRemoveMember)?
+        .ok_or(response::Error::MemberNotFound)?;
+
+    if member.kitchen_id != access.kitchen.id {
+        return Err(response::Error::MemberNotFound);
+    }
+
+    repository::delete_member_by_id(&ctx.db_conn.pool, member.id)
+        .await
+        .map_err(|_| response::Error::FailedToRemoveMember)
+        .map(|_| response::Success::MemberRemoved)
+}
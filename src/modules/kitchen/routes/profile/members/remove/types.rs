@@ -0,0 +1,63 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub id: String,
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        MemberRemoved,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::MemberRemoved => {
+                    (StatusCode::OK, Json(json!({ "message": "Member removed" }))).into_response()
+                }
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToRemoveMember,
+        KitchenNotFound,
+        NotAllowed,
+        MemberNotFound,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToRemoveMember => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to remove member" })),
+                )
+                    .into_response(),
+                Self::KitchenNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Kitchen not found" })),
+                )
+                    .into_response(),
+                Self::NotAllowed => (
+                    StatusCode::FORBIDDEN,
+                    Json(json!({ "error": "Only the kitchen's owner can remove members" })),
+                )
+                    .into_response(),
+                Self::MemberNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Member not found" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
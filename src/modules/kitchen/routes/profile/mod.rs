@@ -1,4 +1,5 @@
 mod get;
+mod members;
 mod update;
 mod update_cover_image;
 
@@ -12,6 +13,7 @@ pub fn get_router() -> Router<Arc<Context>> {
         Router::new()
             .nest("/", get::get_router())
             .nest("/", update::get_router())
-            .nest("/", update_cover_image::get_router()),
+            .nest("/", update_cover_image::get_router())
+            .nest("/", members::get_router()),
     )
 }
@@ -1,5 +1,5 @@
 use super::{service::service, types::request};
-use crate::{modules::auth::middleware::Auth, types::Context};
+use crate::{modules::auth::middleware::UserAuth, types::Context};
 use axum::{
     extract::{Json, State},
     response::IntoResponse,
@@ -7,7 +7,7 @@ use axum::{
 use std::sync::Arc;
 
 pub async fn handler(
-    auth: Auth,
+    auth: UserAuth,
     State(ctx): State<Arc<Context>>,
     Json(body): Json<request::Body>,
 ) -> impl IntoResponse {
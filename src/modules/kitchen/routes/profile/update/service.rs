@@ -27,6 +27,9 @@ pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::
             cover_image: None,
             rating: None,
             likes: None,
+            latitude: payload.body.latitude,
+            longitude: payload.body.longitude,
+            delivery_radius: payload.body.delivery_radius,
             is_available: payload.body.is_available,
         },
     )
@@ -1,5 +1,5 @@
 pub mod request {
-    use crate::modules::{auth::middleware::Auth, kitchen::types};
+    use crate::modules::{auth::middleware::UserAuth as Auth, kitchen::types};
     use regex::Regex;
     use serde::Deserialize;
     use std::borrow::Cow;
@@ -51,6 +51,12 @@ pub mod request {
         pub closing_time: Option<String>,
         pub preparation_time: Option<String>,
         pub delivery_time: Option<String>,
+        #[validate(range(min = -90.0, max = 90.0))]
+        pub latitude: Option<f64>,
+        #[validate(range(min = -180.0, max = 180.0))]
+        pub longitude: Option<f64>,
+        #[validate(range(min = 0.0))]
+        pub delivery_radius: Option<f64>,
         pub is_available: Option<bool>,
     }
 
@@ -28,10 +28,14 @@ pub async fn service(ctx: Arc<Context>, mut payload: request::Payload) -> respon
         })?;
 
     let cover_image = match kitchen.cover_image.0 {
-        Some(cover_image) => storage::update_file(ctx.storage.clone(), cover_image, buf).await,
-        None => storage::upload_file(ctx.storage.clone(), buf).await,
+        Some(cover_image) => storage::update_image(ctx.storage.clone(), cover_image, buf).await,
+        None => storage::upload_image(ctx.storage.clone(), buf).await,
     }
-    .map_err(|_| response::Error::FailedToUpdateCoverImage)?;
+    .map_err(|err| match err {
+        storage::Error::UnsupportedImage => response::Error::UnsupportedImage,
+        storage::Error::InvalidImageDimensions => response::Error::InvalidImageDimensions,
+        _ => response::Error::FailedToUpdateCoverImage,
+    })?;
 
     repository::update_by_id(
         &ctx.db_conn.pool,
@@ -48,6 +52,9 @@ pub async fn service(ctx: Arc<Context>, mut payload: request::Payload) -> respon
             cover_image: Some(cover_image),
             rating: None,
             likes: None,
+            latitude: None,
+            longitude: None,
+            delivery_radius: None,
             is_available: None,
         },
     )
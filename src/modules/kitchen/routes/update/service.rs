@@ -1,6 +1,6 @@
 use super::types::{request, response};
 use crate::{
-    modules::{auth::middleware::Auth, kitchen::repository},
+    modules::{auth::middleware::UserAuth, kitchen::repository},
     types::Context,
 };
 use std::sync::Arc;
@@ -8,7 +8,7 @@ use std::sync::Arc;
 pub async fn service(
     ctx: Arc<Context>,
     payload: request::Payload,
-    auth: Auth,
+    auth: UserAuth,
 ) -> response::Response {
     let kitchen = repository::find_by_id(&ctx.db_conn.pool, payload.id)
         .await
@@ -34,6 +34,9 @@ pub async fn service(
             cover_image: None,
             rating: None,
             likes: None,
+            latitude: payload.body.latitude,
+            longitude: payload.body.longitude,
+            delivery_radius: payload.body.delivery_radius,
             is_available: payload.body.is_available,
         },
     )
@@ -1,5 +1,5 @@
 use super::{service::service, types::request};
-use crate::{modules::auth::middleware::Auth, types::Context};
+use crate::{modules::auth::middleware::UserAuth, types::Context};
 use axum::{
     extract::{Path, State},
     response::IntoResponse,
@@ -8,7 +8,7 @@ use axum_typed_multipart::TypedMultipart;
 use std::sync::Arc;
 
 pub async fn handler(
-    auth: Auth,
+    auth: UserAuth,
     State(ctx): State<Arc<Context>>,
     Path(id): Path<String>,
     TypedMultipart(body): TypedMultipart<request::Body>,
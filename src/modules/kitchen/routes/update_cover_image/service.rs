@@ -1,6 +1,6 @@
 use super::types::{request, response};
 use crate::{
-    modules::{auth::middleware::Auth, kitchen::repository, storage},
+    modules::{auth::middleware::UserAuth, kitchen::repository, storage},
     types::Context,
 };
 use std::{io::Read, sync::Arc};
@@ -8,7 +8,7 @@ use std::{io::Read, sync::Arc};
 pub async fn service(
     ctx: Arc<Context>,
     mut payload: request::Payload,
-    auth: Auth,
+    auth: UserAuth,
 ) -> response::Response {
     let kitchen = repository::find_by_id(&ctx.db_conn.pool, payload.id)
         .await
@@ -32,10 +32,14 @@ pub async fn service(
         })?;
 
     let cover_image = match kitchen.cover_image.0 {
-        Some(cover_image) => storage::update_file(ctx.storage.clone(), cover_image, buf).await,
-        None => storage::upload_file(ctx.storage.clone(), buf).await,
+        Some(cover_image) => storage::update_image(ctx.storage.clone(), cover_image, buf).await,
+        None => storage::upload_image(ctx.storage.clone(), buf).await,
     }
-    .map_err(|_| response::Error::FailedToUpdateCoverImage)?;
+    .map_err(|err| match err {
+        storage::Error::UnsupportedImage => response::Error::UnsupportedImage,
+        storage::Error::InvalidImageDimensions => response::Error::InvalidImageDimensions,
+        _ => response::Error::FailedToUpdateCoverImage,
+    })?;
 
     repository::update_by_id(
         &ctx.db_conn.pool,
@@ -52,6 +56,9 @@ pub async fn service(
             cover_image: Some(cover_image),
             rating: None,
             likes: None,
+            latitude: None,
+            longitude: None,
+            delivery_radius: None,
             is_available: None,
         },
     )
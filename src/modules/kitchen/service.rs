@@ -0,0 +1,71 @@
+use super::repository::{self, Kitchen, KitchenMemberRole, KitchenMemberStatus};
+use crate::types::Context;
+use std::sync::Arc;
+
+pub enum Error {
+    UnexpectedError,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum KitchenRole {
+    Owner,
+    Manager,
+    Staff,
+}
+
+// Everyone with access can see the kitchen's orders, update their status and edit its meals
+pub struct KitchenAccess {
+    pub kitchen: Kitchen,
+    pub role: KitchenRole,
+}
+
+impl KitchenAccess {
+    // NOTE: members, the kitchen's profile and its wallet stay with the owner
+    pub fn is_owner(&self) -> bool {
+        self.role == KitchenRole::Owner
+    }
+
+    pub fn can_manage_meals(&self) -> bool {
+        matches!(self.role, KitchenRole::Owner | KitchenRole::Manager)
+    }
+
+    pub fn can_view_members(&self) -> bool {
+        matches!(self.role, KitchenRole::Owner | KitchenRole::Manager)
+    }
+}
+
+pub async fn find_access_by_user_id(
+    ctx: Arc<Context>,
+    user_id: String,
+) -> Result<Option<KitchenAccess>, Error> {
+    if let Some(kitchen) = repository::find_by_owner_id(&ctx.db_conn.pool, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?
+    {
+        return Ok(Some(KitchenAccess {
+            kitchen,
+            role: KitchenRole::Owner,
+        }));
+    }
+
+    let member = match repository::find_member_by_user_id(&ctx.db_conn.pool, user_id)
+        .await
+        .map_err(|_| Error::UnexpectedError)?
+    {
+        Some(member) if member.status == KitchenMemberStatus::Active => member,
+        _ => return Ok(None),
+    };
+
+    repository::find_by_id(&ctx.db_conn.pool, member.kitchen_id)
+        .await
+        .map_err(|_| Error::UnexpectedError)
+        .map(|kitchen| {
+            kitchen.map(|kitchen| KitchenAccess {
+                kitchen,
+                role: match member.role {
+                    KitchenMemberRole::Manager => KitchenRole::Manager,
+                    KitchenMemberRole::Staff => KitchenRole::Staff,
+                },
+            })
+        })
+}
@@ -1,10 +1,5 @@
 use crate::define_paginated;
-use crate::modules::{
-    cart::repository::Cart,
-    kitchen::{self, repository::Kitchen},
-    storage,
-    user::repository::User,
-};
+use crate::modules::{cart::repository::Cart, storage};
 use bigdecimal::FromPrimitive;
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
@@ -514,42 +509,30 @@ pub async fn delete_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<(),
         })
 }
 
-pub async fn delete_by_id_and_owner_id<'e, E: PgExecutor<'e>>(
+pub async fn delete_by_id_and_kitchen_id<'e, E: PgExecutor<'e>>(
     e: E,
     id: String,
-    owner_id: String,
+    kitchen_id: String,
 ) -> Result<(), Error> {
     sqlx::query!(
         "
-        WITH filtered_meal AS (
-            SELECT
-                meals.id
-            FROM
-                meals,
-                kitchens
-            WHERE 
-                kitchens.owner_id = $2
-                AND meals.kitchen_id = kitchens.id
-                AND meals.id = $1
-        )
         UPDATE
             meals
         SET
             deleted_at = NOW()
-        FROM
-            filtered_meal
         WHERE
-            meals.id = filtered_meal.id
+            id = $1
+            AND kitchen_id = $2
         ",
         id,
-        owner_id
+        kitchen_id
     )
     .execute(e)
     .await
     .map(|_| ())
     .map_err(|err| {
         tracing::error!(
-            "Error occurred while trying to delete a meal by id and owner_id {}: {}",
+            "Error occurred while trying to delete a meal by id and kitchen_id {}: {}",
             id,
             err
         );
@@ -620,6 +603,6 @@ pub async fn unlike_by_id<'e, E: PgExecutor<'e>>(
     })
 }
 
-pub fn is_owner(user: &User, kitchen: &Kitchen, meal: &Meal) -> bool {
-    return kitchen::repository::is_owner(&user, &kitchen) || kitchen.id == meal.kitchen_id;
+pub fn is_orderable(meal: &Meal) -> bool {
+    meal.is_available && meal.deleted_at.is_none()
 }
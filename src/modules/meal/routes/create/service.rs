@@ -6,12 +6,16 @@ use crate::{
 use std::{io::Read, sync::Arc};
 
 pub async fn service(ctx: Arc<Context>, mut payload: request::Payload) -> response::Response {
-    let kitchen =
-        kitchen::repository::find_by_owner_id(&ctx.db_conn.pool, payload.auth.user.id.clone())
+    let access =
+        kitchen::service::find_access_by_user_id(ctx.clone(), payload.auth.user.id.clone())
             .await
             .map_err(|_| response::Error::FailedToCreateMeal)?
             .ok_or(response::Error::KitchenNotCreated)?;
 
+    if !access.can_manage_meals() {
+        return Err(response::Error::NotAllowed);
+    }
+
     let mut buf: Vec<u8> = vec![];
 
     payload
@@ -24,11 +28,15 @@ pub async fn service(ctx: Arc<Context>, mut payload: request::Payload) -> respon
             response::Error::FailedToCreateMeal
         })?;
 
-    let cover_image = storage::upload_file(ctx.storage.clone(), buf)
+    let cover_image = storage::upload_image(ctx.storage.clone(), buf)
         .await
-        .map_err(|err| {
-            tracing::error!("Failed to upload file: {:?}", err);
-            response::Error::FailedToCreateMeal
+        .map_err(|err| match err {
+            storage::Error::UnsupportedImage => response::Error::UnsupportedImage,
+            storage::Error::InvalidImageDimensions => response::Error::InvalidImageDimensions,
+            _ => {
+                tracing::error!("Failed to upload file: {:?}", err);
+                response::Error::FailedToCreateMeal
+            }
         })?;
 
     repository::create(
@@ -38,7 +46,7 @@ pub async fn service(ctx: Arc<Context>, mut payload: request::Payload) -> respon
             description: payload.body.description,
             price: payload.body.price.0,
             cover_image,
-            kitchen_id: kitchen.id,
+            kitchen_id: access.kitchen.id,
         },
     )
     .await
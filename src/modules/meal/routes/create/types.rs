@@ -82,16 +82,34 @@ pub mod response {
     pub enum Error {
         FailedToCreateMeal,
         KitchenNotCreated,
+        NotAllowed,
+        UnsupportedImage,
+        InvalidImageDimensions,
     }
 
     impl IntoResponse for Error {
         fn into_response(self) -> axum::response::Response {
             match self {
+                Self::UnsupportedImage => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Only JPEG, PNG and WebP images are supported" })),
+                )
+                    .into_response(),
+                Self::InvalidImageDimensions => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Image must be between 64 and 6000 pixels wide and tall" })),
+                )
+                    .into_response(),
                 Self::KitchenNotCreated => (
                     StatusCode::BAD_REQUEST,
                     Json(json!({ "error": "Kitchen not created" })),
                 )
                     .into_response(),
+                Self::NotAllowed => (
+                    StatusCode::FORBIDDEN,
+                    Json(json!({ "error": "Only the kitchen's owner and managers can add meals" })),
+                )
+                    .into_response(),
                 Self::FailedToCreateMeal => (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     Json(json!({"error": "Failed to create meal" })),
@@ -1,9 +1,21 @@
 use super::types::{request, response};
-use crate::{modules::meal::repository, types::Context};
+use crate::{
+    modules::{kitchen, meal::repository},
+    types::Context,
+};
 use std::sync::Arc;
 
 pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
-    repository::delete_by_id_and_owner_id(&ctx.db_conn.pool, payload.id, payload.auth.user.id)
+    let access = kitchen::service::find_access_by_user_id(ctx.clone(), payload.auth.user.id)
+        .await
+        .map_err(|_| response::Error::FailedToDeleteMeal)?
+        .ok_or(response::Error::KitchenNotCreated)?;
+
+    if !access.can_manage_meals() {
+        return Err(response::Error::NotAllowed);
+    }
+
+    repository::delete_by_id_and_kitchen_id(&ctx.db_conn.pool, payload.id, access.kitchen.id)
         .await
         .map_err(|_| response::Error::FailedToDeleteMeal)
         .map(|_| response::Success::MealDeleted)
@@ -29,6 +29,8 @@ pub mod response {
 
     pub enum Error {
         FailedToDeleteMeal,
+        KitchenNotCreated,
+        NotAllowed,
     }
 
     impl IntoResponse for Error {
@@ -39,6 +41,16 @@ pub mod response {
                     Json(json!({ "error": "Failed to delete meal" })),
                 )
                     .into_response(),
+                Self::KitchenNotCreated => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Kitchen not created" })),
+                )
+                    .into_response(),
+                Self::NotAllowed => (
+                    StatusCode::FORBIDDEN,
+                    Json(json!({ "error": "Only the kitchen's owner and managers can delete meals" })),
+                )
+                    .into_response(),
             }
         }
     }
@@ -1,9 +1,9 @@
 use super::types::{request, response};
 use crate::{
     modules::{
+        auth::permission::Permission,
         cart,
         meal::repository::{self, MealWithCartStatus},
-        user,
     },
     types::Context,
     utils::pagination::Paginated,
@@ -39,7 +39,7 @@ pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::
                 _ => None,
             };
 
-            let paginated_meals = if user::repository::is_admin(&auth.user) {
+            let paginated_meals = if auth.user.has_permission(&Permission::ManageKitchens) {
                 repository::find_many_as_admin(
                     &ctx.db_conn.pool,
                     payload.pagination,
@@ -23,14 +23,18 @@ async fn upload_cover_image(
         response::Error::FailedToUpdateMeal
     })?;
 
-    storage::update_file(storage, old_image, buf)
+    storage::update_image(storage, old_image, buf)
         .await
-        .map_err(|_| response::Error::FailedToUpdateMeal)
+        .map_err(|err| match err {
+            storage::Error::UnsupportedImage => response::Error::UnsupportedImage,
+            storage::Error::InvalidImageDimensions => response::Error::InvalidImageDimensions,
+            _ => response::Error::FailedToUpdateMeal,
+        })
 }
 
 pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
-    let kitchen =
-        kitchen::repository::find_by_owner_id(&ctx.db_conn.pool, payload.auth.user.clone().id)
+    let access =
+        kitchen::service::find_access_by_user_id(ctx.clone(), payload.auth.user.id.clone())
             .await
             .map_err(|_| response::Error::FailedToUpdateMeal)?
             .ok_or(response::Error::KitchenNotCreated)?;
@@ -40,7 +44,7 @@ pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::
         .map_err(|_| response::Error::FailedToUpdateMeal)?
         .ok_or(response::Error::MealNotFound)?;
 
-    if !repository::is_owner(&payload.auth.user, &kitchen, &meal) {
+    if meal.kitchen_id != access.kitchen.id {
         return Err(response::Error::NotMealOwner);
     }
 
@@ -1,5 +1,5 @@
 pub mod request {
-    use crate::modules::auth::middleware::Auth;
+    use crate::modules::auth::middleware::UserAuth as Auth;
     use async_trait::async_trait;
     use axum::extract::multipart::Field;
     use axum_typed_multipart::{FieldData, TryFromField, TryFromMultipart, TypedMultipartError};
@@ -79,11 +79,23 @@ pub mod response {
         FailedToUpdateMeal,
         NotMealOwner,
         KitchenNotCreated,
+        UnsupportedImage,
+        InvalidImageDimensions,
     }
 
     impl IntoResponse for Error {
         fn into_response(self) -> axum::response::Response {
             match self {
+                Self::UnsupportedImage => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Only JPEG, PNG and WebP images are supported" })),
+                )
+                    .into_response(),
+                Self::InvalidImageDimensions => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Image must be between 64 and 6000 pixels wide and tall" })),
+                )
+                    .into_response(),
                 Self::NotMealOwner => (
                     StatusCode::FORBIDDEN,
                     Json(json!({"error": "You are not the owner of this meal"})),
@@ -1,12 +1,23 @@
 use super::service::service;
 use super::types::request;
-use crate::types::Context;
+use crate::{
+    modules::auth::{middleware::RequirePermission, permission},
+    types::Context,
+};
 use axum::{
-    extract::{Path, State},
+    extract::{Json, Path, State},
     response::IntoResponse,
 };
 use std::sync::Arc;
 
-pub async fn handler(State(ctx): State<Arc<Context>>, Path(id): Path<String>) -> impl IntoResponse {
-    service(ctx, request::Payload { id }).await
+// NOTE: this reaches every stored file, including the images kitchens, meals and ads point at
+pub async fn handler(
+    _: RequirePermission<permission::ManageKitchens>,
+    State(ctx): State<Arc<Context>>,
+    Path(id): Path<String>,
+    body: Option<Json<request::Body>>,
+) -> impl IntoResponse {
+    let sizes = body.and_then(|Json(body)| body.sizes);
+
+    service(ctx, request::Payload { id, sizes }).await
 }
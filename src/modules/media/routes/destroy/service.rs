@@ -1,9 +1,20 @@
 use super::types::{request, response};
-use crate::types::Context;
+use crate::{modules::storage, types::Context};
 use std::sync::Arc;
 
-pub async fn service(_: Arc<Context>, payload: request::Payload) -> response::Response {
-    std::fs::remove_file(format!("public/uploads/{}", payload.id))
-        .map_err(|_| response::Error::FailedToDeleteMedia)
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let media = storage::UploadedMedia {
+        public_id: payload.id,
+        url: String::new(),
+        timestamp: 0,
+        sizes: payload.sizes,
+    };
+
+    storage::delete_image(ctx.storage.clone(), media)
+        .await
+        .map_err(|err| match err {
+            storage::Error::NotFound => response::Error::MediaNotFound,
+            _ => response::Error::FailedToDeleteMedia,
+        })
         .map(|_| response::Success::MediaDeleted)
 }
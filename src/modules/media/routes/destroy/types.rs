@@ -1,6 +1,17 @@
 pub mod request {
+    use crate::modules::storage::MediaSizes;
+    use serde::Deserialize;
+
+    // NOTE: the sizes are stored under keys of their own, they can't be found from the original's id. Clients send
+    // them back the way the upload returned them
+    #[derive(Deserialize)]
+    pub struct Body {
+        pub sizes: Option<MediaSizes>,
+    }
+
     pub struct Payload {
         pub id: String,
+        pub sizes: Option<MediaSizes>,
     }
 }
 
@@ -25,14 +36,20 @@ pub mod response {
     }
 
     pub enum Error {
+        MediaNotFound,
         FailedToDeleteMedia,
     }
 
     impl IntoResponse for Error {
         fn into_response(self) -> axum::response::Response {
             match self {
-                Self::FailedToDeleteMedia => (
+                Self::MediaNotFound => (
                     StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Media not found" })),
+                )
+                    .into_response(),
+                Self::FailedToDeleteMedia => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
                     Json(json!({ "error": "Failed to delete media"})),
                 )
                     .into_response(),
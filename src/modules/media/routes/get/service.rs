@@ -1,7 +1,15 @@
 use super::types::{request, response};
-use crate::types::Context;
+use crate::{
+    modules::storage::{self, StoredMedia},
+    types::Context,
+};
 use std::sync::Arc;
 
-pub async fn service(_: Arc<Context>, payload: request::Payload) -> response::Response {
-    Ok(response::Success::Media(payload.id))
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    match storage::get_file(ctx.storage.clone(), payload.id).await {
+        Ok(StoredMedia::Url(url)) => Ok(response::Success::MediaMoved(url)),
+        Ok(StoredMedia::Contents(contents)) => Ok(response::Success::Media(contents)),
+        Err(storage::Error::NotFound) => Err(response::Error::MediaNotFound),
+        Err(_) => Err(response::Error::FailedToFetchMedia),
+    }
 }
@@ -5,25 +5,46 @@ pub mod request {
 }
 
 pub mod response {
-    use axum::{http::StatusCode, response::IntoResponse};
+    use axum::{
+        extract::Json,
+        http::StatusCode,
+        response::{IntoResponse, Redirect},
+    };
+    use serde_json::json;
 
     pub enum Success {
-        Media(String),
+        Media(Vec<u8>),
+        MediaMoved(String),
     }
 
     impl IntoResponse for Success {
         fn into_response(self) -> axum::response::Response {
             match self {
-                Self::Media(id) => (StatusCode::OK, id).into_response(),
+                Self::Media(contents) => (StatusCode::OK, contents).into_response(),
+                Self::MediaMoved(url) => Redirect::temporary(&url).into_response(),
             }
         }
     }
 
-    pub enum Error {}
+    pub enum Error {
+        MediaNotFound,
+        FailedToFetchMedia,
+    }
 
     impl IntoResponse for Error {
         fn into_response(self) -> axum::response::Response {
-            unreachable!()
+            match self {
+                Self::MediaNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Media not found" })),
+                )
+                    .into_response(),
+                Self::FailedToFetchMedia => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch media" })),
+                )
+                    .into_response(),
+            }
         }
     }
 
@@ -1,19 +1,21 @@
 use super::types::{request, response};
-use crate::types::Context;
-use std::sync::Arc;
-use ulid::Ulid;
+use crate::{modules::storage, types::Context};
+use std::{io::Read, sync::Arc};
 
-pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
-    let file_name = Ulid::new().to_string();
-    let file_path = format!("public/uploads/{}", file_name);
+pub async fn service(ctx: Arc<Context>, mut payload: request::Payload) -> response::Response {
+    let mut buf: Vec<u8> = vec![];
 
-    payload
-        .file
-        .contents
-        .persist(file_path.clone())
-        .map_err(|err| {
-            tracing::error!("Failed to save uploaded file: {:?}", err);
-            response::Error::FailedToUploadMedia
+    payload.file.contents.read_to_end(&mut buf).map_err(|err| {
+        tracing::error!("Failed to read the uploaded file {:?}", err);
+        response::Error::FailedToUploadMedia
+    })?;
+
+    storage::upload_image(ctx.storage.clone(), buf)
+        .await
+        .map_err(|err| match err {
+            storage::Error::UnsupportedImage => response::Error::UnsupportedImage,
+            storage::Error::InvalidImageDimensions => response::Error::InvalidImageDimensions,
+            _ => response::Error::FailedToUploadMedia,
         })
-        .map(|_| response::Success::UploadedMedia(ctx.app.url.clone(), file_name))
+        .map(response::Success::UploadedMedia)
 }
@@ -10,22 +10,27 @@ pub mod request {
 }
 
 pub mod response {
+    use crate::modules::storage::UploadedMedia;
     use axum::{extract::Json, http::StatusCode, response::IntoResponse};
     use serde_json::json;
 
     pub enum Success {
-        UploadedMedia(String, String),
+        UploadedMedia(UploadedMedia),
     }
 
+    // NOTE: `signature` and `secure_url` are still there for clients that were built against cloudinary's response
     impl IntoResponse for Success {
         fn into_response(self) -> axum::response::Response {
             match self {
-                Self::UploadedMedia(url, file_name) => (
+                Self::UploadedMedia(media) => (
                     StatusCode::OK,
                     Json(json!({
-                        "public_id": file_name,
-                        "signature": file_name,
-                        "secure_url": format!("{}/api/media/{}", url, file_name)
+                        "public_id": media.public_id,
+                        "signature": media.public_id,
+                        "secure_url": media.url,
+                        "url": media.url,
+                        "timestamp": media.timestamp,
+                        "sizes": media.sizes,
                     })),
                 )
                     .into_response(),
@@ -35,11 +40,23 @@ pub mod response {
 
     pub enum Error {
         FailedToUploadMedia,
+        UnsupportedImage,
+        InvalidImageDimensions,
     }
 
     impl IntoResponse for Error {
         fn into_response(self) -> axum::response::Response {
             match self {
+                Self::UnsupportedImage => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Only JPEG, PNG and WebP images are supported" })),
+                )
+                    .into_response(),
+                Self::InvalidImageDimensions => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Image must be between 64 and 6000 pixels wide and tall" })),
+                )
+                    .into_response(),
                 Self::FailedToUploadMedia => (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     Json(json!({ "error": "Failed to upload media" })),
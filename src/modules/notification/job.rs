@@ -0,0 +1,82 @@
+use super::service;
+use crate::types::{Context, SchedulableJob};
+use std::pin::Pin;
+use std::str::FromStr;
+use std::sync::Arc;
+
+async fn deliver_notifications_job(ctx: Arc<Context>) -> Result<(), apalis::prelude::Error> {
+    service::outbox::deliver_due(ctx).await;
+    Ok(())
+}
+
+fn setup_deliver_notifications_job(
+    ctx: Arc<Context>,
+) -> Arc<
+    dyn Fn()
+            -> Pin<Box<dyn std::future::Future<Output = Result<(), apalis::prelude::Error>> + Send>>
+        + Send
+        + Sync,
+> {
+    Arc::new(move || {
+        let ctx = ctx.clone();
+        Box::pin(async move { deliver_notifications_job(ctx).await })
+    })
+}
+
+async fn send_campaigns_job(ctx: Arc<Context>) -> Result<(), apalis::prelude::Error> {
+    service::campaign::send_due(ctx).await;
+    Ok(())
+}
+
+fn setup_send_campaigns_job(
+    ctx: Arc<Context>,
+) -> Arc<
+    dyn Fn()
+            -> Pin<Box<dyn std::future::Future<Output = Result<(), apalis::prelude::Error>> + Send>>
+        + Send
+        + Sync,
+> {
+    Arc::new(move || {
+        let ctx = ctx.clone();
+        Box::pin(async move { send_campaigns_job(ctx).await })
+    })
+}
+
+async fn prune_notifications_job(ctx: Arc<Context>) -> Result<(), apalis::prelude::Error> {
+    service::outbox::prune(ctx).await;
+    Ok(())
+}
+
+fn setup_prune_notifications_job(
+    ctx: Arc<Context>,
+) -> Arc<
+    dyn Fn()
+            -> Pin<Box<dyn std::future::Future<Output = Result<(), apalis::prelude::Error>> + Send>>
+        + Send
+        + Sync,
+> {
+    Arc::new(move || {
+        let ctx = ctx.clone();
+        Box::pin(async move { prune_notifications_job(ctx).await })
+    })
+}
+
+pub fn list(ctx: Arc<Context>) -> Vec<SchedulableJob> {
+    vec![
+        SchedulableJob {
+            schedule: apalis::cron::Schedule::from_str("*/10 * * * * *")
+                .expect("Couldn't create schedule"),
+            job: setup_deliver_notifications_job(ctx.clone()),
+        },
+        SchedulableJob {
+            schedule: apalis::cron::Schedule::from_str("0 * * * * *")
+                .expect("Couldn't create schedule"),
+            job: setup_send_campaigns_job(ctx.clone()),
+        },
+        SchedulableJob {
+            schedule: apalis::cron::Schedule::from_str("0 0 3 * * *")
+                .expect("Couldn't create schedule"),
+            job: setup_prune_notifications_job(ctx),
+        },
+    ]
+}
@@ -1,4 +1,5 @@
 mod routes;
 pub use routes::*;
+pub mod job;
 pub mod repository;
 pub mod service;
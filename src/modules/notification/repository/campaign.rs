@@ -0,0 +1,797 @@
+use crate::define_paginated;
+use crate::modules::order::repository::OrderStatus;
+use crate::utils::pagination::{Paginated, Pagination};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sqlx::PgExecutor;
+use ulid::Ulid;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum CampaignStatus {
+    #[serde(rename = "SCHEDULED")]
+    Scheduled,
+    #[serde(rename = "SENDING")]
+    Sending,
+    #[serde(rename = "SENT")]
+    Sent,
+    #[serde(rename = "CANCELLED")]
+    Cancelled,
+    // its segment couldn't be read, so there's no telling who it was meant for
+    #[serde(rename = "FAILED")]
+    Failed,
+}
+
+impl From<String> for CampaignStatus {
+    fn from(value: String) -> Self {
+        match value.as_ref() {
+            "SCHEDULED" => CampaignStatus::Scheduled,
+            "SENDING" => CampaignStatus::Sending,
+            "SENT" => CampaignStatus::Sent,
+            "CANCELLED" => CampaignStatus::Cancelled,
+            "FAILED" => CampaignStatus::Failed,
+            status => unreachable!("Invalid campaign status: {}", status),
+        }
+    }
+}
+
+impl ToString for CampaignStatus {
+    fn to_string(&self) -> String {
+        match self {
+            CampaignStatus::Scheduled => String::from("SCHEDULED"),
+            CampaignStatus::Sending => String::from("SENDING"),
+            CampaignStatus::Sent => String::from("SENT"),
+            CampaignStatus::Cancelled => String::from("CANCELLED"),
+            CampaignStatus::Failed => String::from("FAILED"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum RecipientStatus {
+    #[serde(rename = "PENDING")]
+    Pending,
+    #[serde(rename = "SENT")]
+    Sent,
+    #[serde(rename = "FAILED")]
+    Failed,
+    #[serde(rename = "SKIPPED")]
+    Skipped,
+}
+
+impl From<String> for RecipientStatus {
+    fn from(value: String) -> Self {
+        match value.as_ref() {
+            "PENDING" => RecipientStatus::Pending,
+            "SENT" => RecipientStatus::Sent,
+            "FAILED" => RecipientStatus::Failed,
+            "SKIPPED" => RecipientStatus::Skipped,
+            status => unreachable!("Invalid campaign recipient status: {}", status),
+        }
+    }
+}
+
+impl ToString for RecipientStatus {
+    fn to_string(&self) -> String {
+        match self {
+            RecipientStatus::Pending => String::from("PENDING"),
+            RecipientStatus::Sent => String::from("SENT"),
+            RecipientStatus::Failed => String::from("FAILED"),
+            RecipientStatus::Skipped => String::from("SKIPPED"),
+        }
+    }
+}
+
+// Who a campaign goes to, every filter that is set has to match. Users don't have a city of their own so it's taken
+// from the kitchens they've ordered from or own
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct Segment {
+    pub city_id: Option<String>,
+    pub ordered_within_days: Option<i32>,
+    pub min_order_count: Option<i32>,
+    pub is_kitchen_owner: Option<bool>,
+    pub active_within_days: Option<i32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Campaign {
+    pub id: String,
+    pub title: String,
+    pub body: String,
+    pub link: Option<String>,
+    // NOTE: left as it was stored, see `get_segment`
+    pub segment: Value,
+    pub status: CampaignStatus,
+    pub scheduled_for: NaiveDateTime,
+    pub recipient_count: i32,
+    pub sent_count: i32,
+    pub failed_count: i32,
+    pub skipped_count: i32,
+    pub open_count: i32,
+    pub created_by: String,
+    pub started_at: Option<NaiveDateTime>,
+    pub completed_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+impl Campaign {
+    // NOTE: a segment stored before its shape changed shouldn't take every other campaign down with it
+    pub fn get_segment(&self) -> Result<Segment, Error> {
+        serde_json::from_value::<Segment>(self.segment.clone()).map_err(|err| {
+            tracing::error!("Invalid segment found on campaign {}: {}", self.id, err);
+            Error::UnexpectedError
+        })
+    }
+}
+
+define_paginated!(DatabasePaginatedCampaign, Campaign);
+
+#[derive(Clone)]
+pub struct CampaignRecipient {
+    pub id: String,
+    pub campaign_id: String,
+    pub user_id: String,
+    pub status: RecipientStatus,
+    pub next_attempt_at: NaiveDateTime,
+    pub sent_at: Option<NaiveDateTime>,
+    pub opened_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+pub struct CreateCampaignPayload {
+    pub title: String,
+    pub body: String,
+    pub link: Option<String>,
+    pub segment: Segment,
+    pub scheduled_for: NaiveDateTime,
+    pub created_by: String,
+}
+
+pub enum Error {
+    UnexpectedError,
+}
+
+pub async fn create<'e, E: PgExecutor<'e>>(
+    e: E,
+    payload: CreateCampaignPayload,
+) -> Result<Campaign, Error> {
+    sqlx::query_as!(
+        Campaign,
+        "
+        INSERT INTO push_campaigns (id, title, body, link, segment, status, scheduled_for, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        RETURNING *
+        ",
+        Ulid::new().to_string(),
+        payload.title,
+        payload.body,
+        payload.link,
+        json!(payload.segment),
+        CampaignStatus::Scheduled.to_string(),
+        payload.scheduled_for,
+        payload.created_by
+    )
+    .fetch_one(e)
+    .await
+    .map_err(|err| {
+        tracing::error!("Error occurred while trying to create a campaign: {}", err);
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_by_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+) -> Result<Option<Campaign>, Error> {
+    sqlx::query_as!(Campaign, "SELECT * FROM push_campaigns WHERE id = $1", id)
+        .fetch_optional(e)
+        .await
+        .map_err(|err| {
+            tracing::error!(
+                "Error occurred while trying to fetch campaign with id {}: {}",
+                id,
+                err
+            );
+            Error::UnexpectedError
+        })
+}
+
+pub async fn find_many<'e, E: PgExecutor<'e>>(
+    e: E,
+    pagination: Pagination,
+) -> Result<Paginated<Campaign>, Error> {
+    sqlx::query_as!(
+        DatabasePaginatedCampaign,
+        r#"
+        WITH filtered_data AS (
+            SELECT *
+            FROM push_campaigns
+            ORDER BY created_at DESC
+            LIMIT $1
+            OFFSET $2
+        ),
+        total_count AS (
+            SELECT COUNT(id) AS total_rows
+            FROM push_campaigns
+        )
+        SELECT
+            COALESCE(JSONB_AGG(ROW_TO_JSON(filtered_data)), '[]'::jsonb) AS items,
+            JSONB_BUILD_OBJECT(
+                'total', (SELECT total_rows FROM total_count),
+                'per_page', $1,
+                'page', $2 / $1 + 1
+            ) AS meta
+        FROM filtered_data
+        "#,
+        pagination.per_page as i64,
+        ((pagination.page - 1) * pagination.per_page) as i64
+    )
+    .fetch_one(e)
+    .await
+    .map(DatabasePaginatedCampaign::into)
+    .map_err(|err| {
+        tracing::error!("Error occurred while trying to fetch campaigns: {}", err);
+        Error::UnexpectedError
+    })
+}
+
+// NOTE: a campaign that is already going out stops after the batch in flight, whoever got it already keeps it
+pub async fn cancel_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<bool, Error> {
+    sqlx::query!(
+        "
+        UPDATE push_campaigns
+        SET
+            status = $1,
+            completed_at = NOW(),
+            updated_at = NOW()
+        WHERE
+            id = $2
+            AND status IN ($3, $4)
+        ",
+        CampaignStatus::Cancelled.to_string(),
+        id,
+        CampaignStatus::Scheduled.to_string(),
+        CampaignStatus::Sending.to_string()
+    )
+    .execute(e)
+    .await
+    .map(|result| result.rows_affected() > 0)
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to cancel campaign with id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+// Moves the next due campaign over to sending, run it in the transaction that creates its recipients
+pub async fn claim_due<'e, E: PgExecutor<'e>>(e: E) -> Result<Option<Campaign>, Error> {
+    sqlx::query_as!(
+        Campaign,
+        "
+        UPDATE push_campaigns
+        SET
+            status = $1,
+            started_at = NOW(),
+            updated_at = NOW()
+        WHERE
+            id = (
+                SELECT
+                    id
+                FROM
+                    push_campaigns
+                WHERE
+                    status = $2
+                    AND scheduled_for <= NOW()
+                ORDER BY
+                    scheduled_for ASC
+                LIMIT 1
+                FOR UPDATE SKIP LOCKED
+            )
+        RETURNING *
+        ",
+        CampaignStatus::Sending.to_string(),
+        CampaignStatus::Scheduled.to_string()
+    )
+    .fetch_optional(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to claim a due campaign: {}",
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_many_sending<'e, E: PgExecutor<'e>>(e: E) -> Result<Vec<Campaign>, Error> {
+    sqlx::query_as!(
+        Campaign,
+        "
+        SELECT
+            *
+        FROM
+            push_campaigns
+        WHERE
+            status = $1
+        ORDER BY
+            started_at ASC
+        ",
+        CampaignStatus::Sending.to_string()
+    )
+    .fetch_all(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch campaigns being sent: {}",
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn set_recipient_count_by_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+    recipient_count: i32,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "
+        UPDATE push_campaigns
+        SET
+            recipient_count = $1,
+            updated_at = NOW()
+        WHERE
+            id = $2
+        ",
+        recipient_count,
+        id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to set the recipient count of campaign with id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub struct IncrementCountsPayload {
+    pub sent: i32,
+    pub failed: i32,
+    pub skipped: i32,
+}
+
+pub async fn increment_counts_by_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+    payload: IncrementCountsPayload,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "
+        UPDATE push_campaigns
+        SET
+            sent_count = sent_count + $1,
+            failed_count = failed_count + $2,
+            skipped_count = skipped_count + $3,
+            updated_at = NOW()
+        WHERE
+            id = $4
+        ",
+        payload.sent,
+        payload.failed,
+        payload.skipped,
+        id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to update the counts of campaign with id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn increment_open_count_by_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "
+        UPDATE push_campaigns
+        SET
+            open_count = open_count + 1,
+            updated_at = NOW()
+        WHERE
+            id = $1
+        ",
+        id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to update the open count of campaign with id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+// Gives up on a campaign that was claimed but can't be started
+pub async fn fail_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<(), Error> {
+    sqlx::query!(
+        "
+        UPDATE push_campaigns
+        SET
+            status = $1,
+            completed_at = NOW(),
+            updated_at = NOW()
+        WHERE
+            id = $2
+        ",
+        CampaignStatus::Failed.to_string(),
+        id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fail campaign with id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+// Only completes the campaign once every recipient has been dealt with, held ones included
+pub async fn complete_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<bool, Error> {
+    sqlx::query!(
+        "
+        UPDATE push_campaigns
+        SET
+            status = $1,
+            completed_at = NOW(),
+            updated_at = NOW()
+        WHERE
+            id = $2
+            AND status = $3
+            AND NOT EXISTS (
+                SELECT
+                    1
+                FROM
+                    push_campaign_recipients
+                WHERE
+                    campaign_id = $2
+                    AND status = $4
+            )
+        ",
+        CampaignStatus::Sent.to_string(),
+        id,
+        CampaignStatus::Sending.to_string(),
+        RecipientStatus::Pending.to_string()
+    )
+    .execute(e)
+    .await
+    .map(|result| result.rows_affected() > 0)
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to complete campaign with id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+// NOTE: only users with a registered device are part of a segment, there's nothing to send to the others
+pub async fn find_many_user_ids_by_segment<'e, E: PgExecutor<'e>>(
+    e: E,
+    segment: &Segment,
+) -> Result<Vec<String>, Error> {
+    sqlx::query!(
+        "
+        SELECT
+            users.id
+        FROM
+            users
+        WHERE
+            users.deleted_at IS NULL
+            AND EXISTS (
+                SELECT 1 FROM push_tokens WHERE push_tokens.user_id = users.id
+            )
+            AND (
+                $1::VARCHAR IS NULL
+                OR EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        orders
+                        INNER JOIN kitchens ON kitchens.id = orders.kitchen_id
+                    WHERE
+                        orders.owner_id = users.id
+                        AND kitchens.city_id = $1
+                )
+                OR EXISTS (
+                    SELECT 1 FROM kitchens WHERE kitchens.owner_id = users.id AND kitchens.city_id = $1
+                )
+            )
+            AND (
+                $2::INT IS NULL
+                OR EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        orders
+                    WHERE
+                        orders.owner_id = users.id
+                        AND orders.status NOT IN ($6, $7)
+                        AND orders.created_at >= NOW() - MAKE_INTERVAL(days => $2)
+                )
+            )
+            AND (
+                $3::INT IS NULL
+                OR (
+                    SELECT
+                        COUNT(orders.id)
+                    FROM
+                        orders
+                    WHERE
+                        orders.owner_id = users.id
+                        AND orders.status NOT IN ($6, $7)
+                ) >= $3
+            )
+            AND ($4::BOOLEAN IS NULL OR users.has_kitchen = $4)
+            AND (
+                $5::INT IS NULL
+                OR EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        sessions
+                    WHERE
+                        sessions.user_id = users.id
+                        AND COALESCE(sessions.updated_at, sessions.created_at) >= NOW() - MAKE_INTERVAL(days => $5)
+                )
+            )
+        ",
+        segment.city_id,
+        segment.ordered_within_days,
+        segment.min_order_count,
+        segment.is_kitchen_owner,
+        segment.active_within_days,
+        OrderStatus::AwaitingPayment.to_string(),
+        OrderStatus::Cancelled.to_string()
+    )
+    .fetch_all(e)
+    .await
+    .map(|records| records.into_iter().map(|record| record.id).collect())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch the users in a campaign segment: {}",
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn create_many_recipients<'e, E: PgExecutor<'e>>(
+    e: E,
+    campaign_id: String,
+    user_ids: Vec<String>,
+) -> Result<(), Error> {
+    let ids = user_ids
+        .iter()
+        .map(|_| Ulid::new().to_string())
+        .collect::<Vec<_>>();
+
+    sqlx::query!(
+        "
+        INSERT INTO push_campaign_recipients (id, campaign_id, user_id, status)
+        SELECT
+            UNNEST($1::VARCHAR[]),
+            $2,
+            UNNEST($3::VARCHAR[]),
+            $4
+        ON CONFLICT (campaign_id, user_id) DO NOTHING
+        ",
+        &ids,
+        campaign_id,
+        &user_ids,
+        RecipientStatus::Pending.to_string()
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to add recipients to campaign with id {}: {}",
+            campaign_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+// NOTE: same leasing as the notification outbox, claimed recipients are pushed back by `lease` seconds
+pub async fn claim_many_due_recipients<'e, E: PgExecutor<'e>>(
+    e: E,
+    campaign_id: String,
+    limit: i64,
+    lease: i64,
+) -> Result<Vec<CampaignRecipient>, Error> {
+    sqlx::query_as!(
+        CampaignRecipient,
+        "
+        UPDATE push_campaign_recipients
+        SET
+            next_attempt_at = NOW() + MAKE_INTERVAL(secs => $1),
+            updated_at = NOW()
+        WHERE
+            id IN (
+                SELECT
+                    id
+                FROM
+                    push_campaign_recipients
+                WHERE
+                    campaign_id = $2
+                    AND status = $3
+                    AND next_attempt_at <= NOW()
+                ORDER BY
+                    next_attempt_at ASC
+                LIMIT $4
+                FOR UPDATE SKIP LOCKED
+            )
+        RETURNING *
+        ",
+        lease as f64,
+        campaign_id,
+        RecipientStatus::Pending.to_string(),
+        limit
+    )
+    .fetch_all(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to claim recipients of campaign with id {}: {}",
+            campaign_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn update_recipient_status_by_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+    status: RecipientStatus,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "
+        UPDATE push_campaign_recipients
+        SET
+            status = $1,
+            sent_at = CASE WHEN $1::VARCHAR = $2 THEN NOW() ELSE sent_at END,
+            updated_at = NOW()
+        WHERE
+            id = $3
+        ",
+        status.to_string(),
+        RecipientStatus::Sent.to_string(),
+        id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to update campaign recipient with id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn hold_recipient_by_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+    next_attempt_at: NaiveDateTime,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "
+        UPDATE push_campaign_recipients
+        SET
+            next_attempt_at = $1,
+            updated_at = NOW()
+        WHERE
+            id = $2
+        ",
+        next_attempt_at,
+        id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to hold campaign recipient with id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+// Only the first open counts, returns false when the user wasn't sent the campaign or already opened it
+pub async fn mark_recipient_as_opened_by_campaign_id_and_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    campaign_id: String,
+    user_id: String,
+) -> Result<bool, Error> {
+    sqlx::query!(
+        "
+        UPDATE push_campaign_recipients
+        SET
+            opened_at = NOW(),
+            updated_at = NOW()
+        WHERE
+            campaign_id = $1
+            AND user_id = $2
+            AND status = $3
+            AND opened_at IS NULL
+        ",
+        campaign_id,
+        user_id,
+        RecipientStatus::Sent.to_string()
+    )
+    .execute(e)
+    .await
+    .map(|result| result.rows_affected() > 0)
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to mark campaign with id {} as opened by user with id {}: {}",
+            campaign_id,
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn delete_many_recipients_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "DELETE FROM push_campaign_recipients WHERE user_id = $1",
+        user_id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to delete the campaign recipients of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
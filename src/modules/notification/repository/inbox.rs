@@ -0,0 +1,259 @@
+use crate::define_paginated;
+use crate::modules::notification::service::Category;
+use crate::utils::pagination::{Paginated, Pagination};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::PgExecutor;
+use ulid::Ulid;
+
+// A copy of a notification the user can come back to in the app, it's kept even when the device was offline
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct InboxNotification {
+    pub id: String,
+    pub user_id: String,
+    pub category: Category,
+    pub title: String,
+    pub body: String,
+    pub read_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+define_paginated!(DatabasePaginatedInboxNotification, InboxNotification);
+
+pub struct CreateInboxNotificationPayload {
+    pub user_id: String,
+    pub category: Category,
+    pub title: String,
+    pub body: String,
+}
+
+pub enum Error {
+    UnexpectedError,
+}
+
+pub async fn create<'e, E: PgExecutor<'e>>(
+    e: E,
+    payload: CreateInboxNotificationPayload,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "
+        INSERT INTO notifications (id, user_id, category, title, body)
+        VALUES ($1, $2, $3, $4, $5)
+        ",
+        Ulid::new().to_string(),
+        payload.user_id,
+        payload.category.to_string(),
+        payload.title,
+        payload.body
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to add a notification to the inbox: {}",
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_many_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    pagination: Pagination,
+    user_id: String,
+) -> Result<Paginated<InboxNotification>, Error> {
+    sqlx::query_as!(
+        DatabasePaginatedInboxNotification,
+        r#"
+        WITH filtered_data AS (
+            SELECT *
+            FROM notifications
+            WHERE user_id = $3
+            ORDER BY created_at DESC
+            LIMIT $1
+            OFFSET $2
+        ),
+        total_count AS (
+            SELECT COUNT(id) AS total_rows
+            FROM notifications
+            WHERE user_id = $3
+        )
+        SELECT
+            COALESCE(JSONB_AGG(ROW_TO_JSON(filtered_data)), '[]'::jsonb) AS items,
+            JSONB_BUILD_OBJECT(
+                'total', (SELECT total_rows FROM total_count),
+                'per_page', $1,
+                'page', $2 / $1 + 1
+            ) AS meta
+        FROM filtered_data
+        "#,
+        pagination.per_page as i64,
+        ((pagination.page - 1) * pagination.per_page) as i64,
+        user_id
+    )
+    .fetch_one(e)
+    .await
+    .map(DatabasePaginatedInboxNotification::into)
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch the inbox of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+// Every notification in the inbox, unpaginated, see `find_many_by_user_id` for what the app pages through
+pub async fn find_all_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<Vec<InboxNotification>, Error> {
+    sqlx::query_as!(
+        InboxNotification,
+        "SELECT * FROM notifications WHERE user_id = $1 ORDER BY created_at DESC",
+        user_id
+    )
+    .fetch_all(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch the inbox of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn count_unread_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<i64, Error> {
+    sqlx::query!(
+        r#"
+        SELECT
+            COUNT(id) AS "count!"
+        FROM
+            notifications
+        WHERE
+            user_id = $1
+            AND read_at IS NULL
+        "#,
+        user_id
+    )
+    .fetch_one(e)
+    .await
+    .map(|record| record.count)
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to count unread notifications of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn mark_as_read_by_id_and_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+    user_id: String,
+) -> Result<bool, Error> {
+    sqlx::query!(
+        "
+        UPDATE notifications
+        SET
+            read_at = COALESCE(read_at, NOW()),
+            updated_at = NOW()
+        WHERE
+            id = $1
+            AND user_id = $2
+        ",
+        id,
+        user_id
+    )
+    .execute(e)
+    .await
+    .map(|result| result.rows_affected() > 0)
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to mark notification with id {} as read: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn mark_all_as_read_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "
+        UPDATE notifications
+        SET
+            read_at = NOW(),
+            updated_at = NOW()
+        WHERE
+            user_id = $1
+            AND read_at IS NULL
+        ",
+        user_id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to mark all notifications of user with id {} as read: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn delete_by_id_and_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+    user_id: String,
+) -> Result<bool, Error> {
+    sqlx::query!(
+        "DELETE FROM notifications WHERE id = $1 AND user_id = $2",
+        id,
+        user_id
+    )
+    .execute(e)
+    .await
+    .map(|result| result.rows_affected() > 0)
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to delete notification with id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn delete_many_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<(), Error> {
+    sqlx::query!("DELETE FROM notifications WHERE user_id = $1", user_id)
+        .execute(e)
+        .await
+        .map(|_| ())
+        .map_err(|err| {
+            tracing::error!(
+                "Error occurred while trying to delete notifications of user with id {}: {}",
+                user_id,
+                err
+            );
+            Error::UnexpectedError
+        })
+}
@@ -1 +1,8 @@
+pub mod campaign;
+pub mod inbox;
+pub mod outbox;
+pub mod preference;
 pub mod push_token;
+pub mod quiet_hours;
+pub mod setting;
+pub mod sms;
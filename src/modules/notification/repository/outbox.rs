@@ -0,0 +1,328 @@
+use crate::modules::notification::service::{Backend, Category, Notification};
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use serde_json::{json, Value};
+use sqlx::PgExecutor;
+use ulid::Ulid;
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub enum OutboxStatus {
+    #[serde(rename = "PENDING")]
+    Pending,
+    #[serde(rename = "SENT")]
+    Sent,
+    #[serde(rename = "FAILED")]
+    Failed,
+}
+
+impl From<String> for OutboxStatus {
+    fn from(value: String) -> Self {
+        match value.as_ref() {
+            "PENDING" => OutboxStatus::Pending,
+            "SENT" => OutboxStatus::Sent,
+            "FAILED" => OutboxStatus::Failed,
+            status => unreachable!("Invalid outbox status: {}", status),
+        }
+    }
+}
+
+impl ToString for OutboxStatus {
+    fn to_string(&self) -> String {
+        match self {
+            OutboxStatus::Pending => String::from("PENDING"),
+            OutboxStatus::Sent => String::from("SENT"),
+            OutboxStatus::Failed => String::from("FAILED"),
+        }
+    }
+}
+
+// A notification waiting to go out over a single backend, written in the same transaction as the change it's about.
+// `notification` is left as it was stored, it's only decoded once it's being delivered (see `service::outbox`)
+#[derive(Clone)]
+pub struct OutboxEntry {
+    pub id: String,
+    pub user_id: Option<String>,
+    pub notification: Value,
+    pub category: Category,
+    pub backend: Backend,
+    pub status: OutboxStatus,
+    pub attempts: i32,
+    pub next_attempt_at: NaiveDateTime,
+    pub sent_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+pub enum Error {
+    UnexpectedError,
+}
+
+pub async fn create<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+    notification: Notification,
+    category: Category,
+    backend: Backend,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "
+        INSERT INTO notification_outbox (id, user_id, notification, category, backend, status)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ",
+        Ulid::new().to_string(),
+        user_id,
+        json!(notification),
+        category.to_string(),
+        backend.to_string(),
+        OutboxStatus::Pending.to_string()
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to add a notification to the outbox: {}",
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+// NOTE: claimed entries are pushed back by `lease` seconds so that another worker won't pick them up while they're
+// being delivered, a worker that dies mid-delivery only delays them
+pub async fn claim_many_due<'e, E: PgExecutor<'e>>(
+    e: E,
+    limit: i64,
+    lease: i64,
+) -> Result<Vec<OutboxEntry>, Error> {
+    sqlx::query_as!(
+        OutboxEntry,
+        r#"
+        UPDATE notification_outbox
+        SET
+            attempts = attempts + 1,
+            next_attempt_at = NOW() + MAKE_INTERVAL(secs => $1),
+            updated_at = NOW()
+        WHERE
+            id IN (
+                SELECT
+                    id
+                FROM
+                    notification_outbox
+                WHERE
+                    status = $2
+                    AND next_attempt_at <= NOW()
+                ORDER BY
+                    next_attempt_at ASC
+                LIMIT $3
+                FOR UPDATE SKIP LOCKED
+            )
+        RETURNING *
+        "#,
+        lease as f64,
+        OutboxStatus::Pending.to_string(),
+        limit
+    )
+    .fetch_all(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to claim due notifications: {}",
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn mark_as_sent_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<(), Error> {
+    sqlx::query!(
+        "
+        UPDATE notification_outbox
+        SET
+            status = $1,
+            sent_at = NOW(),
+            updated_at = NOW()
+        WHERE
+            id = $2
+        ",
+        OutboxStatus::Sent.to_string(),
+        id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to mark notification with id {} as sent: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn reschedule_by_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+    next_attempt_at: NaiveDateTime,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "
+        UPDATE notification_outbox
+        SET
+            next_attempt_at = $1,
+            updated_at = NOW()
+        WHERE
+            id = $2
+        ",
+        next_attempt_at,
+        id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to reschedule notification with id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+// Puts the notification off without it counting as an attempt, e.g during the user's quiet hours
+pub async fn hold_by_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+    next_attempt_at: NaiveDateTime,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "
+        UPDATE notification_outbox
+        SET
+            attempts = GREATEST(attempts - 1, 0),
+            next_attempt_at = $1,
+            updated_at = NOW()
+        WHERE
+            id = $2
+        ",
+        next_attempt_at,
+        id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to hold notification with id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn mark_as_failed_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<(), Error> {
+    sqlx::query!(
+        "
+        UPDATE notification_outbox
+        SET
+            status = $1,
+            updated_at = NOW()
+        WHERE
+            id = $2
+        ",
+        OutboxStatus::Failed.to_string(),
+        id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to mark notification with id {} as failed: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+// NOTE: the notification carries the user's details, so it goes along with the rest of their data
+pub async fn delete_many_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "DELETE FROM notification_outbox WHERE user_id = $1",
+        user_id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to delete notifications of user {} from the outbox: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+// Removes whatever is done being delivered (sent or given up on), along with its attempts
+pub async fn delete_many_finished_before<'e, E: PgExecutor<'e>>(
+    e: E,
+    before: NaiveDateTime,
+) -> Result<u64, Error> {
+    sqlx::query!(
+        "DELETE FROM notification_outbox WHERE status <> $1 AND updated_at < $2",
+        OutboxStatus::Pending.to_string(),
+        before
+    )
+    .execute(e)
+    .await
+    .map(|result| result.rows_affected())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to prune the notification outbox: {}",
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub struct CreateAttemptPayload {
+    pub outbox_id: String,
+    pub backend: Backend,
+    pub error: Option<String>,
+}
+
+pub async fn create_attempt<'e, E: PgExecutor<'e>>(
+    e: E,
+    payload: CreateAttemptPayload,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "
+        INSERT INTO notification_attempts (id, outbox_id, backend, is_successful, error)
+        VALUES ($1, $2, $3, $4, $5)
+        ",
+        Ulid::new().to_string(),
+        payload.outbox_id,
+        payload.backend.to_string(),
+        payload.error.is_none(),
+        payload.error
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to record an attempt for notification with id {}: {}",
+            payload.outbox_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
@@ -0,0 +1,135 @@
+use crate::modules::notification::service::{Backend, Category};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::PgExecutor;
+use ulid::Ulid;
+
+// Whether a category of notifications can reach the user over a backend, there's only a row once the user changed it
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct NotificationPreference {
+    pub id: String,
+    pub user_id: String,
+    pub category: Category,
+    pub backend: Backend,
+    pub is_enabled: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+pub struct UpsertNotificationPreferencePayload {
+    pub user_id: String,
+    pub category: Category,
+    pub backend: Backend,
+    pub is_enabled: bool,
+}
+
+pub enum Error {
+    UnexpectedError,
+}
+
+pub async fn upsert<'e, E: PgExecutor<'e>>(
+    e: E,
+    payload: UpsertNotificationPreferencePayload,
+) -> Result<NotificationPreference, Error> {
+    sqlx::query_as!(
+        NotificationPreference,
+        "
+        INSERT INTO notification_preferences (id, user_id, category, backend, is_enabled)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (user_id, category, backend) DO UPDATE SET
+            is_enabled = EXCLUDED.is_enabled,
+            updated_at = NOW()
+        RETURNING *
+        ",
+        Ulid::new().to_string(),
+        payload.user_id,
+        payload.category.to_string(),
+        payload.backend.to_string(),
+        payload.is_enabled
+    )
+    .fetch_one(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to save a notification preference: {}",
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_many_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<Vec<NotificationPreference>, Error> {
+    sqlx::query_as!(
+        NotificationPreference,
+        "SELECT * FROM notification_preferences WHERE user_id = $1",
+        user_id
+    )
+    .fetch_all(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch notification preferences of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_by_user_id_and_category_and_backend<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+    category: Category,
+    backend: Backend,
+) -> Result<Option<NotificationPreference>, Error> {
+    sqlx::query_as!(
+        NotificationPreference,
+        "
+        SELECT
+            *
+        FROM
+            notification_preferences
+        WHERE
+            user_id = $1
+            AND category = $2
+            AND backend = $3
+        ",
+        user_id,
+        category.to_string(),
+        backend.to_string()
+    )
+    .fetch_optional(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch notification preference of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn delete_many_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "DELETE FROM notification_preferences WHERE user_id = $1",
+        user_id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to delete notification preferences of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
@@ -9,6 +9,7 @@ pub struct PushToken {
     pub id: String,
     pub token: String,
     pub user_id: String,
+    pub session_id: String,
     pub created_at: NaiveDateTime,
     pub updated_at: Option<NaiveDateTime>,
 }
@@ -16,6 +17,7 @@ pub struct PushToken {
 pub struct CreatePushTokenPayload {
     pub token: String,
     pub user_id: String,
+    pub session_id: String,
 }
 
 pub enum Error {
@@ -32,14 +34,19 @@ pub async fn create<'e, E: PgExecutor<'e>>(
         INSERT INTO push_tokens (
             id, 
             token,
-            user_id 
+            user_id,
+            session_id
         )
-        VALUES ($1, $2, $3)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (token, user_id) DO UPDATE SET
+            session_id = EXCLUDED.session_id,
+            updated_at = NOW()
         RETURNING *
         ",
         Ulid::new().to_string(),
         payload.token,
         payload.user_id,
+        payload.session_id,
     )
     .fetch_one(e)
     .await
@@ -72,3 +79,36 @@ pub async fn find_many_by_user_id<'e, E: PgExecutor<'e>>(
         Error::UnexpectedError
     })
 }
+
+pub async fn delete_many_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<(), Error> {
+    sqlx::query!("DELETE FROM push_tokens WHERE user_id = $1", &user_id)
+        .execute(e)
+        .await
+        .map(|_| ())
+        .map_err(|err| {
+            tracing::error!(
+                "Error occurred while trying to delete many push tokens by user id {}: {:?}",
+                user_id,
+                err
+            );
+            Error::UnexpectedError
+        })
+}
+
+pub async fn delete_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<(), Error> {
+    sqlx::query!("DELETE FROM push_tokens WHERE id = $1", &id)
+        .execute(e)
+        .await
+        .map(|_| ())
+        .map_err(|err| {
+            tracing::error!(
+                "Error occurred while trying to delete push token by id {}: {:?}",
+                id,
+                err
+            );
+            Error::UnexpectedError
+        })
+}
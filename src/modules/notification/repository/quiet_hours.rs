@@ -0,0 +1,99 @@
+use chrono::{NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Serialize};
+use sqlx::PgExecutor;
+use ulid::Ulid;
+
+// The part of the day (in the user's timezone) non-urgent pushes are held back for, it may wrap past midnight
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct QuietHours {
+    pub id: String,
+    pub user_id: String,
+    pub starts_at: NaiveTime,
+    pub ends_at: NaiveTime,
+    pub timezone: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+pub struct UpsertQuietHoursPayload {
+    pub user_id: String,
+    pub starts_at: NaiveTime,
+    pub ends_at: NaiveTime,
+    pub timezone: String,
+}
+
+pub enum Error {
+    UnexpectedError,
+}
+
+pub async fn upsert<'e, E: PgExecutor<'e>>(
+    e: E,
+    payload: UpsertQuietHoursPayload,
+) -> Result<QuietHours, Error> {
+    sqlx::query_as!(
+        QuietHours,
+        "
+        INSERT INTO notification_quiet_hours (id, user_id, starts_at, ends_at, timezone)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (user_id) DO UPDATE SET
+            starts_at = EXCLUDED.starts_at,
+            ends_at = EXCLUDED.ends_at,
+            timezone = EXCLUDED.timezone,
+            updated_at = NOW()
+        RETURNING *
+        ",
+        Ulid::new().to_string(),
+        payload.user_id,
+        payload.starts_at,
+        payload.ends_at,
+        payload.timezone
+    )
+    .fetch_one(e)
+    .await
+    .map_err(|err| {
+        tracing::error!("Error occurred while trying to save quiet hours: {}", err);
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<Option<QuietHours>, Error> {
+    sqlx::query_as!(
+        QuietHours,
+        "SELECT * FROM notification_quiet_hours WHERE user_id = $1",
+        user_id
+    )
+    .fetch_optional(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch quiet hours of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn delete_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<bool, Error> {
+    sqlx::query!(
+        "DELETE FROM notification_quiet_hours WHERE user_id = $1",
+        user_id
+    )
+    .execute(e)
+    .await
+    .map(|result| result.rows_affected() > 0)
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to delete quiet hours of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
@@ -0,0 +1,121 @@
+use crate::modules::notification::service::Category;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::PgExecutor;
+use ulid::Ulid;
+
+// Only categories the user has changed have a row, everything else is enabled
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct NotificationSetting {
+    pub id: String,
+    pub user_id: String,
+    pub category: Category,
+    pub is_enabled: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+pub struct UpsertNotificationSettingPayload {
+    pub user_id: String,
+    pub category: Category,
+    pub is_enabled: bool,
+}
+
+pub enum Error {
+    UnexpectedError,
+}
+
+pub async fn upsert<'e, E: PgExecutor<'e>>(
+    e: E,
+    payload: UpsertNotificationSettingPayload,
+) -> Result<NotificationSetting, Error> {
+    sqlx::query_as!(
+        NotificationSetting,
+        "
+        INSERT INTO notification_settings (id, user_id, category, is_enabled)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (user_id, category) DO UPDATE SET
+            is_enabled = EXCLUDED.is_enabled,
+            updated_at = NOW()
+        RETURNING *
+        ",
+        Ulid::new().to_string(),
+        payload.user_id,
+        payload.category.to_string(),
+        payload.is_enabled
+    )
+    .fetch_one(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to save a notification setting: {}",
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_many_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<Vec<NotificationSetting>, Error> {
+    sqlx::query_as!(
+        NotificationSetting,
+        "SELECT * FROM notification_settings WHERE user_id = $1",
+        user_id
+    )
+    .fetch_all(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch notification settings of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_by_user_id_and_category<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+    category: Category,
+) -> Result<Option<NotificationSetting>, Error> {
+    sqlx::query_as!(
+        NotificationSetting,
+        "SELECT * FROM notification_settings WHERE user_id = $1 AND category = $2",
+        user_id,
+        category.to_string()
+    )
+    .fetch_optional(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch notification setting of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn delete_many_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "DELETE FROM notification_settings WHERE user_id = $1",
+        user_id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to delete notification settings of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
@@ -0,0 +1,136 @@
+use bigdecimal::BigDecimal;
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use sqlx::PgExecutor;
+use ulid::Ulid;
+
+#[derive(Serialize, Clone, Debug)]
+pub struct SmsMessage {
+    pub id: String,
+    pub user_id: Option<String>,
+    pub phone_number: String,
+    pub provider: String,
+    pub reference: Option<String>,
+    pub segments: i32,
+    pub cost: BigDecimal,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+// What a single sms cost us, `cost` is in naira and is the provider's figure when it reports one
+pub struct CreateSmsMessagePayload {
+    pub user_id: Option<String>,
+    pub phone_number: String,
+    pub provider: String,
+    pub reference: Option<String>,
+    pub segments: i32,
+    pub cost: BigDecimal,
+}
+
+pub enum Error {
+    UnexpectedError,
+}
+
+pub async fn create<'e, E: PgExecutor<'e>>(
+    e: E,
+    payload: CreateSmsMessagePayload,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "
+        INSERT INTO sms_messages (id, user_id, phone_number, provider, reference, segments, cost)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        ",
+        Ulid::new().to_string(),
+        payload.user_id,
+        payload.phone_number,
+        payload.provider,
+        payload.reference,
+        payload.segments,
+        payload.cost
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!("Error occurred while trying to record an sms: {}", err);
+        Error::UnexpectedError
+    })
+}
+
+pub async fn sum_cost_since<'e, E: PgExecutor<'e>>(
+    e: E,
+    since: NaiveDateTime,
+) -> Result<BigDecimal, Error> {
+    sqlx::query!(
+        r#"
+        SELECT
+            COALESCE(SUM(cost), 0) AS "total!"
+        FROM
+            sms_messages
+        WHERE
+            created_at >= $1
+        "#,
+        since
+    )
+    .fetch_one(e)
+    .await
+    .map(|record| record.total)
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to sum the cost of sms sent since {}: {}",
+            since,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_many_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<Vec<SmsMessage>, Error> {
+    sqlx::query_as!(
+        SmsMessage,
+        "SELECT * FROM sms_messages WHERE user_id = $1 ORDER BY created_at DESC",
+        user_id
+    )
+    .fetch_all(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch the sms of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+// NOTE: the costs are kept for accounting, only the phone number goes
+pub async fn anonymise_many_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "
+        UPDATE sms_messages
+        SET
+            phone_number = '',
+            updated_at = NOW()
+        WHERE
+            user_id = $1
+        ",
+        user_id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to anonymise the sms of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
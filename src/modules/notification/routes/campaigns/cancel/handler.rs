@@ -0,0 +1,19 @@
+use super::service::service;
+use super::types::request;
+use crate::{
+    modules::auth::{middleware::RequirePermission, permission},
+    types::Context,
+};
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+pub async fn handler(
+    State(ctx): State<Arc<Context>>,
+    _: RequirePermission<permission::ManageNotifications>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    service(ctx, request::Payload { id }).await
+}
@@ -0,0 +1,22 @@
+use super::types::{request, response};
+use crate::{modules::notification::repository, types::Context};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    if repository::campaign::find_by_id(&ctx.db_conn.pool, payload.id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToCancelCampaign)?
+        .is_none()
+    {
+        return Err(response::Error::CampaignNotFound);
+    }
+
+    let is_cancelled = repository::campaign::cancel_by_id(&ctx.db_conn.pool, payload.id)
+        .await
+        .map_err(|_| response::Error::FailedToCancelCampaign)?;
+
+    match is_cancelled {
+        true => Ok(response::Success::CampaignCancelled),
+        false => Err(response::Error::CampaignAlreadyFinished),
+    }
+}
@@ -0,0 +1,56 @@
+pub mod request {
+    pub struct Payload {
+        pub id: String,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        CampaignCancelled,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::CampaignCancelled => (
+                    StatusCode::OK,
+                    Json(json!({ "message": "Campaign cancelled" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        CampaignNotFound,
+        CampaignAlreadyFinished,
+        FailedToCancelCampaign,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::CampaignNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Campaign not found" })),
+                )
+                    .into_response(),
+                Self::CampaignAlreadyFinished => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Campaign has already been sent or cancelled" })),
+                )
+                    .into_response(),
+                Self::FailedToCancelCampaign => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to cancel campaign" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
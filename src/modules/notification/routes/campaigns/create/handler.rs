@@ -0,0 +1,26 @@
+use super::service::service;
+use super::types::request;
+use crate::{
+    modules::auth::{middleware::RequirePermission, permission},
+    types::Context,
+};
+use axum::{
+    extract::{Json, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+pub async fn handler(
+    State(ctx): State<Arc<Context>>,
+    auth: RequirePermission<permission::ManageNotifications>,
+    Json(body): Json<request::Body>,
+) -> impl IntoResponse {
+    service(
+        ctx,
+        request::Payload {
+            body,
+            user_id: auth.user.id,
+        },
+    )
+    .await
+}
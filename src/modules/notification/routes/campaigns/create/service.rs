@@ -0,0 +1,34 @@
+use super::types::{request, response};
+use crate::{modules::notification::repository::campaign, types::Context};
+use chrono::Utc;
+use std::sync::Arc;
+use validator::Validate;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    payload.body.validate().map_err(|errors| {
+        tracing::warn!("Failed to validate payload: {errors}");
+        response::Error::FailedToValidate(errors)
+    })?;
+
+    let now = Utc::now().naive_utc();
+    let scheduled_for = payload.body.scheduled_for.unwrap_or(now);
+
+    if scheduled_for < now {
+        return Err(response::Error::ScheduledInThePast);
+    }
+
+    campaign::create(
+        &ctx.db_conn.pool,
+        campaign::CreateCampaignPayload {
+            title: payload.body.title,
+            body: payload.body.body,
+            link: payload.body.link,
+            segment: payload.body.segment,
+            scheduled_for,
+            created_by: payload.user_id,
+        },
+    )
+    .await
+    .map(response::Success::CampaignCreated)
+    .map_err(|_| response::Error::FailedToCreateCampaign)
+}
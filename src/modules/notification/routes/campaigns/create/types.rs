@@ -0,0 +1,76 @@
+pub mod request {
+    use crate::modules::notification::{
+        repository::campaign::Segment, service::campaign::validate_segment,
+    };
+    use chrono::NaiveDateTime;
+    use serde::Deserialize;
+    use validator::Validate;
+
+    // `scheduled_for` is in UTC, the campaign goes out right away when it's left out
+    #[derive(Deserialize, Validate)]
+    pub struct Body {
+        #[validate(length(min = 1, max = 65))]
+        pub title: String,
+        #[validate(length(min = 1, max = 240))]
+        pub body: String,
+        pub link: Option<String>,
+        #[serde(default)]
+        #[validate(custom(function = "validate_segment"))]
+        pub segment: Segment,
+        pub scheduled_for: Option<NaiveDateTime>,
+    }
+
+    pub struct Payload {
+        pub body: Body,
+        pub user_id: String,
+    }
+}
+
+pub mod response {
+    use crate::modules::notification::repository::campaign::Campaign;
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+    use validator::ValidationErrors;
+
+    pub enum Success {
+        CampaignCreated(Campaign),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::CampaignCreated(campaign) => {
+                    (StatusCode::CREATED, Json(json!(campaign))).into_response()
+                }
+            }
+        }
+    }
+
+    pub enum Error {
+        ScheduledInThePast,
+        FailedToCreateCampaign,
+        FailedToValidate(ValidationErrors),
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::ScheduledInThePast => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Campaign can't be scheduled in the past" })),
+                )
+                    .into_response(),
+                Self::FailedToCreateCampaign => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to create campaign" })),
+                )
+                    .into_response(),
+                Self::FailedToValidate(errors) => {
+                    (StatusCode::BAD_REQUEST, Json(json!({ "errors": errors }))).into_response()
+                }
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -0,0 +1,11 @@
+use super::types::{request, response};
+use crate::{modules::notification::repository, types::Context};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    repository::campaign::find_by_id(&ctx.db_conn.pool, payload.id)
+        .await
+        .map_err(|_| response::Error::FailedToFetchCampaign)?
+        .map(response::Success::Campaign)
+        .ok_or(response::Error::CampaignNotFound)
+}
@@ -0,0 +1,47 @@
+pub mod request {
+    pub struct Payload {
+        pub id: String,
+    }
+}
+
+pub mod response {
+    use crate::modules::notification::repository::campaign::Campaign;
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        Campaign(Campaign),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::Campaign(campaign) => (StatusCode::OK, Json(json!(campaign))).into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        CampaignNotFound,
+        FailedToFetchCampaign,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::CampaignNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Campaign not found" })),
+                )
+                    .into_response(),
+                Self::FailedToFetchCampaign => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch campaign" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
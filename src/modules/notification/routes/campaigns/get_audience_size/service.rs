@@ -0,0 +1,17 @@
+use super::types::{request, response};
+use crate::{modules::notification::repository, types::Context};
+use std::sync::Arc;
+use validator::Validate;
+
+// How many users a campaign with this segment would reach if it went out now
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    payload.body.validate().map_err(|errors| {
+        tracing::warn!("Failed to validate payload: {errors}");
+        response::Error::FailedToValidate(errors)
+    })?;
+
+    repository::campaign::find_many_user_ids_by_segment(&ctx.db_conn.pool, &payload.body.segment)
+        .await
+        .map(|user_ids| response::Success::AudienceSize(user_ids.len()))
+        .map_err(|_| response::Error::FailedToFetchAudienceSize)
+}
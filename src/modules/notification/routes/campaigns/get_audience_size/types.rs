@@ -0,0 +1,60 @@
+pub mod request {
+    use crate::modules::notification::{
+        repository::campaign::Segment, service::campaign::validate_segment,
+    };
+    use serde::Deserialize;
+    use validator::Validate;
+
+    #[derive(Deserialize, Validate)]
+    pub struct Body {
+        #[serde(default)]
+        #[validate(custom(function = "validate_segment"))]
+        pub segment: Segment,
+    }
+
+    pub struct Payload {
+        pub body: Body,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+    use validator::ValidationErrors;
+
+    pub enum Success {
+        AudienceSize(usize),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::AudienceSize(count) => {
+                    (StatusCode::OK, Json(json!({ "count": count }))).into_response()
+                }
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToFetchAudienceSize,
+        FailedToValidate(ValidationErrors),
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToFetchAudienceSize => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch audience size" })),
+                )
+                    .into_response(),
+                Self::FailedToValidate(errors) => {
+                    (StatusCode::BAD_REQUEST, Json(json!({ "errors": errors }))).into_response()
+                }
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
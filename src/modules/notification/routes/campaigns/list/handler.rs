@@ -0,0 +1,17 @@
+use super::service::service;
+use super::types::request;
+use crate::{
+    modules::auth::{middleware::RequirePermission, permission},
+    types::Context,
+    utils::pagination::Pagination,
+};
+use axum::{extract::State, response::IntoResponse};
+use std::sync::Arc;
+
+pub async fn handler(
+    State(ctx): State<Arc<Context>>,
+    _: RequirePermission<permission::ManageNotifications>,
+    pagination: Pagination,
+) -> impl IntoResponse {
+    service(ctx, request::Payload { pagination }).await
+}
@@ -0,0 +1,10 @@
+use super::types::{request, response};
+use crate::{modules::notification::repository, types::Context};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    repository::campaign::find_many(&ctx.db_conn.pool, payload.pagination)
+        .await
+        .map(response::Success::Campaigns)
+        .map_err(|_| response::Error::FailedToFetchCampaigns)
+}
@@ -0,0 +1,47 @@
+pub mod request {
+    use crate::utils::pagination::Pagination;
+
+    pub struct Payload {
+        pub pagination: Pagination,
+    }
+}
+
+pub mod response {
+    use crate::{
+        modules::notification::repository::campaign::Campaign, utils::pagination::Paginated,
+    };
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        Campaigns(Paginated<Campaign>),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::Campaigns(campaigns) => {
+                    (StatusCode::OK, Json(json!(campaigns))).into_response()
+                }
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToFetchCampaigns,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToFetchCampaigns => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch campaigns" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
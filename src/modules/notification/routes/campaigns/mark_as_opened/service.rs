@@ -0,0 +1,40 @@
+use super::types::{request, response};
+use crate::{modules::notification::repository, types::Context};
+use std::sync::Arc;
+
+// Called by the app when the user opens the campaign's deep link, opening it again doesn't count
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let mut tx = ctx.db_conn.pool.begin().await.map_err(|err| {
+        tracing::error!("Failed to start database transaction: {}", err);
+        response::Error::FailedToMarkCampaignAsOpened
+    })?;
+
+    if repository::campaign::find_by_id(&mut *tx, payload.id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToMarkCampaignAsOpened)?
+        .is_none()
+    {
+        return Err(response::Error::CampaignNotFound);
+    }
+
+    let is_marked = repository::campaign::mark_recipient_as_opened_by_campaign_id_and_user_id(
+        &mut *tx,
+        payload.id.clone(),
+        payload.auth.user.id,
+    )
+    .await
+    .map_err(|_| response::Error::FailedToMarkCampaignAsOpened)?;
+
+    if is_marked {
+        repository::campaign::increment_open_count_by_id(&mut *tx, payload.id)
+            .await
+            .map_err(|_| response::Error::FailedToMarkCampaignAsOpened)?;
+    }
+
+    tx.commit().await.map_err(|err| {
+        tracing::error!("Failed to commit database transaction: {}", err);
+        response::Error::FailedToMarkCampaignAsOpened
+    })?;
+
+    Ok(response::Success::CampaignMarkedAsOpened)
+}
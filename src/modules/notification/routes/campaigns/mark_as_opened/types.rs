@@ -0,0 +1,53 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub id: String,
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        CampaignMarkedAsOpened,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::CampaignMarkedAsOpened => (
+                    StatusCode::OK,
+                    Json(json!({ "message": "Campaign marked as opened" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        CampaignNotFound,
+        FailedToMarkCampaignAsOpened,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::CampaignNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Campaign not found" })),
+                )
+                    .into_response(),
+                Self::FailedToMarkCampaignAsOpened => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to mark campaign as opened" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
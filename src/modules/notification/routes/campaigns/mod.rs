@@ -0,0 +1,23 @@
+mod cancel;
+mod create;
+mod get;
+mod get_audience_size;
+mod list;
+mod mark_as_opened;
+
+use crate::types::Context;
+use axum::routing::Router;
+use std::sync::Arc;
+
+pub fn get_router() -> Router<Arc<Context>> {
+    Router::new().nest(
+        "/campaigns",
+        Router::new()
+            .nest("/", list::get_router())
+            .nest("/", create::get_router())
+            .nest("/", get_audience_size::get_router())
+            .nest("/", get::get_router())
+            .nest("/", cancel::get_router())
+            .nest("/", mark_as_opened::get_router()),
+    )
+}
@@ -0,0 +1,18 @@
+use super::types::{request, response};
+use crate::{modules::notification::repository, types::Context};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let is_deleted = repository::inbox::delete_by_id_and_user_id(
+        &ctx.db_conn.pool,
+        payload.id,
+        payload.auth.user.id,
+    )
+    .await
+    .map_err(|_| response::Error::FailedToDeleteNotification)?;
+
+    match is_deleted {
+        true => Ok(response::Success::NotificationDeleted),
+        false => Err(response::Error::NotificationNotFound),
+    }
+}
@@ -0,0 +1,15 @@
+use super::types::{request, response};
+use crate::{modules::notification::repository, types::Context};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let is_deleted =
+        repository::quiet_hours::delete_by_user_id(&ctx.db_conn.pool, payload.auth.user.id)
+            .await
+            .map_err(|_| response::Error::FailedToDeleteQuietHours)?;
+
+    match is_deleted {
+        true => Ok(response::Success::QuietHoursDeleted),
+        false => Err(response::Error::QuietHoursNotSet),
+    }
+}
@@ -0,0 +1,52 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        QuietHoursDeleted,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::QuietHoursDeleted => (
+                    StatusCode::OK,
+                    Json(json!({ "message": "Quiet hours removed" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        QuietHoursNotSet,
+        FailedToDeleteQuietHours,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::QuietHoursNotSet => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Quiet hours not set" })),
+                )
+                    .into_response(),
+                Self::FailedToDeleteQuietHours => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to remove quiet hours" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
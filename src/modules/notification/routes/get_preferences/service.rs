@@ -0,0 +1,38 @@
+use super::types::{request, response};
+use crate::{
+    modules::notification::{
+        repository,
+        service::{BACKENDS, CONFIGURABLE_CATEGORIES},
+    },
+    types::Context,
+};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let preferences =
+        repository::preference::find_many_by_user_id(&ctx.db_conn.pool, payload.auth.user.id)
+            .await
+            .map_err(|_| response::Error::FailedToFetchPreferences)?;
+
+    Ok(response::Success::Preferences(
+        CONFIGURABLE_CATEGORIES
+            .into_iter()
+            .flat_map(|category| {
+                BACKENDS
+                    .into_iter()
+                    .map(move |backend| (category.clone(), backend))
+            })
+            .map(|(category, backend)| response::Preference {
+                is_enabled: preferences
+                    .iter()
+                    .find(|preference| {
+                        preference.category == category && preference.backend == backend
+                    })
+                    .map(|preference| preference.is_enabled)
+                    .unwrap_or(true),
+                category,
+                backend,
+            })
+            .collect(),
+    ))
+}
@@ -0,0 +1,53 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use crate::modules::notification::service::{Backend, Category};
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde::Serialize;
+    use serde_json::json;
+
+    #[derive(Serialize)]
+    pub struct Preference {
+        pub category: Category,
+        pub backend: Backend,
+        pub is_enabled: bool,
+    }
+
+    pub enum Success {
+        Preferences(Vec<Preference>),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::Preferences(preferences) => {
+                    (StatusCode::OK, Json(json!(preferences))).into_response()
+                }
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToFetchPreferences,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToFetchPreferences => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch notification preferences" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -0,0 +1,11 @@
+use super::types::{request, response};
+use crate::{modules::notification::repository, types::Context};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    repository::quiet_hours::find_by_user_id(&ctx.db_conn.pool, payload.auth.user.id)
+        .await
+        .map_err(|_| response::Error::FailedToFetchQuietHours)?
+        .map(response::Success::QuietHours)
+        .ok_or(response::Error::QuietHoursNotSet)
+}
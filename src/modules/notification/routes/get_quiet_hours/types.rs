@@ -0,0 +1,51 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use crate::modules::notification::repository::quiet_hours::QuietHours;
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        QuietHours(QuietHours),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::QuietHours(quiet_hours) => {
+                    (StatusCode::OK, Json(json!(quiet_hours))).into_response()
+                }
+            }
+        }
+    }
+
+    pub enum Error {
+        QuietHoursNotSet,
+        FailedToFetchQuietHours,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::QuietHoursNotSet => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Quiet hours not set" })),
+                )
+                    .into_response(),
+                Self::FailedToFetchQuietHours => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch quiet hours" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
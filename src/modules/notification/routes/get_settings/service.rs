@@ -0,0 +1,27 @@
+use super::types::{request, response};
+use crate::{
+    modules::notification::{repository, service::CONFIGURABLE_CATEGORIES},
+    types::Context,
+};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let settings =
+        repository::setting::find_many_by_user_id(&ctx.db_conn.pool, payload.auth.user.id)
+            .await
+            .map_err(|_| response::Error::FailedToFetchSettings)?;
+
+    Ok(response::Success::Settings(
+        CONFIGURABLE_CATEGORIES
+            .into_iter()
+            .map(|category| response::Setting {
+                is_enabled: settings
+                    .iter()
+                    .find(|setting| setting.category == category)
+                    .map(|setting| setting.is_enabled)
+                    .unwrap_or(true),
+                category,
+            })
+            .collect(),
+    ))
+}
@@ -0,0 +1,50 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use crate::modules::notification::service::Category;
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde::Serialize;
+    use serde_json::json;
+
+    #[derive(Serialize)]
+    pub struct Setting {
+        pub category: Category,
+        pub is_enabled: bool,
+    }
+
+    pub enum Success {
+        Settings(Vec<Setting>),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::Settings(settings) => (StatusCode::OK, Json(json!(settings))).into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToFetchSettings,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToFetchSettings => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch notification settings" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
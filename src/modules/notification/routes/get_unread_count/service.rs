@@ -0,0 +1,10 @@
+use super::types::{request, response};
+use crate::{modules::notification::repository, types::Context};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    repository::inbox::count_unread_by_user_id(&ctx.db_conn.pool, payload.auth.user.id)
+        .await
+        .map(response::Success::UnreadCount)
+        .map_err(|_| response::Error::FailedToFetchUnreadCount)
+}
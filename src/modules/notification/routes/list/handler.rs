@@ -0,0 +1,13 @@
+use super::service::service;
+use super::types::request;
+use crate::{modules::auth::middleware::Auth, types::Context, utils::pagination::Pagination};
+use axum::{extract::State, response::IntoResponse};
+use std::sync::Arc;
+
+pub async fn handler(
+    State(ctx): State<Arc<Context>>,
+    pagination: Pagination,
+    auth: Auth,
+) -> impl IntoResponse {
+    service(ctx, request::Payload { pagination, auth }).await
+}
@@ -0,0 +1,14 @@
+use super::types::{request, response};
+use crate::{modules::notification::repository, types::Context};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    repository::inbox::find_many_by_user_id(
+        &ctx.db_conn.pool,
+        payload.pagination,
+        payload.auth.user.id,
+    )
+    .await
+    .map(response::Success::Notifications)
+    .map_err(|_| response::Error::FailedToFetchNotifications)
+}
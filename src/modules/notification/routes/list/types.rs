@@ -0,0 +1,48 @@
+pub mod request {
+    use crate::{modules::auth::middleware::Auth, utils::pagination::Pagination};
+
+    pub struct Payload {
+        pub pagination: Pagination,
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use crate::{
+        modules::notification::repository::inbox::InboxNotification, utils::pagination::Paginated,
+    };
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        Notifications(Paginated<InboxNotification>),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::Notifications(notifications) => {
+                    (StatusCode::OK, Json(json!(notifications))).into_response()
+                }
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToFetchNotifications,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToFetchNotifications => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch notifications" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -0,0 +1,10 @@
+use super::types::{request, response};
+use crate::{modules::notification::repository, types::Context};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    repository::inbox::mark_all_as_read_by_user_id(&ctx.db_conn.pool, payload.auth.user.id)
+        .await
+        .map(|_| response::Success::NotificationsMarkedAsRead)
+        .map_err(|_| response::Error::FailedToMarkNotificationsAsRead)
+}
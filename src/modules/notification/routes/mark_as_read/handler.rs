@@ -0,0 +1,16 @@
+use super::service::service;
+use super::types::request;
+use crate::{modules::auth::middleware::Auth, types::Context};
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+pub async fn handler(
+    State(ctx): State<Arc<Context>>,
+    Path(id): Path<String>,
+    auth: Auth,
+) -> impl IntoResponse {
+    service(ctx, request::Payload { id, auth }).await
+}
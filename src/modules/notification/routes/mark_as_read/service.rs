@@ -0,0 +1,18 @@
+use super::types::{request, response};
+use crate::{modules::notification::repository, types::Context};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let is_marked = repository::inbox::mark_as_read_by_id_and_user_id(
+        &ctx.db_conn.pool,
+        payload.id,
+        payload.auth.user.id,
+    )
+    .await
+    .map_err(|_| response::Error::FailedToMarkNotificationAsRead)?;
+
+    match is_marked {
+        true => Ok(response::Success::NotificationMarkedAsRead),
+        false => Err(response::Error::NotificationNotFound),
+    }
+}
@@ -0,0 +1,53 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub id: String,
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        NotificationMarkedAsRead,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::NotificationMarkedAsRead => (
+                    StatusCode::OK,
+                    Json(json!({ "message": "Notification marked as read" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        NotificationNotFound,
+        FailedToMarkNotificationAsRead,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::NotificationNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Notification not found" })),
+                )
+                    .into_response(),
+                Self::FailedToMarkNotificationAsRead => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to mark notification as read" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
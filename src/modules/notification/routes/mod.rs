@@ -1,9 +1,38 @@
+mod campaigns;
+mod delete;
+mod delete_quiet_hours;
+mod get_preferences;
+mod get_quiet_hours;
+mod get_settings;
+mod get_unread_count;
+mod list;
+mod mark_all_as_read;
+mod mark_as_read;
+mod preview_template;
 mod push_token;
+mod update_preferences;
+mod update_quiet_hours;
+mod update_settings;
 
 use crate::types::Context;
 use axum::routing::Router;
 use std::sync::Arc;
 
 pub fn get_router() -> Router<Arc<Context>> {
-    Router::new().nest("/", push_token::get_router())
+    Router::new()
+        .nest("/", list::get_router())
+        .nest("/", get_unread_count::get_router())
+        .nest("/", mark_all_as_read::get_router())
+        .nest("/", mark_as_read::get_router())
+        .nest("/", delete::get_router())
+        .nest("/", get_settings::get_router())
+        .nest("/", update_settings::get_router())
+        .nest("/", get_preferences::get_router())
+        .nest("/", update_preferences::get_router())
+        .nest("/", get_quiet_hours::get_router())
+        .nest("/", update_quiet_hours::get_router())
+        .nest("/", delete_quiet_hours::get_router())
+        .nest("/", push_token::get_router())
+        .nest("/", preview_template::get_router())
+        .nest("/", campaigns::get_router())
 }
@@ -0,0 +1,11 @@
+use super::service::service;
+use super::types::request;
+use crate::modules::auth::{middleware::RequirePermission, permission};
+use axum::{extract::Json, response::IntoResponse};
+
+pub async fn handler(
+    _: RequirePermission<permission::ManageNotifications>,
+    Json(body): Json<request::Body>,
+) -> impl IntoResponse {
+    service(request::Payload { body }).await
+}
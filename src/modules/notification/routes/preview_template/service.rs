@@ -0,0 +1,18 @@
+use super::types::{request, response};
+use crate::modules::notification::service::template;
+
+// Renders a template with whatever sample variables the admin passed, missing ones are left as placeholders
+pub async fn service(payload: request::Payload) -> response::Response {
+    if !template::NAMES.contains(&payload.body.template.as_str()) {
+        return Err(response::Error::TemplateNotFound);
+    }
+
+    template::render_template(
+        &payload.body.template,
+        &payload.body.backend,
+        &payload.body.locale,
+        &payload.body.variables,
+    )
+    .map(response::Success::Preview)
+    .map_err(|_| response::Error::TemplateNotAvailableForBackend)
+}
@@ -0,0 +1,61 @@
+pub mod request {
+    use crate::modules::{notification::service::Backend, user::repository::Locale};
+    use serde::Deserialize;
+    use std::collections::HashMap;
+
+    #[derive(Deserialize)]
+    pub struct Body {
+        pub template: String,
+        pub backend: Backend,
+        #[serde(default)]
+        pub locale: Locale,
+        #[serde(default)]
+        pub variables: HashMap<String, String>,
+    }
+
+    pub struct Payload {
+        pub body: Body,
+    }
+}
+
+pub mod response {
+    use crate::modules::notification::service::template::Rendered;
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        Preview(Rendered),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::Preview(rendered) => (StatusCode::OK, Json(json!(rendered))).into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        TemplateNotFound,
+        TemplateNotAvailableForBackend,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::TemplateNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Template not found" })),
+                )
+                    .into_response(),
+                Self::TemplateNotAvailableForBackend => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Template isn't available for this backend" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -8,6 +8,7 @@ pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::
         repository::push_token::CreatePushTokenPayload {
             token: payload.body.token,
             user_id: payload.auth.user.id,
+            session_id: payload.auth.session_id,
         },
     )
     .await
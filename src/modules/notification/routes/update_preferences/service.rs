@@ -0,0 +1,25 @@
+use super::types::{request, response};
+use crate::{
+    modules::notification::{repository, service::CONFIGURABLE_CATEGORIES},
+    types::Context,
+};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    if !CONFIGURABLE_CATEGORIES.contains(&payload.body.category) {
+        return Err(response::Error::CategoryNotConfigurable);
+    }
+
+    repository::preference::upsert(
+        &ctx.db_conn.pool,
+        repository::preference::UpsertNotificationPreferencePayload {
+            user_id: payload.auth.user.id,
+            category: payload.body.category,
+            backend: payload.body.backend,
+            is_enabled: payload.body.is_enabled,
+        },
+    )
+    .await
+    .map(|_| response::Success::PreferencesUpdated)
+    .map_err(|_| response::Error::FailedToUpdatePreferences)
+}
@@ -0,0 +1,64 @@
+pub mod request {
+    use crate::modules::{
+        auth::middleware::Auth,
+        notification::service::{Backend, Category},
+    };
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    pub struct Body {
+        pub category: Category,
+        pub backend: Backend,
+        pub is_enabled: bool,
+    }
+
+    pub struct Payload {
+        pub body: Body,
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        PreferencesUpdated,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::PreferencesUpdated => (
+                    StatusCode::OK,
+                    Json(json!({ "message": "Notification preferences updated" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        CategoryNotConfigurable,
+        FailedToUpdatePreferences,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::CategoryNotConfigurable => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Transactional notifications can't be turned off" })),
+                )
+                    .into_response(),
+                Self::FailedToUpdatePreferences => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to update notification preferences" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
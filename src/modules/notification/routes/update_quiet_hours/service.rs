@@ -0,0 +1,23 @@
+use super::types::{request, response};
+use crate::{modules::notification::repository, types::Context};
+use chrono_tz::Tz;
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    if payload.body.timezone.parse::<Tz>().is_err() {
+        return Err(response::Error::InvalidTimezone);
+    }
+
+    repository::quiet_hours::upsert(
+        &ctx.db_conn.pool,
+        repository::quiet_hours::UpsertQuietHoursPayload {
+            user_id: payload.auth.user.id,
+            starts_at: payload.body.starts_at,
+            ends_at: payload.body.ends_at,
+            timezone: payload.body.timezone,
+        },
+    )
+    .await
+    .map(response::Success::QuietHoursUpdated)
+    .map_err(|_| response::Error::FailedToUpdateQuietHours)
+}
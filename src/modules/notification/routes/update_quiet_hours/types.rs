@@ -0,0 +1,62 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+    use chrono::NaiveTime;
+    use serde::Deserialize;
+
+    // e.g { "starts_at": "22:00:00", "ends_at": "07:00:00", "timezone": "Africa/Lagos" }
+    #[derive(Deserialize)]
+    pub struct Body {
+        pub starts_at: NaiveTime,
+        pub ends_at: NaiveTime,
+        pub timezone: String,
+    }
+
+    pub struct Payload {
+        pub body: Body,
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use crate::modules::notification::repository::quiet_hours::QuietHours;
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        QuietHoursUpdated(QuietHours),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::QuietHoursUpdated(quiet_hours) => {
+                    (StatusCode::OK, Json(json!(quiet_hours))).into_response()
+                }
+            }
+        }
+    }
+
+    pub enum Error {
+        InvalidTimezone,
+        FailedToUpdateQuietHours,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::InvalidTimezone => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Invalid timezone" })),
+                )
+                    .into_response(),
+                Self::FailedToUpdateQuietHours => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to update quiet hours" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
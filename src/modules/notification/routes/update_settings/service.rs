@@ -0,0 +1,24 @@
+use super::types::{request, response};
+use crate::{
+    modules::notification::{repository, service::CONFIGURABLE_CATEGORIES},
+    types::Context,
+};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    if !CONFIGURABLE_CATEGORIES.contains(&payload.body.category) {
+        return Err(response::Error::CategoryNotConfigurable);
+    }
+
+    repository::setting::upsert(
+        &ctx.db_conn.pool,
+        repository::setting::UpsertNotificationSettingPayload {
+            user_id: payload.auth.user.id,
+            category: payload.body.category,
+            is_enabled: payload.body.is_enabled,
+        },
+    )
+    .await
+    .map(|_| response::Success::SettingsUpdated)
+    .map_err(|_| response::Error::FailedToUpdateSettings)
+}
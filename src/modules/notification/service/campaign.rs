@@ -0,0 +1,236 @@
+use super::{preference, push, Backend, Category, Error, Result};
+use crate::{
+    modules::notification::repository::{
+        campaign::{self, Campaign, CampaignRecipient, RecipientStatus, Segment},
+        inbox,
+    },
+    types::Context,
+};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Arc;
+use validator::ValidationError;
+
+// How many recipients of a campaign get their push on every run, with the job running every minute this is also the
+// most a campaign sends per minute
+const BATCH_SIZE: i64 = 500;
+// How long (in seconds) a claimed recipient is kept away from other workers
+const LEASE: i64 = 300;
+
+// NOTE: an empty segment is a broadcast to everyone with a registered device
+pub fn validate_segment(segment: &Segment) -> std::result::Result<(), ValidationError> {
+    let counts = [
+        segment.ordered_within_days,
+        segment.min_order_count,
+        segment.active_within_days,
+    ];
+
+    match counts.into_iter().flatten().all(|count| count > 0) {
+        true => Ok(()),
+        false => Err(
+            ValidationError::new("INVALID_SEGMENT").with_message(Cow::from(
+                "Segment day and order counts must be greater than 0",
+            )),
+        ),
+    }
+}
+
+// What the app gets along with the push, it opens `link` and reports the open back with `campaign_id`
+fn get_data(campaign: &Campaign) -> HashMap<String, String> {
+    let mut data = HashMap::from([(String::from("campaign_id"), campaign.id.clone())]);
+
+    if let Some(link) = &campaign.link {
+        data.insert(String::from("link"), link.clone());
+    }
+
+    data
+}
+
+// Campaigns are promotions, so they follow the user's preferences and wait out their quiet hours
+async fn deliver(
+    ctx: Arc<Context>,
+    campaign: &Campaign,
+    recipient: &CampaignRecipient,
+) -> Result<RecipientStatus> {
+    let user_id = recipient.user_id.clone();
+
+    if !preference::is_enabled(
+        ctx.clone(),
+        user_id.clone(),
+        &Category::Promotions,
+        &Backend::Push,
+    )
+    .await?
+    {
+        return Ok(RecipientStatus::Skipped);
+    }
+
+    if let Some(until) = preference::get_quiet_hours_end(ctx.clone(), user_id.clone()).await? {
+        return Err(Error::Held(until));
+    }
+
+    let delivered = match push::send_to_user(
+        ctx.clone(),
+        user_id.clone(),
+        campaign.title.clone(),
+        campaign.body.clone(),
+        Some(get_data(campaign)),
+    )
+    .await
+    {
+        Ok(delivered) => delivered,
+        Err(_) => return Ok(RecipientStatus::Failed),
+    };
+
+    // NOTE: every device the user had has since been unregistered
+    if delivered == 0 {
+        return Ok(RecipientStatus::Skipped);
+    }
+
+    inbox::create(
+        &ctx.db_conn.pool,
+        inbox::CreateInboxNotificationPayload {
+            user_id,
+            category: Category::Promotions,
+            title: campaign.title.clone(),
+            body: campaign.body.clone(),
+        },
+    )
+    .await
+    .ok();
+
+    Ok(RecipientStatus::Sent)
+}
+
+// Works out who's in the segment of every campaign that is due, the audience is fixed from then on
+async fn start_due(ctx: Arc<Context>) {
+    loop {
+        let mut tx = match ctx.db_conn.pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => {
+                tracing::error!("Failed to start database transaction: {}", err);
+                return;
+            }
+        };
+
+        let campaign = match campaign::claim_due(&mut *tx).await {
+            Ok(Some(campaign)) => campaign,
+            _ => return,
+        };
+
+        let segment = match campaign.get_segment() {
+            Ok(segment) => segment,
+            Err(_) => {
+                if campaign::fail_by_id(&mut *tx, campaign.id.clone())
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+                if let Err(err) = tx.commit().await {
+                    tracing::error!("Failed to commit database transaction: {}", err);
+                    return;
+                }
+                continue;
+            }
+        };
+
+        let user_ids = match campaign::find_many_user_ids_by_segment(&mut *tx, &segment).await {
+            Ok(user_ids) => user_ids,
+            Err(_) => return,
+        };
+        let recipient_count = user_ids.len() as i32;
+
+        if campaign::create_many_recipients(&mut *tx, campaign.id.clone(), user_ids)
+            .await
+            .is_err()
+        {
+            return;
+        }
+
+        if campaign::set_recipient_count_by_id(&mut *tx, campaign.id.clone(), recipient_count)
+            .await
+            .is_err()
+        {
+            return;
+        }
+
+        if let Err(err) = tx.commit().await {
+            tracing::error!("Failed to commit database transaction: {}", err);
+            return;
+        }
+
+        tracing::info!(
+            "Started campaign {} with {} recipients",
+            campaign.id,
+            recipient_count
+        );
+    }
+}
+
+async fn send_batch(ctx: Arc<Context>, campaign: Campaign) {
+    let recipients = match campaign::claim_many_due_recipients(
+        &ctx.db_conn.pool,
+        campaign.id.clone(),
+        BATCH_SIZE,
+        LEASE,
+    )
+    .await
+    {
+        Ok(recipients) => recipients,
+        Err(_) => return,
+    };
+
+    let mut counts = campaign::IncrementCountsPayload {
+        sent: 0,
+        failed: 0,
+        skipped: 0,
+    };
+
+    for recipient in recipients {
+        match deliver(ctx.clone(), &campaign, &recipient).await {
+            Ok(status) => {
+                match status {
+                    RecipientStatus::Sent => counts.sent += 1,
+                    RecipientStatus::Failed => counts.failed += 1,
+                    RecipientStatus::Skipped => counts.skipped += 1,
+                    RecipientStatus::Pending => (),
+                };
+
+                campaign::update_recipient_status_by_id(&ctx.db_conn.pool, recipient.id, status)
+                    .await
+                    .ok();
+            }
+            Err(Error::Held(until)) => {
+                campaign::hold_recipient_by_id(&ctx.db_conn.pool, recipient.id, until)
+                    .await
+                    .ok();
+            }
+            // NOTE: the recipient is picked up again once the lease runs out
+            Err(_) => (),
+        }
+    }
+
+    campaign::increment_counts_by_id(&ctx.db_conn.pool, campaign.id.clone(), counts)
+        .await
+        .ok();
+
+    if let Ok(true) = campaign::complete_by_id(&ctx.db_conn.pool, campaign.id.clone()).await {
+        tracing::info!("Finished sending campaign {}", campaign.id);
+    }
+}
+
+// Starts the campaigns that are due and sends the next batch of every campaign that is going out. A cancelled campaign
+// stops after the batch in flight
+pub async fn send_due(ctx: Arc<Context>) {
+    start_due(ctx.clone()).await;
+
+    let campaigns = match campaign::find_many_sending(&ctx.db_conn.pool).await {
+        Ok(campaigns) => campaigns,
+        Err(_) => return,
+    };
+
+    for campaign in campaigns {
+        send_batch(ctx.clone(), campaign).await;
+    }
+}
@@ -0,0 +1,41 @@
+use super::{Email, Error, Result, Transport};
+use async_trait::async_trait;
+use std::path::PathBuf;
+use ulid::Ulid;
+
+// Writes every email to `directory` as an .eml file instead of sending it, for development and tests
+pub struct FileTransport {
+    directory: PathBuf,
+}
+
+impl FileTransport {
+    pub fn new(directory: String) -> Self {
+        let directory = PathBuf::from(directory);
+
+        std::fs::create_dir_all(&directory).unwrap_or_else(|err| {
+            tracing::error!("{}", err);
+            panic!("Failed to create mail directory {}", directory.display())
+        });
+
+        Self { directory }
+    }
+}
+
+#[async_trait]
+impl Transport for FileTransport {
+    async fn send(&self, email: &Email) -> Result<()> {
+        // NOTE: ulids sort by time, so the mailbox lists in the order the emails were sent
+        let path = self.directory.join(format!("{}.eml", Ulid::new()));
+
+        tokio::fs::write(&path, email.to_message()?.formatted())
+            .await
+            .map_err(|err| {
+                tracing::error!("Failed to write email to {}: {}", path.display(), err);
+                Error::NotSent
+            })?;
+
+        tracing::info!("Email to {} written to {}", email.to, path.display());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use super::{Email, Error, Result, Transport};
+use async_trait::async_trait;
+use lettre::message::Mailbox;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Recipient {
+    name: Option<String>,
+    email: String,
+}
+
+impl From<&Mailbox> for Recipient {
+    fn from(mailbox: &Mailbox) -> Self {
+        Self {
+            name: mailbox.name.clone(),
+            email: mailbox.email.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SendEmailRequest {
+    from: Recipient,
+    to: Vec<Recipient>,
+    subject: String,
+    html: String,
+}
+
+// For email APIs (e.g when the host blocks outgoing SMTP), the email is posted as json with the api key as a bearer
+// token
+pub struct HttpTransport {
+    client: reqwest::Client,
+    api_endpoint: String,
+    api_key: String,
+}
+
+impl HttpTransport {
+    pub fn new(api_endpoint: String, api_key: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            api_endpoint,
+            api_key,
+        }
+    }
+}
+
+#[async_trait]
+impl Transport for HttpTransport {
+    async fn send(&self, email: &Email) -> Result<()> {
+        let res = self
+            .client
+            .post(&self.api_endpoint)
+            .bearer_auth(&self.api_key)
+            .json(&SendEmailRequest {
+                from: Recipient::from(&email.from),
+                to: vec![Recipient::from(&email.to)],
+                subject: email.subject.clone(),
+                html: email.body.clone(),
+            })
+            .send()
+            .await
+            .map_err(|err| {
+                tracing::error!("Failed to reach the email api: {}", err);
+                Error::NotSent
+            })?;
+
+        if !res.status().is_success() {
+            tracing::error!(
+                "Failed to send email: {}",
+                res.text().await.unwrap_or_default()
+            );
+            return Err(Error::NotSent);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+mod file;
+mod http;
+mod smtp;
+
+use super::{template, Backend, Error, Notification, Result};
+use crate::{modules::user::repository::User, Context};
+use async_trait::async_trait;
+use lettre::{
+    message::{header::ContentType, Mailbox},
+    Message,
+};
+use std::sync::Arc;
+
+pub use file::FileTransport;
+pub use http::HttpTransport;
+pub use smtp::{SmtpTransport, SmtpTransportConfig};
+
+pub struct Email {
+    pub from: Mailbox,
+    pub to: Mailbox,
+    pub subject: String,
+    // html
+    pub body: String,
+}
+
+impl Email {
+    pub fn to_message(&self) -> Result<Message> {
+        Message::builder()
+            .from(self.from.clone())
+            .to(self.to.clone())
+            .subject(self.subject.clone())
+            .header(ContentType::TEXT_HTML)
+            .body(self.body.clone())
+            .map_err(|err| {
+                tracing::error!("Failed to build email: {}", err);
+                Error::NotSent
+            })
+    }
+}
+
+// Whatever actually gets the email out, picked with `MAIL_TRANSPORT` and built once on startup
+#[async_trait]
+pub trait Transport: Send + Sync {
+    async fn send(&self, email: &Email) -> Result<()>;
+}
+
+pub async fn send(ctx: Arc<Context>, notification: Notification) -> Result<()> {
+    let rendered = template::render(&notification, &Backend::Email)?;
+
+    send_email(
+        ctx,
+        SendEmailPayload {
+            user: template::get_recipient(&notification).clone(),
+            subject: rendered.title,
+            body: rendered.body,
+        },
+    )
+    .await
+}
+
+struct SendEmailPayload {
+    user: User,
+    body: String,
+    subject: String,
+}
+
+// NOTE: a user without a valid email (e.g one that signed up with just their phone number) is never going to get it,
+// so it fails as `InvalidRecipient` instead of being retried
+fn get_mailbox(user: &User) -> Result<Mailbox> {
+    // NOTE: the name is kept apart from the address, a comma or angle bracket in it would otherwise break the parse
+    let address = user.email.parse().map_err(|err| {
+        tracing::warn!(
+            "Invalid email address for user with id {}: {}",
+            user.id,
+            err
+        );
+        Error::InvalidRecipient
+    })?;
+
+    Ok(Mailbox::new(
+        Some(format!("{} {}", user.first_name, user.last_name)),
+        address,
+    ))
+}
+
+async fn send_email(ctx: Arc<Context>, payload: SendEmailPayload) -> Result<()> {
+    let email = Email {
+        from: ctx.mail.sender.clone(),
+        to: get_mailbox(&payload.user)?,
+        subject: payload.subject,
+        body: payload.body,
+    };
+
+    ctx.mail.transport.send(&email).await
+}
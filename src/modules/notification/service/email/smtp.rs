@@ -0,0 +1,57 @@
+use super::{Email, Error, Result, Transport};
+use async_trait::async_trait;
+use lettre::{
+    transport::smtp::{
+        authentication::{Credentials, Mechanism},
+        PoolConfig,
+    },
+    AsyncSmtpTransport, AsyncTransport, Tokio1Executor,
+};
+
+// NOTE: connections are pooled and reused across sends instead of opening one per email
+pub struct SmtpTransport {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+pub struct SmtpTransportConfig {
+    pub host: String,
+    pub port: Option<u16>,
+    pub user: String,
+    pub password: String,
+    pub pool_size: u32,
+}
+
+impl SmtpTransport {
+    pub fn new(config: SmtpTransportConfig) -> Self {
+        let mut builder = AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host)
+            .unwrap_or_else(|err| {
+                tracing::error!("{}", err);
+                panic!("Invalid mail host {}", config.host)
+            })
+            .authentication(vec![Mechanism::Plain])
+            .credentials(Credentials::new(config.user, config.password))
+            .pool_config(PoolConfig::new().max_size(config.pool_size));
+
+        if let Some(port) = config.port {
+            builder = builder.port(port);
+        }
+
+        Self {
+            transport: builder.build(),
+        }
+    }
+}
+
+#[async_trait]
+impl Transport for SmtpTransport {
+    async fn send(&self, email: &Email) -> Result<()> {
+        self.transport
+            .send(email.to_message()?)
+            .await
+            .map(|_| ())
+            .map_err(|err| {
+                tracing::error!("Failed to send email: {}", err);
+                Error::NotSent
+            })
+    }
+}
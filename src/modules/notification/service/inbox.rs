@@ -0,0 +1,41 @@
+use super::{template, Backend, Category, Error, Notification, Result};
+use crate::modules::notification::repository::inbox;
+use sqlx::PgExecutor;
+
+// OTPs and sign in links are only good for a few minutes, they shouldn't be lying around in the inbox
+fn is_kept(notification: &Notification) -> bool {
+    !matches!(
+        notification,
+        Notification::VerificationOtpRequested(_)
+            | Notification::MagicLinkRequested(_)
+            | Notification::EmailChangeRequested(_)
+    )
+}
+
+// NOTE: the inbox shows the same text as the push notification, a notification without one just isn't kept
+pub async fn store<'e, E: PgExecutor<'e>>(
+    e: E,
+    notification: &Notification,
+    category: Category,
+) -> Result<()> {
+    if !is_kept(notification) {
+        return Ok(());
+    }
+
+    let rendered = match template::render(notification, &Backend::Push) {
+        Ok(rendered) => rendered,
+        Err(_) => return Ok(()),
+    };
+
+    inbox::create(
+        e,
+        inbox::CreateInboxNotificationPayload {
+            user_id: template::get_recipient(notification).id.clone(),
+            category,
+            title: rendered.title,
+            body: rendered.body,
+        },
+    )
+    .await
+    .map_err(|_| Error::NotQueued)
+}
@@ -1,21 +1,114 @@
+pub mod campaign;
 pub mod email;
+pub mod inbox;
+pub mod outbox;
+pub mod preference;
 pub mod push;
 pub mod sms;
+pub mod template;
 
 // use super::{email, push, sms};
-use crate::{modules::order::repository::Order, modules::user::repository::User, types::Context};
+use crate::{
+    modules::kitchen::repository::{Kitchen, KitchenMemberRole},
+    modules::order::repository::{FullOrder, Order},
+    modules::user::repository::{ContactKind, User},
+    modules::notification::repository,
+    types::Context,
+};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::{Acquire, Postgres};
 use std::sync::Arc;
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub enum Backend {
+    #[serde(rename = "EMAIL")]
     Email,
+    #[serde(rename = "PUSH")]
     Push,
+    #[serde(rename = "SMS")]
     Sms,
 }
 
+impl From<String> for Backend {
+    fn from(value: String) -> Self {
+        match value.as_ref() {
+            "EMAIL" => Backend::Email,
+            "PUSH" => Backend::Push,
+            "SMS" => Backend::Sms,
+            backend => unreachable!("Invalid notification backend: {}", backend),
+        }
+    }
+}
+
+impl ToString for Backend {
+    fn to_string(&self) -> String {
+        match self {
+            Backend::Email => String::from("EMAIL"),
+            Backend::Push => String::from("PUSH"),
+            Backend::Sms => String::from("SMS"),
+        }
+    }
+}
+
+// What a notification is about, everything but transactional notifications (OTPs, receipts, security notices...) can
+// be turned off by the user
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum Category {
+    #[serde(rename = "ORDERS")]
+    Orders,
+    #[serde(rename = "PROMOTIONS")]
+    Promotions,
+    #[serde(rename = "WALLET")]
+    Wallet,
+    #[serde(rename = "TRANSACTIONAL")]
+    Transactional,
+}
+
+impl From<String> for Category {
+    fn from(value: String) -> Self {
+        match value.as_ref() {
+            "ORDERS" => Category::Orders,
+            "PROMOTIONS" => Category::Promotions,
+            "WALLET" => Category::Wallet,
+            "TRANSACTIONAL" => Category::Transactional,
+            category => unreachable!("Invalid notification category: {}", category),
+        }
+    }
+}
+
+impl ToString for Category {
+    fn to_string(&self) -> String {
+        match self {
+            Category::Orders => String::from("ORDERS"),
+            Category::Promotions => String::from("PROMOTIONS"),
+            Category::Wallet => String::from("WALLET"),
+            Category::Transactional => String::from("TRANSACTIONAL"),
+        }
+    }
+}
+
+impl Category {
+    // NOTE: an order that's on its way can't wait for the morning
+    pub fn is_urgent(&self) -> bool {
+        matches!(self, Category::Transactional | Category::Orders)
+    }
+}
+
+pub const CONFIGURABLE_CATEGORIES: [Category; 3] =
+    [Category::Orders, Category::Promotions, Category::Wallet];
+
+pub const BACKENDS: [Backend; 3] = [Backend::Email, Backend::Push, Backend::Sms];
+
 pub mod types {
-    use crate::modules::{order::repository::Order, user::repository::User};
+    use crate::modules::{
+        kitchen::repository::{Kitchen, KitchenMemberRole},
+        order::repository::{FullOrder, Order},
+        user::repository::{ContactKind, User},
+    };
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Clone)]
+    #[derive(Clone, Serialize, Deserialize)]
     pub struct Registered {
         pub user: User,
     }
@@ -25,44 +118,97 @@ pub mod types {
     //     pub order: Order,
     // }
 
-    #[derive(Clone)]
+    #[derive(Clone, Serialize, Deserialize)]
     pub struct VerificationOtpRequested {
         pub user: User,
+        pub code: String,
+        pub validity: i32,
+    }
+
+    #[derive(Clone, Serialize, Deserialize)]
+    pub struct MagicLinkRequested {
+        pub user: User,
+        pub link: String,
+        pub validity: i32,
+    }
+
+    // `user` carries the new email, the link confirms that it belongs to them
+    #[derive(Clone, Serialize, Deserialize)]
+    pub struct EmailChangeRequested {
+        pub user: User,
+        pub link: String,
+        pub validity: i32,
     }
 
-    #[derive(Clone)]
+    // `user` still carries the previous contact details, that's who gets told about the change
+    #[derive(Clone, Serialize, Deserialize)]
+    pub struct ContactChanged {
+        pub user: User,
+        pub kind: ContactKind,
+    }
+
+    #[derive(Clone, Serialize, Deserialize)]
     pub struct CustomerIdentificationFailed {
         pub user: User,
         pub reason: String,
     }
 
-    #[derive(Clone)]
+    #[derive(Clone, Serialize, Deserialize)]
     pub struct BankAccountCreationSuccessful {
         pub user: User,
     }
 
-    #[derive(Clone)]
+    #[derive(Clone, Serialize, Deserialize)]
     pub struct BankAccountCreationFailed {
         pub user: User,
     }
 
-    #[derive(Clone)]
+    #[derive(Clone, Serialize, Deserialize)]
     pub struct OrderStatusUpdated {
         pub user: User,
         pub order: Order,
+        pub kitchen: Kitchen,
+    }
+
+    // Emailed to the customer once the order is paid for
+    #[derive(Clone, Serialize, Deserialize)]
+    pub struct OrderReceipt {
+        pub user: User,
+        pub order: FullOrder,
+    }
+
+    // What the kitchen has to prepare and where it's going, `user` is the kitchen owner
+    #[derive(Clone, Serialize, Deserialize)]
+    pub struct KitchenOrderSummary {
+        pub user: User,
+        pub order: FullOrder,
+    }
+
+    #[derive(Clone, Serialize, Deserialize)]
+    pub struct KitchenInvitationReceived {
+        pub user: User,
+        pub kitchen: Kitchen,
+        pub role: KitchenMemberRole,
     }
 }
 
 // TODO: handle these notifications
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Notification {
     Registered(types::Registered),
     // OrderPaid(types::OrderPaid),
     VerificationOtpRequested(types::VerificationOtpRequested),
+    MagicLinkRequested(types::MagicLinkRequested),
+    EmailChangeRequested(types::EmailChangeRequested),
+    ContactChanged(types::ContactChanged),
     // CustomerIdentificationFailed(types::CustomerIdentificationFailed),
     BankAccountCreationSuccessful(types::BankAccountCreationSuccessful),
     BankAccountCreationFailed(types::BankAccountCreationFailed),
     OrderStatusUpdated(types::OrderStatusUpdated),
+    OrderReceipt(types::OrderReceipt),
+    KitchenOrderSummary(types::KitchenOrderSummary),
+    KitchenInvitationReceived(types::KitchenInvitationReceived),
 }
 
 impl Notification {
@@ -70,8 +216,32 @@ impl Notification {
         Notification::Registered(types::Registered { user })
     }
 
-    pub fn verification_otp_requested(user: User) -> Self {
-        Notification::VerificationOtpRequested(types::VerificationOtpRequested { user })
+    pub fn verification_otp_requested(user: User, code: String, validity: i32) -> Self {
+        Notification::VerificationOtpRequested(types::VerificationOtpRequested {
+            user,
+            code,
+            validity,
+        })
+    }
+
+    pub fn magic_link_requested(user: User, link: String, validity: i32) -> Self {
+        Notification::MagicLinkRequested(types::MagicLinkRequested {
+            user,
+            link,
+            validity,
+        })
+    }
+
+    pub fn email_change_requested(user: User, link: String, validity: i32) -> Self {
+        Notification::EmailChangeRequested(types::EmailChangeRequested {
+            user,
+            link,
+            validity,
+        })
+    }
+
+    pub fn contact_changed(user: User, kind: ContactKind) -> Self {
+        Notification::ContactChanged(types::ContactChanged { user, kind })
     }
 
     // pub fn customer_identification_failed(user: User, reason: String) -> Self {
@@ -89,25 +259,140 @@ impl Notification {
         Notification::BankAccountCreationFailed(types::BankAccountCreationFailed { user })
     }
 
-    pub fn order_status_updated(order: Order, user: User) -> Self {
-        Notification::OrderStatusUpdated(types::OrderStatusUpdated { order, user })
+    pub fn order_status_updated(order: Order, kitchen: Kitchen, user: User) -> Self {
+        Notification::OrderStatusUpdated(types::OrderStatusUpdated {
+            order,
+            kitchen,
+            user,
+        })
+    }
+
+    pub fn order_receipt(order: FullOrder, user: User) -> Self {
+        Notification::OrderReceipt(types::OrderReceipt { order, user })
+    }
+
+    pub fn kitchen_order_summary(order: FullOrder, user: User) -> Self {
+        Notification::KitchenOrderSummary(types::KitchenOrderSummary { order, user })
+    }
+
+    pub fn kitchen_invitation_received(
+        user: User,
+        kitchen: Kitchen,
+        role: KitchenMemberRole,
+    ) -> Self {
+        Notification::KitchenInvitationReceived(types::KitchenInvitationReceived {
+            user,
+            kitchen,
+            role,
+        })
     }
 }
 
 #[derive(Debug)]
 pub enum Error {
     NotSent,
+    NotQueued,
     InvalidNotification,
+    // the recipient can't be reached over the backend at all, e.g an invalid email address
+    InvalidRecipient,
+    // the user has no device to push to, see `fall_back_to_sms`
+    NoDevice,
+    // e.g the daily sms spending cap
+    LimitReached,
+    // the user doesn't want to hear about it until then, see `preference::get_quiet_hours_end`
+    Held(NaiveDateTime),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 // TODO: create a general `NotificationResponse` type and make all notification backends return that
 
-pub async fn send(ctx: Arc<Context>, notification: Notification, backend: Backend) -> Result<()> {
+// Queues the notification in the outbox for every backend and keeps a copy in the user's inbox, pass the transaction
+// making the change so that they're either all kept or all dropped together. Delivery happens in the background, see
+// `outbox::deliver_due`
+pub async fn enqueue<'a, A: Acquire<'a, Database = Postgres>>(
+    a: A,
+    notification: Notification,
+    category: Category,
+    backends: Vec<Backend>,
+) -> Result<()> {
+    let mut conn = a.acquire().await.map_err(|err| {
+        tracing::error!("Failed to acquire a database connection: {}", err);
+        Error::NotQueued
+    })?;
+
+    inbox::store(&mut *conn, &notification, category.clone()).await?;
+
+    let user_id = template::get_recipient(&notification).id.clone();
+    for backend in backends {
+        repository::outbox::create(
+            &mut *conn,
+            user_id.clone(),
+            notification.clone(),
+            category.clone(),
+            backend,
+        )
+        .await
+        .map_err(|_| Error::NotQueued)?;
+    }
+
+    Ok(())
+}
+
+// Customers without the app still hear about their order over sms, anything else that has no device to go to is
+// dropped like before
+async fn fall_back_to_sms(
+    ctx: Arc<Context>,
+    notification: Notification,
+    category: Category,
+) -> Result<()> {
+    let user_id = match &notification {
+        Notification::OrderStatusUpdated(n) if n.user.id == n.order.owner_id => n.user.id.clone(),
+        _ => return Ok(()),
+    };
+
+    if !preference::is_enabled(ctx.clone(), user_id, &category, &Backend::Sms).await? {
+        return Ok(());
+    }
+
+    tracing::debug!("No device to push to, sending the order update over sms instead");
+
+    sms::send(ctx, notification).await
+}
+
+// Sends the notification right away, only for things the caller has to wait on (e.g OTPs). Queued notifications end
+// up here too once they're due. Transactional notifications ignore the user's preferences
+pub async fn send(
+    ctx: Arc<Context>,
+    notification: Notification,
+    category: Category,
+    backend: Backend,
+) -> Result<()> {
+    if category != Category::Transactional {
+        let user_id = template::get_recipient(&notification).id.clone();
+
+        if !preference::is_enabled(ctx.clone(), user_id.clone(), &category, &backend).await? {
+            tracing::debug!(
+                "Skipping {} notification, the user turned off {} notifications",
+                backend.to_string(),
+                category.to_string()
+            );
+            return Ok(());
+        }
+
+        if backend == Backend::Push && !category.is_urgent() {
+            if let Some(until) = preference::get_quiet_hours_end(ctx.clone(), user_id).await? {
+                return Err(Error::Held(until));
+            }
+        }
+    }
+
     match backend {
         Backend::Email => email::send(ctx, notification).await,
-        Backend::Push => push::send(ctx, notification).await,
-        Backend::Sms => sms::send(ctx, notification).await.map(|_| ()),
+        Backend::Push => match push::send(ctx.clone(), notification.clone()).await {
+            Err(Error::NoDevice) => fall_back_to_sms(ctx, notification, category).await,
+            result => result,
+        },
+        Backend::Sms => sms::send(ctx, notification).await,
     }
 }
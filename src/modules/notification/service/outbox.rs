@@ -0,0 +1,119 @@
+use super::{send, Error, Notification};
+use crate::{
+    modules::notification::repository::outbox::{self, OutboxEntry},
+    types::Context,
+};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+const BATCH_SIZE: i64 = 50;
+// How long (in seconds) a claimed notification is kept away from other workers
+const LEASE: i64 = 300;
+const MAX_ATTEMPTS: i32 = 8;
+// The delay (in seconds) before the first retry, it doubles on every attempt after that
+const BASE_RETRY_DELAY: i64 = 30;
+const MAX_RETRY_DELAY: i64 = 6 * 60 * 60;
+// How long (in days) a sent or failed notification is kept around, it still holds the recipient's details
+const RETENTION: i64 = 30;
+
+fn get_retry_delay(attempts: i32) -> Duration {
+    let delay = BASE_RETRY_DELAY.saturating_mul(1 << (attempts - 1).clamp(0, 20));
+    Duration::seconds(delay.min(MAX_RETRY_DELAY))
+}
+
+async fn deliver(ctx: Arc<Context>, entry: OutboxEntry) {
+    // NOTE: a notification stored before its payload changed shape can never be sent, retrying it won't change that
+    let notification = match serde_json::from_value::<Notification>(entry.notification.clone()) {
+        Ok(notification) => notification,
+        Err(err) => {
+            tracing::error!(
+                "Failed to decode notification {} from the outbox: {}",
+                entry.id,
+                err
+            );
+            outbox::mark_as_failed_by_id(&ctx.db_conn.pool, entry.id)
+                .await
+                .ok();
+            return;
+        }
+    };
+
+    let result = send(
+        ctx.clone(),
+        notification,
+        entry.category.clone(),
+        entry.backend.clone(),
+    )
+    .await;
+
+    if let Err(Error::Held(until)) = result {
+        outbox::hold_by_id(&ctx.db_conn.pool, entry.id, until)
+            .await
+            .ok();
+        return;
+    }
+
+    outbox::create_attempt(
+        &ctx.db_conn.pool,
+        outbox::CreateAttemptPayload {
+            outbox_id: entry.id.clone(),
+            backend: entry.backend.clone(),
+            error: result.as_ref().err().map(|err| format!("{:?}", err)),
+        },
+    )
+    .await
+    .ok();
+
+    let _ = match result {
+        Ok(_) => outbox::mark_as_sent_by_id(&ctx.db_conn.pool, entry.id).await,
+        // NOTE: the backend doesn't support this notification or recipient at all, retrying won't change that. An sms
+        // held back by the daily cap would only arrive once it's no longer relevant
+        Err(Error::InvalidNotification | Error::InvalidRecipient | Error::LimitReached) => {
+            outbox::mark_as_failed_by_id(&ctx.db_conn.pool, entry.id).await
+        }
+        Err(_) if entry.attempts >= MAX_ATTEMPTS => {
+            tracing::error!(
+                "Giving up on notification {} after {} attempts",
+                entry.id,
+                entry.attempts
+            );
+            outbox::mark_as_failed_by_id(&ctx.db_conn.pool, entry.id).await
+        }
+        Err(_) => {
+            outbox::reschedule_by_id(
+                &ctx.db_conn.pool,
+                entry.id,
+                Utc::now().naive_utc() + get_retry_delay(entry.attempts),
+            )
+            .await
+        }
+    };
+}
+
+// Delivers every notification that is due, claiming them in batches so that several workers can run side by side
+pub async fn deliver_due(ctx: Arc<Context>) {
+    loop {
+        let entries = match outbox::claim_many_due(&ctx.db_conn.pool, BATCH_SIZE, LEASE).await {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+
+        let is_last_batch = (entries.len() as i64) < BATCH_SIZE;
+
+        for entry in entries {
+            deliver(ctx.clone(), entry).await;
+        }
+
+        if is_last_batch {
+            return;
+        }
+    }
+}
+
+pub async fn prune(ctx: Arc<Context>) {
+    let before = Utc::now().naive_utc() - Duration::days(RETENTION);
+
+    if let Ok(count) = outbox::delete_many_finished_before(&ctx.db_conn.pool, before).await {
+        tracing::debug!("Pruned {} notifications from the outbox", count);
+    }
+}
@@ -0,0 +1,188 @@
+use super::{Backend, Category, Error, Result};
+use crate::{
+    modules::notification::repository::{preference, quiet_hours, setting},
+    types::Context,
+};
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::sync::Arc;
+
+// A category has to be on as a whole (see `setting`) and for the backend, anything the user never touched is on
+pub async fn is_enabled(
+    ctx: Arc<Context>,
+    user_id: String,
+    category: &Category,
+    backend: &Backend,
+) -> Result<bool> {
+    let is_category_enabled =
+        setting::find_by_user_id_and_category(&ctx.db_conn.pool, user_id.clone(), category.clone())
+            .await
+            .map_err(|_| Error::NotSent)?
+            .map(|setting| setting.is_enabled)
+            .unwrap_or(true);
+
+    if !is_category_enabled {
+        return Ok(false);
+    }
+
+    preference::find_by_user_id_and_category_and_backend(
+        &ctx.db_conn.pool,
+        user_id,
+        category.clone(),
+        backend.clone(),
+    )
+    .await
+    .map(|preference| {
+        preference
+            .map(|preference| preference.is_enabled)
+            .unwrap_or(true)
+    })
+    .map_err(|_| Error::NotSent)
+}
+
+// When the quiet hours the user is in right now end (in UTC), `None` when they aren't in them
+pub fn get_end(quiet_hours: &quiet_hours::QuietHours, now: DateTime<Utc>) -> Option<NaiveDateTime> {
+    let timezone = quiet_hours.timezone.parse::<Tz>().ok()?;
+    let now = now.with_timezone(&timezone);
+    let time = now.time();
+
+    let is_quiet = match quiet_hours.starts_at <= quiet_hours.ends_at {
+        true => time >= quiet_hours.starts_at && time < quiet_hours.ends_at,
+        // e.g 22:00 - 07:00
+        false => time >= quiet_hours.starts_at || time < quiet_hours.ends_at,
+    };
+
+    if !is_quiet {
+        return None;
+    }
+
+    let date = match time >= quiet_hours.ends_at {
+        true => now.date_naive().succ_opt()?,
+        false => now.date_naive(),
+    };
+
+    timezone
+        .from_local_datetime(&date.and_time(quiet_hours.ends_at))
+        .earliest()
+        .map(|ends_at| ends_at.naive_utc())
+}
+
+pub async fn get_quiet_hours_end(
+    ctx: Arc<Context>,
+    user_id: String,
+) -> Result<Option<NaiveDateTime>> {
+    let quiet_hours = quiet_hours::find_by_user_id(&ctx.db_conn.pool, user_id)
+        .await
+        .map_err(|_| Error::NotSent)?;
+
+    Ok(quiet_hours.and_then(|quiet_hours| get_end(&quiet_hours, Utc::now())))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::NaiveTime;
+
+    fn get_quiet_hours(starts_at: &str, ends_at: &str, timezone: &str) -> quiet_hours::QuietHours {
+        quiet_hours::QuietHours {
+            id: String::from("quiet-hours"),
+            user_id: String::from("user"),
+            starts_at: NaiveTime::parse_from_str(starts_at, "%H:%M").unwrap(),
+            ends_at: NaiveTime::parse_from_str(ends_at, "%H:%M").unwrap(),
+            timezone: String::from(timezone),
+            created_at: Utc::now().naive_utc(),
+            updated_at: None,
+        }
+    }
+
+    fn get_time(time: &str) -> DateTime<Utc> {
+        time.parse::<DateTime<Utc>>().unwrap()
+    }
+
+    fn get_naive_time(time: &str) -> NaiveDateTime {
+        get_time(time).naive_utc()
+    }
+
+    #[test]
+    fn should_end_on_the_same_day() {
+        let quiet_hours = get_quiet_hours("13:00", "15:00", "UTC");
+
+        assert_eq!(
+            get_end(&quiet_hours, get_time("2024-06-10T14:00:00Z")),
+            Some(get_naive_time("2024-06-10T15:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn should_end_the_next_day_when_wrapping_past_midnight() {
+        let quiet_hours = get_quiet_hours("22:00", "07:00", "UTC");
+
+        assert_eq!(
+            get_end(&quiet_hours, get_time("2024-06-10T23:30:00Z")),
+            Some(get_naive_time("2024-06-11T07:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn should_end_the_same_day_when_already_past_midnight() {
+        let quiet_hours = get_quiet_hours("22:00", "07:00", "UTC");
+
+        assert_eq!(
+            get_end(&quiet_hours, get_time("2024-06-11T03:00:00Z")),
+            Some(get_naive_time("2024-06-11T07:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn should_not_end_outside_of_quiet_hours() {
+        let same_day = get_quiet_hours("13:00", "15:00", "UTC");
+        let wrapping = get_quiet_hours("22:00", "07:00", "UTC");
+
+        assert_eq!(get_end(&same_day, get_time("2024-06-10T12:59:00Z")), None);
+        assert_eq!(get_end(&same_day, get_time("2024-06-10T15:00:00Z")), None);
+        assert_eq!(get_end(&wrapping, get_time("2024-06-10T07:00:00Z")), None);
+        assert_eq!(get_end(&wrapping, get_time("2024-06-10T21:59:00Z")), None);
+    }
+
+    #[test]
+    fn should_use_the_timezone_of_the_quiet_hours() {
+        let quiet_hours = get_quiet_hours("22:00", "07:00", "Africa/Lagos");
+
+        // 22:30 in Lagos, an hour ahead of UTC
+        assert_eq!(
+            get_end(&quiet_hours, get_time("2024-06-10T21:30:00Z")),
+            Some(get_naive_time("2024-06-11T06:00:00Z"))
+        );
+        // 21:30 in Lagos
+        assert_eq!(
+            get_end(&quiet_hours, get_time("2024-06-10T20:30:00Z")),
+            None
+        );
+    }
+
+    #[test]
+    fn should_end_at_the_local_time_across_a_dst_change() {
+        let quiet_hours = get_quiet_hours("22:00", "07:00", "Europe/London");
+
+        // the clocks go forward at 01:00 UTC on the 31st, 07:00 is BST by the time quiet hours end
+        assert_eq!(
+            get_end(&quiet_hours, get_time("2024-03-30T22:30:00Z")),
+            Some(get_naive_time("2024-03-31T06:00:00Z"))
+        );
+        // and back at 01:00 UTC on the 27th of october, 22:00 is still BST when they start
+        assert_eq!(
+            get_end(&quiet_hours, get_time("2024-10-26T21:30:00Z")),
+            Some(get_naive_time("2024-10-27T07:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn should_not_end_with_an_invalid_timezone() {
+        let quiet_hours = get_quiet_hours("22:00", "07:00", "Nowhere/Special");
+
+        assert_eq!(
+            get_end(&quiet_hours, get_time("2024-06-10T23:30:00Z")),
+            None
+        );
+    }
+}
@@ -1,97 +1,86 @@
-use super::{types, Error, Notification, Result};
-use crate::{
-    modules::{notification::repository::push_token, user},
-    types::Context,
-};
+use super::{template, Backend, Error, Notification, Result};
+use crate::{modules::notification::repository::push_token, types::Context};
 use oauth_fcm::{send_fcm_message, FcmNotification};
+use std::collections::HashMap;
 // use std::fs::File;
 use std::sync::Arc;
 
 pub async fn send(ctx: Arc<Context>, notification: Notification) -> Result<()> {
-    match notification {
-        Notification::Registered(n) => send_registered_push_notification(ctx, n).await,
-        Notification::OrderStatusUpdated(n) => {
-            send_order_status_updated_push_notification(ctx, n).await
-        }
-        _ => Ok(()),
-    }
-}
+    let rendered = template::render(&notification, &Backend::Push)?;
+    let user_id = template::get_recipient(&notification).id.clone();
 
-async fn send_registered_push_notification(
-    ctx: Arc<Context>,
-    payload: types::Registered,
-) -> Result<()> {
-    let tokens = push_token::find_many_by_user_id(&ctx.db_conn.pool, payload.user.id)
-        .await
-        .map_err(|_| Error::NotSent)?;
+    tracing::debug!(
+        "About to send push notification to user with id: {}",
+        &user_id
+    );
 
-    for token in tokens {
-        send_fcm_message::<String>(
-            &token.token,
-            Some(FcmNotification {
-                title: "Registration successful".to_string(),
-                body: "Welcome to FoodHut".to_string(),
-            }),
-            None,
-            &ctx.google.fcm_token_manager,
-            &ctx.google.fcm_project_id,
-        )
-        .await
-        .map_err(|_| Error::NotSent)?;
+    match send_to_user(ctx, user_id, rendered.title, rendered.body, None).await? {
+        0 => Err(Error::NoDevice),
+        _ => Ok(()),
     }
-
-    Ok(())
 }
 
-async fn send_order_status_updated_push_notification(
+// Sends to every device of the user and returns how many were reached. One bad token doesn't stop the rest, and the
+// push only counts as failed when no device could be reached
+pub async fn send_to_user(
     ctx: Arc<Context>,
-    payload: types::OrderStatusUpdated,
-) -> Result<()> {
-    tracing::debug!(
-        "About to send push notification to user with id: {}",
-        &payload.user.id
-    );
-
-    let tokens = push_token::find_many_by_user_id(&ctx.db_conn.pool, payload.user.id.clone())
+    user_id: String,
+    title: String,
+    body: String,
+    data: Option<HashMap<String, String>>,
+) -> Result<usize> {
+    let tokens = push_token::find_many_by_user_id(&ctx.db_conn.pool, user_id.clone())
         .await
         .map_err(|_| Error::NotSent)?;
 
-    tracing::debug!(
-        "Got {} tokens for user with id {}",
-        tokens.len(),
-        &payload.user.id
-    );
+    tracing::debug!("Got {} tokens for user with id {}", tokens.len(), &user_id);
+
+    let mut delivered = 0;
+    let mut has_failed = false;
 
     for token in tokens {
-        send_fcm_message::<String>(
+        match send_fcm_message(
             &token.token,
             Some(FcmNotification {
-                title: "Order status updated".to_string(),
-                body: format!("Order {} status has been updated", payload.order.id),
+                title: title.clone(),
+                body: body.clone(),
             }),
-            None,
+            data.clone(),
             &ctx.google.fcm_token_manager,
             &ctx.google.fcm_project_id,
         )
         .await
-        .map(|_| {
-            tracing::info!(
-                "Successfully sent push notification using token with id: {}",
-                &token.id
-            );
-        })
-        .map_err(|err| {
-            tracing::error!(
-                "Failed to send push notification using token with id {}: {:?}",
-                &token.id,
-                err
-            );
-            Error::NotSent
-        })
-        .ok();
+        {
+            Ok(_) => {
+                tracing::info!(
+                    "Successfully sent push notification using token with id: {}",
+                    &token.id
+                );
+                delivered += 1;
+            }
+            // NOTE: FCM reports UNREGISTERED once the app is uninstalled or the token has expired, it'll never work
+            // again
+            Err(err) if format!("{:?}", err).contains("UNREGISTERED") => {
+                tracing::info!("Removing unregistered push token with id {}", &token.id);
+                push_token::delete_by_id(&ctx.db_conn.pool, token.id)
+                    .await
+                    .ok();
+            }
+            Err(err) => {
+                tracing::error!(
+                    "Failed to send push notification using token with id {}: {:?}",
+                    &token.id,
+                    err
+                );
+                has_failed = true;
+            }
+        }
     }
 
-    Ok(())
+    match has_failed && delivered == 0 {
+        true => Err(Error::NotSent),
+        false => Ok(delivered),
+    }
 }
 
 // #[cfg(test)]
@@ -0,0 +1,130 @@
+use super::{Error, Result, SentSms, SmsProvider};
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use serde::Deserialize;
+use std::str::FromStr;
+
+pub const NAME: &str = "AFRICAS_TALKING";
+
+#[derive(Deserialize)]
+struct Recipient {
+    #[serde(rename = "statusCode")]
+    status_code: i32,
+    status: String,
+    // e.g `NGN 2.2000`
+    cost: String,
+    #[serde(rename = "messageId")]
+    message_id: String,
+}
+
+#[derive(Deserialize)]
+struct SmsMessageData {
+    #[serde(rename = "Recipients")]
+    recipients: Vec<Recipient>,
+}
+
+#[derive(Deserialize)]
+struct SendEndpointPayload {
+    #[serde(rename = "SMSMessageData")]
+    sms_message_data: SmsMessageData,
+}
+
+// 100 (processed), 101 (sent) and 102 (queued) are all on their way
+fn is_successful(status_code: i32) -> bool {
+    (100..=102).contains(&status_code)
+}
+
+fn parse_cost(raw_cost: &str) -> Option<BigDecimal> {
+    let (_currency, amount) = raw_cost.trim().split_once(' ')?;
+    BigDecimal::from_str(amount).ok()
+}
+
+pub struct AfricasTalkingSmsProvider {
+    client: reqwest::Client,
+    username: String,
+    api_key: String,
+    sender_id: Option<String>,
+    endpoint: String,
+}
+
+impl AfricasTalkingSmsProvider {
+    pub fn new(
+        username: String,
+        api_key: String,
+        sender_id: Option<String>,
+        endpoint: String,
+    ) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            username,
+            api_key,
+            sender_id,
+            endpoint,
+        }
+    }
+}
+
+#[async_trait]
+impl SmsProvider for AfricasTalkingSmsProvider {
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    async fn send(&self, to: &str, message: &str) -> Result<SentSms> {
+        let mut form = vec![
+            ("username", self.username.as_str()),
+            ("to", to),
+            ("message", message),
+        ];
+        if let Some(sender_id) = &self.sender_id {
+            form.push(("from", sender_id.as_str()));
+        }
+
+        let res = self
+            .client
+            .post(self.endpoint.clone())
+            .header("apiKey", self.api_key.clone())
+            .header("Accept", "application/json")
+            .form(&form)
+            .send()
+            .await
+            .map_err(|err| {
+                tracing::error!("Failed to send sms: {}", err);
+                Error::NotSent
+            })?;
+
+        if !res.status().is_success() {
+            match res.text().await {
+                Ok(data) => tracing::error!("Failed to send sms: {}", data),
+                Err(err) => tracing::error!("Failed to get response body: {}", err),
+            }
+            return Err(Error::NotSent);
+        }
+
+        let payload = res.json::<SendEndpointPayload>().await.map_err(|err| {
+            tracing::error!("Failed to parse the sms response: {}", err);
+            Error::NotSent
+        })?;
+
+        // NOTE: there's only ever one recipient
+        let recipient = payload
+            .sms_message_data
+            .recipients
+            .into_iter()
+            .next()
+            .ok_or(Error::NotSent)?;
+
+        if !is_successful(recipient.status_code) {
+            tracing::error!("Failed to send sms: {}", recipient.status);
+            return match recipient.status.as_ref() {
+                "InvalidPhoneNumber" | "UserInBlacklist" => Err(Error::InvalidRecipient),
+                _ => Err(Error::NotSent),
+            };
+        }
+
+        Ok(SentSms {
+            reference: Some(recipient.message_id),
+            cost: parse_cost(&recipient.cost),
+        })
+    }
+}
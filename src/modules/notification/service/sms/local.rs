@@ -0,0 +1,52 @@
+use super::{Error, Result, SentSms, SmsProvider};
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use std::path::PathBuf;
+use ulid::Ulid;
+
+pub const NAME: &str = "LOCAL";
+
+// Captures every sms in `directory` as a .txt file instead of sending it, for development and tests. Captured messages
+// are free so they never count towards the daily cap
+pub struct LocalSmsProvider {
+    directory: PathBuf,
+}
+
+impl LocalSmsProvider {
+    pub fn new(directory: String) -> Self {
+        let directory = PathBuf::from(directory);
+
+        std::fs::create_dir_all(&directory).unwrap_or_else(|err| {
+            tracing::error!("{}", err);
+            panic!("Failed to create sms directory {}", directory.display())
+        });
+
+        Self { directory }
+    }
+}
+
+#[async_trait]
+impl SmsProvider for LocalSmsProvider {
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    async fn send(&self, to: &str, message: &str) -> Result<SentSms> {
+        let id = Ulid::new().to_string();
+        let path = self.directory.join(format!("{}.txt", id));
+
+        tokio::fs::write(&path, format!("To: {}\n\n{}\n", to, message))
+            .await
+            .map_err(|err| {
+                tracing::error!("Failed to write sms to {}: {}", path.display(), err);
+                Error::NotSent
+            })?;
+
+        tracing::info!("Sms to {} written to {}", to, path.display());
+
+        Ok(SentSms {
+            reference: Some(id),
+            cost: Some(BigDecimal::from(0)),
+        })
+    }
+}
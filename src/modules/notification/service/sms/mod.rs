@@ -0,0 +1,110 @@
+mod africas_talking;
+mod local;
+mod termii;
+
+use super::{template, Backend, Error, Notification, Result};
+use crate::{modules::notification::repository, types::Context};
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use std::sync::Arc;
+
+pub use africas_talking::AfricasTalkingSmsProvider;
+pub use local::LocalSmsProvider;
+pub use termii::TermiiSmsProvider;
+
+// `cost` is in naira, providers that don't report it leave it out and it's estimated from `SMS_COST_PER_SEGMENT`
+pub struct SentSms {
+    pub reference: Option<String>,
+    pub cost: Option<BigDecimal>,
+}
+
+// Whoever actually delivers the sms, picked with `SMS_PROVIDER` and built once on startup
+#[async_trait]
+pub trait SmsProvider: Send + Sync {
+    // NOTE: stored alongside every sms so that costs can be reconciled with the provider's invoices
+    fn name(&self) -> &'static str;
+
+    async fn send(&self, to: &str, message: &str) -> Result<SentSms>;
+}
+
+pub async fn send(ctx: Arc<Context>, notification: Notification) -> Result<()> {
+    let rendered = template::render(&notification, &Backend::Sms)?;
+    let user = template::get_recipient(&notification);
+
+    send_sms(
+        ctx,
+        Some(user.id.clone()),
+        user.phone_number.clone(),
+        rendered.body,
+    )
+    .await
+}
+
+// Messages with anything outside of plain ascii (e.g yoruba tone marks) are sent as unicode, which fits a lot less in
+// a segment
+fn get_segments(message: &str) -> i32 {
+    let length = message.chars().count();
+    let (single, multipart) = match message.is_ascii() {
+        true => (160, 153),
+        false => (70, 67),
+    };
+
+    match length <= single {
+        true => 1,
+        false => length.div_ceil(multipart) as i32,
+    }
+}
+
+// NOTE: the cap is checked before sending, so the last message of the day can go a little over it. OTPs hit it too
+// (that's what sms pumping goes after), they fall back to another channel when it's reached
+async fn send_sms(
+    ctx: Arc<Context>,
+    user_id: Option<String>,
+    to: String,
+    message: String,
+) -> Result<()> {
+    let start_of_day = Utc::now()
+        .date_naive()
+        .and_hms_opt(0, 0, 0)
+        .expect("Invalid start of day");
+
+    let spent = repository::sms::sum_cost_since(&ctx.db_conn.pool, start_of_day)
+        .await
+        .map_err(|_| Error::NotSent)?;
+
+    if spent >= ctx.sms.daily_cap {
+        tracing::warn!(
+            "Not sending sms, {} has already been spent today against a cap of {}",
+            spent,
+            ctx.sms.daily_cap
+        );
+        return Err(Error::LimitReached);
+    }
+
+    let sent = ctx.sms.provider.send(&to, &message).await?;
+
+    let segments = get_segments(&message);
+    let cost = sent
+        .cost
+        .unwrap_or_else(|| &ctx.sms.cost_per_segment * BigDecimal::from(segments));
+
+    // NOTE: the sms is already out, failing to record it shouldn't get it sent again
+    repository::sms::create(
+        &ctx.db_conn.pool,
+        repository::sms::CreateSmsMessagePayload {
+            user_id,
+            phone_number: to,
+            provider: ctx.sms.provider.name().to_string(),
+            reference: sent.reference,
+            segments,
+            cost,
+        },
+    )
+    .await
+    .ok();
+
+    tracing::debug!("Successfully sent sms");
+
+    Ok(())
+}
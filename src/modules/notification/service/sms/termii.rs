@@ -0,0 +1,92 @@
+use super::{Error, Result, SentSms, SmsProvider};
+use async_trait::async_trait;
+use axum::http::HeaderMap;
+use hyper::StatusCode;
+use serde::Deserialize;
+use serde_json::json;
+
+pub const NAME: &str = "TERMII";
+
+#[derive(Deserialize)]
+struct SendEndpointPayload {
+    message_id: Option<String>,
+}
+
+// NOTE: shares the api key and sender id with the termii OTP provider
+pub struct TermiiSmsProvider {
+    client: reqwest::Client,
+    api_key: String,
+    sender_id: String,
+    endpoint: String,
+}
+
+impl TermiiSmsProvider {
+    pub fn new(api_key: String, sender_id: String, endpoint: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            api_key,
+            sender_id,
+            endpoint,
+        }
+    }
+}
+
+#[async_trait]
+impl SmsProvider for TermiiSmsProvider {
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    // termii doesn't say what a message cost, so it's left to the estimate
+    async fn send(&self, to: &str, message: &str) -> Result<SentSms> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "Content-Type",
+            "application/json"
+                .try_into()
+                .expect("Invalid content type header value"),
+        );
+
+        let res = self
+            .client
+            .post(self.endpoint.clone())
+            .headers(headers)
+            .body(
+                json!({
+                    "api_key": self.api_key.clone(),
+                    "to": to,
+                    "from": self.sender_id.clone(),
+                    "sms": message,
+                    "type": "plain",
+                    "channel": "dnd",
+                })
+                .to_string(),
+            )
+            .send()
+            .await
+            .map_err(|err| {
+                tracing::error!("Failed to send sms: {}", err);
+                Error::NotSent
+            })?;
+
+        if res.status() != StatusCode::OK {
+            match res.text().await {
+                Ok(data) => tracing::error!("Failed to send sms: {}", data),
+                Err(err) => tracing::error!("Failed to get response body: {}", err),
+            }
+            return Err(Error::NotSent);
+        }
+
+        // NOTE: the sms is out by now, a response we can't make sense of only costs us the reference
+        let reference = res
+            .json::<SendEndpointPayload>()
+            .await
+            .ok()
+            .and_then(|payload| payload.message_id);
+
+        Ok(SentSms {
+            reference,
+            cost: None,
+        })
+    }
+}
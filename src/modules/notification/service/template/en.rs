@@ -0,0 +1,91 @@
+use super::Template;
+use crate::modules::notification::service::Backend;
+
+pub fn find(name: &str, backend: &Backend) -> Option<Template> {
+    let (title, body) = match (name, backend) {
+        ("registered", Backend::Email) => (
+            "Welcome to FoodHut",
+            "Greetings {{first_name}}, welcome to FoodHut",
+        ),
+        ("registered", _) => ("Registration successful", "Welcome to FoodHut"),
+        ("verification_otp_requested", _) => (
+            "Your FoodHut verification code",
+            "Your FoodHut app verification pin is {{code}}\nPin will expire in {{validity}} minutes, do not share it with anyone",
+        ),
+        ("magic_link_requested", Backend::Email) => (
+            "Sign in to FoodHut",
+            "<a href=\"{{link}}\">Click here to sign in to FoodHut</a>\nThe link will expire in {{validity}} minutes and can only be used once, do not share it with anyone",
+        ),
+        ("email_change_requested", Backend::Email) => (
+            "Confirm your new FoodHut email",
+            "<a href=\"{{link}}\">Click here to confirm this as your new FoodHut email</a>\nThe link will expire in {{validity}} minutes, ignore this email if you didn't ask for the change",
+        ),
+        ("contact_changed.email", _) => (
+            "Your FoodHut email was changed",
+            "The email on your FoodHut account has just been changed. If this wasn't you, please contact support immediately",
+        ),
+        ("contact_changed.phone_number", _) => (
+            "Your FoodHut phone number was changed",
+            "The phone number on your FoodHut account has just been changed. If this wasn't you, please contact support immediately",
+        ),
+        ("bank_account_creation_successful", _) => (
+            "Virtual Account Created",
+            "Dear {{first_name}}, your virtual account has been created!",
+        ),
+        ("bank_account_creation_failed", _) => (
+            "Virtual Account Creation Failed",
+            "Dear {{first_name}}, your virtual account couldn't be created",
+        ),
+        ("order_status_updated.awaiting_acknowledgement", _) => (
+            "Order placed",
+            "Your order from {{kitchen_name}} has been placed, we'll let you know once they start preparing it",
+        ),
+        ("order_status_updated.preparing", _) => (
+            "Order is being prepared",
+            "{{kitchen_name}} is preparing your order",
+        ),
+        ("order_status_updated.in_transit", _) => (
+            "Order on its way",
+            "Your order from {{kitchen_name}} is on its way",
+        ),
+        ("order_status_updated.delivered", _) => (
+            "Order delivered",
+            "Your order from {{kitchen_name}} has been delivered, enjoy your meal!",
+        ),
+        ("order_status_updated.cancelled", _) => (
+            "Order cancelled",
+            "Your order from {{kitchen_name}} has been cancelled",
+        ),
+        ("kitchen_order_status_updated.awaiting_acknowledgement", _) => (
+            "New order",
+            "{{kitchen_name}} has a new order of {{order_total}}, accept it to start preparing",
+        ),
+        ("kitchen_order_status_updated.delivered", _) => (
+            "Order delivered",
+            "Order {{order_id}} has been delivered to the customer",
+        ),
+        ("kitchen_order_status_updated.cancelled", _) => (
+            "Order cancelled",
+            "Order {{order_id}} has been cancelled by the customer",
+        ),
+        ("order_receipt", Backend::Email) => (
+            "Your FoodHut receipt for order {{order_id}}",
+            "Hi {{first_name}}, thanks for ordering from {{kitchen_name}}!\n\n{{items}}\n\nSubtotal: {{sub_total}}\nDelivery fee: {{delivery_fee}}\nService fee: {{service_fee}}\nTotal: {{order_total}}\n\nDelivering to: {{delivery_address}}",
+        ),
+        ("kitchen_order_summary", Backend::Email) => (
+            "New order {{order_id}} for {{kitchen_name}}",
+            "{{kitchen_name}} has a new order to prepare\n\n{{items}}\n\nSubtotal: {{sub_total}}\nDelivering to: {{delivery_address}}\nNote for the rider: {{dispatch_rider_note}}\n\nAccept it in the FoodHut app to start cooking",
+        ),
+        ("kitchen_invitation_received", Backend::Sms) => (
+            "",
+            "You have been invited to join {{kitchen_name}} on FoodHut as {{role}}. Open the FoodHut app to accept the invitation",
+        ),
+        ("kitchen_invitation_received", _) => (
+            "Kitchen invitation",
+            "You have been invited to join {{kitchen_name}} as {{role}}",
+        ),
+        _ => return None,
+    };
+
+    Some(Template { title, body })
+}
@@ -0,0 +1,74 @@
+use super::Template;
+use crate::modules::notification::service::Backend;
+
+pub fn find(name: &str, backend: &Backend) -> Option<Template> {
+    let (title, body) = match (name, backend) {
+        ("registered", _) => (
+            "Barka da zuwa FoodHut",
+            "Sannu {{first_name}}, barka da zuwa FoodHut",
+        ),
+        ("verification_otp_requested", _) => (
+            "Lambar tabbatarwa ta FoodHut",
+            "Lambar tabbatarwa ta FoodHut ɗinka ita ce {{code}}\nZa ta ƙare cikin mintuna {{validity}}, kada ka nuna wa kowa",
+        ),
+        ("contact_changed.email", _) => (
+            "An canza imel ɗinka na FoodHut",
+            "An canza imel ɗin da ke kan asusunka na FoodHut yanzu. Idan ba kai ba ne, don Allah ka tuntuɓi sashen taimako nan take",
+        ),
+        ("contact_changed.phone_number", _) => (
+            "An canza lambar wayarka ta FoodHut",
+            "An canza lambar wayar da ke kan asusunka na FoodHut yanzu. Idan ba kai ba ne, don Allah ka tuntuɓi sashen taimako nan take",
+        ),
+        ("bank_account_creation_successful", _) => (
+            "An buɗe asusunka",
+            "{{first_name}}, an buɗe asusunka na banki!",
+        ),
+        ("bank_account_creation_failed", _) => (
+            "Ba a iya buɗe asusunka ba",
+            "{{first_name}}, ba mu iya buɗe asusunka na banki ba",
+        ),
+        ("order_status_updated.awaiting_acknowledgement", _) => (
+            "An karɓi odarka",
+            "An aika odarka zuwa {{kitchen_name}}, za mu sanar da kai idan sun fara dafa ta",
+        ),
+        ("order_status_updated.preparing", _) => (
+            "Ana shirya odarka",
+            "{{kitchen_name}} na shirya odarka",
+        ),
+        ("order_status_updated.in_transit", _) => (
+            "Odarka na kan hanya",
+            "Odarka daga {{kitchen_name}} na kan hanya",
+        ),
+        ("order_status_updated.delivered", _) => (
+            "Odarka ta iso",
+            "Odarka daga {{kitchen_name}} ta iso, a ci daɗi!",
+        ),
+        ("order_status_updated.cancelled", _) => (
+            "An soke odarka",
+            "An soke odarka daga {{kitchen_name}}",
+        ),
+        ("kitchen_order_status_updated.awaiting_acknowledgement", _) => (
+            "Sabuwar oda",
+            "{{kitchen_name}} na da sabuwar oda ta {{order_total}}, karɓe ta don fara dafawa",
+        ),
+        ("kitchen_order_status_updated.delivered", _) => (
+            "An isar da oda",
+            "An isar da oda {{order_id}} ga abokin ciniki",
+        ),
+        ("kitchen_order_status_updated.cancelled", _) => (
+            "An soke oda",
+            "Abokin ciniki ya soke oda {{order_id}}",
+        ),
+        ("kitchen_invitation_received", Backend::Sms) => (
+            "",
+            "An gayyace ka ka shiga {{kitchen_name}} a FoodHut a matsayin {{role}}. Buɗe manhajar FoodHut don karɓa",
+        ),
+        ("kitchen_invitation_received", _) => (
+            "Gayyatar kicin",
+            "An gayyace ka ka shiga {{kitchen_name}} a matsayin {{role}}",
+        ),
+        _ => return None,
+    };
+
+    Some(Template { title, body })
+}
@@ -0,0 +1,74 @@
+use super::Template;
+use crate::modules::notification::service::Backend;
+
+pub fn find(name: &str, backend: &Backend) -> Option<Template> {
+    let (title, body) = match (name, backend) {
+        ("registered", _) => (
+            "Nnọọ na FoodHut",
+            "Ndeewo {{first_name}}, nnọọ na FoodHut",
+        ),
+        ("verification_otp_requested", _) => (
+            "Koodu nkwenye FoodHut gị",
+            "Koodu nkwenye FoodHut gị bụ {{code}}\nỌ ga-agwụ n'ime nkeji {{validity}}, egosila ya onye ọ bụla",
+        ),
+        ("contact_changed.email", _) => (
+            "Agbanweela email FoodHut gị",
+            "Agbanweela email dị na akaụntụ FoodHut gị ugbu a. Ọ bụrụ na ọ bụghị gị, biko kpọtụrụ ndị nkwado ozugbo",
+        ),
+        ("contact_changed.phone_number", _) => (
+            "Agbanweela nọmba ekwentị FoodHut gị",
+            "Agbanweela nọmba ekwentị dị na akaụntụ FoodHut gị ugbu a. Ọ bụrụ na ọ bụghị gị, biko kpọtụrụ ndị nkwado ozugbo",
+        ),
+        ("bank_account_creation_successful", _) => (
+            "Emepụtala akaụntụ gị",
+            "{{first_name}}, emepụtala akaụntụ gị!",
+        ),
+        ("bank_account_creation_failed", _) => (
+            "Emepụtaghị akaụntụ gị",
+            "{{first_name}}, anyị enweghị ike imepụta akaụntụ gị",
+        ),
+        ("order_status_updated.awaiting_acknowledgement", _) => (
+            "Etinyela iwu gị",
+            "E zigara {{kitchen_name}} iwu gị, anyị ga-agwa gị mgbe ha malitere isi ya",
+        ),
+        ("order_status_updated.preparing", _) => (
+            "A na-esi nri gị",
+            "{{kitchen_name}} na-akwado nri gị",
+        ),
+        ("order_status_updated.in_transit", _) => (
+            "Nri gị na-abịa",
+            "Nri gị si na {{kitchen_name}} nọ n'ụzọ",
+        ),
+        ("order_status_updated.delivered", _) => (
+            "Nri gị abịala",
+            "Nri gị si na {{kitchen_name}} abịala, rie nke ọma!",
+        ),
+        ("order_status_updated.cancelled", _) => (
+            "Akagburu iwu gị",
+            "Akagburu iwu gị si na {{kitchen_name}}",
+        ),
+        ("kitchen_order_status_updated.awaiting_acknowledgement", _) => (
+            "Iwu ọhụrụ",
+            "{{kitchen_name}} nwere iwu ọhụrụ nke {{order_total}}, nabata ya ka ị malite isi ya",
+        ),
+        ("kitchen_order_status_updated.delivered", _) => (
+            "E nyefeela iwu",
+            "E nyefeela onye ahịa iwu {{order_id}}",
+        ),
+        ("kitchen_order_status_updated.cancelled", _) => (
+            "Akagburu iwu",
+            "Onye ahịa kagburu iwu {{order_id}}",
+        ),
+        ("kitchen_invitation_received", Backend::Sms) => (
+            "",
+            "A kpọrọ gị òkù isonye na {{kitchen_name}} na FoodHut dị ka {{role}}. Mepee ngwa FoodHut ka ị nabata ya",
+        ),
+        ("kitchen_invitation_received", _) => (
+            "Òkù kichin",
+            "A kpọrọ gị òkù isonye na {{kitchen_name}} dị ka {{role}}",
+        ),
+        _ => return None,
+    };
+
+    Some(Template { title, body })
+}
@@ -0,0 +1,254 @@
+mod en;
+mod ha;
+mod ig;
+mod pcm;
+mod yo;
+
+use super::{Backend, Error, Notification, Result};
+use crate::modules::{
+    order::repository::FullOrder,
+    user::repository::{Locale, User},
+};
+use bigdecimal::BigDecimal;
+use serde::Serialize;
+use std::collections::HashMap;
+
+// A message for a single channel in a single language. Placeholders look like `{{first_name}}` and are filled in
+// from the notification payload, sms doesn't have a title so it's ignored there
+pub struct Template {
+    pub title: &'static str,
+    pub body: &'static str,
+}
+
+#[derive(Serialize)]
+pub struct Rendered {
+    pub title: String,
+    pub body: String,
+}
+
+pub const NAMES: [&str; 19] = [
+    "registered",
+    "verification_otp_requested",
+    "magic_link_requested",
+    "email_change_requested",
+    "contact_changed.email",
+    "contact_changed.phone_number",
+    "bank_account_creation_successful",
+    "bank_account_creation_failed",
+    "order_status_updated.awaiting_acknowledgement",
+    "order_status_updated.preparing",
+    "order_status_updated.in_transit",
+    "order_status_updated.delivered",
+    "order_status_updated.cancelled",
+    "kitchen_order_status_updated.awaiting_acknowledgement",
+    "kitchen_order_status_updated.delivered",
+    "kitchen_order_status_updated.cancelled",
+    "order_receipt",
+    "kitchen_order_summary",
+    "kitchen_invitation_received",
+];
+
+// NOTE: english is the reference, anything a locale doesn't have falls back to it. That's on purpose for the sign in
+// and email change links, receipts and kitchen order summaries in yoruba, igbo and hausa, they carry links, amounts and
+// item names more than prose and a mistranslation there costs more than reading them in english
+pub fn find(name: &str, backend: &Backend, locale: &Locale) -> Option<Template> {
+    let template = match locale {
+        Locale::English => None,
+        Locale::Pidgin => pcm::find(name, backend),
+        Locale::Yoruba => yo::find(name, backend),
+        Locale::Igbo => ig::find(name, backend),
+        Locale::Hausa => ha::find(name, backend),
+    };
+
+    template.or_else(|| en::find(name, backend))
+}
+
+pub fn get_name(notification: &Notification) -> String {
+    match notification {
+        Notification::Registered(_) => String::from("registered"),
+        Notification::VerificationOtpRequested(_) => String::from("verification_otp_requested"),
+        Notification::MagicLinkRequested(_) => String::from("magic_link_requested"),
+        Notification::EmailChangeRequested(_) => String::from("email_change_requested"),
+        Notification::ContactChanged(n) => {
+            format!("contact_changed.{}", n.kind.to_string().to_lowercase())
+        }
+        Notification::BankAccountCreationSuccessful(_) => {
+            String::from("bank_account_creation_successful")
+        }
+        Notification::BankAccountCreationFailed(_) => String::from("bank_account_creation_failed"),
+        // the same update goes to both sides of the order, the customer and the kitchen don't want to read the same
+        // thing
+        Notification::OrderStatusUpdated(n) => match n.user.id == n.order.owner_id {
+            true => format!(
+                "order_status_updated.{}",
+                n.order.status.to_string().to_lowercase()
+            ),
+            false => format!(
+                "kitchen_order_status_updated.{}",
+                n.order.status.to_string().to_lowercase()
+            ),
+        },
+        Notification::OrderReceipt(_) => String::from("order_receipt"),
+        Notification::KitchenOrderSummary(_) => String::from("kitchen_order_summary"),
+        Notification::KitchenInvitationReceived(_) => String::from("kitchen_invitation_received"),
+    }
+}
+
+pub fn get_recipient(notification: &Notification) -> &User {
+    match notification {
+        Notification::Registered(n) => &n.user,
+        Notification::VerificationOtpRequested(n) => &n.user,
+        Notification::MagicLinkRequested(n) => &n.user,
+        Notification::EmailChangeRequested(n) => &n.user,
+        Notification::ContactChanged(n) => &n.user,
+        Notification::BankAccountCreationSuccessful(n) => &n.user,
+        Notification::BankAccountCreationFailed(n) => &n.user,
+        Notification::OrderStatusUpdated(n) => &n.user,
+        Notification::OrderReceipt(n) => &n.user,
+        Notification::KitchenOrderSummary(n) => &n.user,
+        Notification::KitchenInvitationReceived(n) => &n.user,
+    }
+}
+
+fn format_amount(amount: &BigDecimal) -> String {
+    format!("₦{}", amount.with_scale(2))
+}
+
+// One line per meal, e.g `2 x Jollof rice - ₦3000.00`
+fn format_items(order: &FullOrder) -> String {
+    order
+        .items
+        .0
+        .iter()
+        .map(|item| {
+            format!(
+                "{} x {} - {}",
+                item.quantity,
+                item.meal.name,
+                format_amount(&(&item.price * BigDecimal::from(item.quantity)))
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn insert_full_order_variables(variables: &mut HashMap<String, String>, order: &FullOrder) {
+    variables.insert(String::from("order_id"), order.id.clone());
+    variables.insert(String::from("order_total"), format_amount(&order.total));
+    variables.insert(String::from("sub_total"), format_amount(&order.sub_total));
+    variables.insert(
+        String::from("delivery_fee"),
+        format_amount(&order.delivery_fee),
+    );
+    variables.insert(
+        String::from("service_fee"),
+        format_amount(&order.service_fee),
+    );
+    variables.insert(
+        String::from("delivery_address"),
+        order.delivery_address.clone(),
+    );
+    variables.insert(
+        String::from("dispatch_rider_note"),
+        order.dispatch_rider_note.clone(),
+    );
+    variables.insert(String::from("kitchen_name"), order.kitchen.name.clone());
+    variables.insert(String::from("items"), format_items(order));
+}
+
+pub fn get_variables(notification: &Notification) -> HashMap<String, String> {
+    let mut variables = HashMap::from([(
+        String::from("first_name"),
+        get_recipient(notification).first_name.clone(),
+    )]);
+
+    match notification {
+        Notification::VerificationOtpRequested(n) => {
+            variables.insert(String::from("code"), n.code.clone());
+            variables.insert(String::from("validity"), n.validity.to_string());
+        }
+        Notification::MagicLinkRequested(n) => {
+            variables.insert(String::from("link"), n.link.clone());
+            variables.insert(String::from("validity"), n.validity.to_string());
+        }
+        Notification::EmailChangeRequested(n) => {
+            variables.insert(String::from("link"), n.link.clone());
+            variables.insert(String::from("validity"), n.validity.to_string());
+        }
+        Notification::OrderStatusUpdated(n) => {
+            variables.insert(String::from("order_id"), n.order.id.clone());
+            variables.insert(String::from("order_total"), format_amount(&n.order.total));
+            variables.insert(String::from("kitchen_name"), n.kitchen.name.clone());
+        }
+        Notification::OrderReceipt(n) => insert_full_order_variables(&mut variables, &n.order),
+        Notification::KitchenOrderSummary(n) => {
+            insert_full_order_variables(&mut variables, &n.order)
+        }
+        Notification::KitchenInvitationReceived(n) => {
+            variables.insert(String::from("kitchen_name"), n.kitchen.name.clone());
+            variables.insert(String::from("role"), n.role.to_string().to_lowercase());
+        }
+        _ => (),
+    };
+
+    variables
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn fill(text: &str, variables: &HashMap<String, String>, is_html: bool) -> String {
+    variables
+        .iter()
+        .fold(text.to_string(), |text, (name, value)| {
+            let value = match is_html {
+                true => escape_html(value),
+                false => value.clone(),
+            };
+
+            text.replace(&format!("{{{{{}}}}}", name), &value)
+        })
+}
+
+pub fn render_template(
+    name: &str,
+    backend: &Backend,
+    locale: &Locale,
+    variables: &HashMap<String, String>,
+) -> Result<Rendered> {
+    let template = find(name, backend, locale).ok_or_else(|| {
+        tracing::error!(
+            "No {} template called {} for locale {}",
+            backend.to_string(),
+            name,
+            locale.to_string()
+        );
+        Error::InvalidNotification
+    })?;
+
+    // email bodies are html, everything else (including email subjects) is plain text
+    let body = match backend {
+        Backend::Email => fill(template.body, variables, true).replace('\n', "<br/>"),
+        _ => fill(template.body, variables, false),
+    };
+
+    Ok(Rendered {
+        title: fill(template.title, variables, false),
+        body,
+    })
+}
+
+pub fn render(notification: &Notification, backend: &Backend) -> Result<Rendered> {
+    render_template(
+        &get_name(notification),
+        backend,
+        &get_recipient(notification).locale,
+        &get_variables(notification),
+    )
+}
@@ -0,0 +1,91 @@
+use super::Template;
+use crate::modules::notification::service::Backend;
+
+pub fn find(name: &str, backend: &Backend) -> Option<Template> {
+    let (title, body) = match (name, backend) {
+        ("registered", Backend::Email) => (
+            "Welcome to FoodHut",
+            "How far {{first_name}}, welcome to FoodHut",
+        ),
+        ("registered", _) => ("Registration don set", "Welcome to FoodHut"),
+        ("verification_otp_requested", _) => (
+            "Your FoodHut verification code",
+            "Your FoodHut app verification pin na {{code}}\nThe pin go expire for {{validity}} minutes, no show am to anybody",
+        ),
+        ("magic_link_requested", Backend::Email) => (
+            "Sign in to FoodHut",
+            "<a href=\"{{link}}\">Click here make you sign in to FoodHut</a>\nThe link go expire for {{validity}} minutes and you fit use am only once, no share am with anybody",
+        ),
+        ("email_change_requested", Backend::Email) => (
+            "Confirm your new FoodHut email",
+            "<a href=\"{{link}}\">Click here make you confirm say na this one be your new FoodHut email</a>\nThe link go expire for {{validity}} minutes, if no be you ask for the change, just ignore this email",
+        ),
+        ("contact_changed.email", _) => (
+            "Your FoodHut email don change",
+            "The email for your FoodHut account don change just now. If no be you, abeg contact support sharp sharp",
+        ),
+        ("contact_changed.phone_number", _) => (
+            "Your FoodHut phone number don change",
+            "The phone number for your FoodHut account don change just now. If no be you, abeg contact support sharp sharp",
+        ),
+        ("bank_account_creation_successful", _) => (
+            "Virtual account don ready",
+            "{{first_name}}, your virtual account don ready!",
+        ),
+        ("bank_account_creation_failed", _) => (
+            "Virtual account no work",
+            "{{first_name}}, we no fit create your virtual account",
+        ),
+        ("order_status_updated.awaiting_acknowledgement", _) => (
+            "Order don enter",
+            "Your order from {{kitchen_name}} don enter, we go tell you once dem start to cook am",
+        ),
+        ("order_status_updated.preparing", _) => (
+            "Dem dey cook your order",
+            "{{kitchen_name}} dey prepare your order",
+        ),
+        ("order_status_updated.in_transit", _) => (
+            "Your order dey come",
+            "Your order from {{kitchen_name}} dey on the way",
+        ),
+        ("order_status_updated.delivered", _) => (
+            "Your order don land",
+            "Your order from {{kitchen_name}} don land, enjoy your food!",
+        ),
+        ("order_status_updated.cancelled", _) => (
+            "Order don cancel",
+            "Your order from {{kitchen_name}} don cancel",
+        ),
+        ("kitchen_order_status_updated.awaiting_acknowledgement", _) => (
+            "New order",
+            "{{kitchen_name}} get new order of {{order_total}}, accept am make you start to cook",
+        ),
+        ("kitchen_order_status_updated.delivered", _) => (
+            "Order don land",
+            "Order {{order_id}} don reach the customer",
+        ),
+        ("kitchen_order_status_updated.cancelled", _) => (
+            "Order don cancel",
+            "The customer don cancel order {{order_id}}",
+        ),
+        ("order_receipt", Backend::Email) => (
+            "Your FoodHut receipt for order {{order_id}}",
+            "How far {{first_name}}, thank you as you order from {{kitchen_name}}!\n\n{{items}}\n\nSubtotal: {{sub_total}}\nDelivery fee: {{delivery_fee}}\nService fee: {{service_fee}}\nTotal: {{order_total}}\n\nWe dey deliver am to: {{delivery_address}}",
+        ),
+        ("kitchen_order_summary", Backend::Email) => (
+            "New order {{order_id}} for {{kitchen_name}}",
+            "{{kitchen_name}} get new order to prepare\n\n{{items}}\n\nSubtotal: {{sub_total}}\nWe dey deliver am to: {{delivery_address}}\nNote for the rider: {{dispatch_rider_note}}\n\nAccept am for the FoodHut app make you start to cook",
+        ),
+        ("kitchen_invitation_received", Backend::Sms) => (
+            "",
+            "Dem don invite you make you join {{kitchen_name}} for FoodHut as {{role}}. Open the FoodHut app make you accept am",
+        ),
+        ("kitchen_invitation_received", _) => (
+            "Kitchen invitation",
+            "Dem don invite you make you join {{kitchen_name}} as {{role}}",
+        ),
+        _ => return None,
+    };
+
+    Some(Template { title, body })
+}
@@ -0,0 +1,74 @@
+use super::Template;
+use crate::modules::notification::service::Backend;
+
+pub fn find(name: &str, backend: &Backend) -> Option<Template> {
+    let (title, body) = match (name, backend) {
+        ("registered", _) => (
+            "Ẹ kú àbọ̀ sí FoodHut",
+            "Ẹ n lẹ́ o {{first_name}}, ẹ kú àbọ̀ sí FoodHut",
+        ),
+        ("verification_otp_requested", _) => (
+            "Kóòdù ìjẹ́rìísí FoodHut rẹ",
+            "Kóòdù ìjẹ́rìísí FoodHut rẹ ni {{code}}\nYóò parí láàárín ìṣẹ́jú {{validity}}, má ṣe fi han ẹnikẹ́ni",
+        ),
+        ("contact_changed.email", _) => (
+            "A ti yí ímeèlì FoodHut rẹ padà",
+            "A ṣẹ̀ṣẹ̀ yí ímeèlì tó wà lórí àkọọ́lẹ̀ FoodHut rẹ padà. Tí kì í bá ṣe ìwọ, jọ̀wọ́ kàn sí àwọn olùrànlọ́wọ́ wa lẹ́sẹ̀kẹsẹ̀",
+        ),
+        ("contact_changed.phone_number", _) => (
+            "A ti yí nọ́mbà fóònù FoodHut rẹ padà",
+            "A ṣẹ̀ṣẹ̀ yí nọ́mbà fóònù tó wà lórí àkọọ́lẹ̀ FoodHut rẹ padà. Tí kì í bá ṣe ìwọ, jọ̀wọ́ kàn sí àwọn olùrànlọ́wọ́ wa lẹ́sẹ̀kẹsẹ̀",
+        ),
+        ("bank_account_creation_successful", _) => (
+            "A ti ṣí àkọọ́lẹ̀ ìfowópamọ́ rẹ",
+            "{{first_name}}, a ti ṣí àkọọ́lẹ̀ ìfowópamọ́ rẹ!",
+        ),
+        ("bank_account_creation_failed", _) => (
+            "A kò rí àkọọ́lẹ̀ ìfowópamọ́ ṣí",
+            "{{first_name}}, a kò lè ṣí àkọọ́lẹ̀ ìfowópamọ́ rẹ",
+        ),
+        ("order_status_updated.awaiting_acknowledgement", _) => (
+            "A ti gba ìbéèrè rẹ",
+            "A ti fi ìbéèrè rẹ ránṣẹ́ sí {{kitchen_name}}, a ó sọ fún ọ nígbà tí wọ́n bá bẹ̀rẹ̀ sí í sè é",
+        ),
+        ("order_status_updated.preparing", _) => (
+            "Wọ́n ń se oúnjẹ rẹ",
+            "{{kitchen_name}} ń se oúnjẹ rẹ lọ́wọ́",
+        ),
+        ("order_status_updated.in_transit", _) => (
+            "Oúnjẹ rẹ ń bọ̀",
+            "Oúnjẹ rẹ láti {{kitchen_name}} ti wà lójú ọ̀nà",
+        ),
+        ("order_status_updated.delivered", _) => (
+            "Oúnjẹ rẹ ti dé",
+            "Oúnjẹ rẹ láti {{kitchen_name}} ti dé, gbádùn rẹ̀!",
+        ),
+        ("order_status_updated.cancelled", _) => (
+            "A ti fagilé ìbéèrè rẹ",
+            "A ti fagilé ìbéèrè rẹ láti {{kitchen_name}}",
+        ),
+        ("kitchen_order_status_updated.awaiting_acknowledgement", _) => (
+            "Ìbéèrè tuntun",
+            "{{kitchen_name}} ní ìbéèrè tuntun ti {{order_total}}, gbà á kí o lè bẹ̀rẹ̀ sí í sè é",
+        ),
+        ("kitchen_order_status_updated.delivered", _) => (
+            "Oúnjẹ ti dé",
+            "Ìbéèrè {{order_id}} ti dé ọ̀dọ̀ oníbàárà",
+        ),
+        ("kitchen_order_status_updated.cancelled", _) => (
+            "A ti fagilé ìbéèrè",
+            "Oníbàárà ti fagilé ìbéèrè {{order_id}}",
+        ),
+        ("kitchen_invitation_received", Backend::Sms) => (
+            "",
+            "Wọ́n ti pè ọ́ láti darapọ̀ mọ́ {{kitchen_name}} lórí FoodHut gẹ́gẹ́ bí {{role}}. Ṣí áàpù FoodHut láti gbà á",
+        ),
+        ("kitchen_invitation_received", _) => (
+            "Ìpè sí ilé ìdáná",
+            "Wọ́n ti pè ọ́ láti darapọ̀ mọ́ {{kitchen_name}} gẹ́gẹ́ bí {{role}}",
+        ),
+        _ => return None,
+    };
+
+    Some(Template { title, body })
+}
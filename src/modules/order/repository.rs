@@ -167,6 +167,54 @@ pub struct Order {
     pub items: OrderItems,
     pub kitchen_id: String,
     pub owner_id: String,
+    pub group_id: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum OrderGroupStatus {
+    #[serde(rename = "AWAITING_PAYMENT")]
+    AwaitingPayment,
+    #[serde(rename = "PAID")]
+    Paid,
+}
+
+impl ToString for OrderGroupStatus {
+    fn to_string(&self) -> String {
+        match self {
+            OrderGroupStatus::AwaitingPayment => String::from("AWAITING_PAYMENT"),
+            OrderGroupStatus::Paid => String::from("PAID"),
+        }
+    }
+}
+
+impl FromStr for OrderGroupStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "AWAITING_PAYMENT" => Ok(OrderGroupStatus::AwaitingPayment),
+            "PAID" => Ok(OrderGroupStatus::Paid),
+            _ => Err(format!("'{}' is not a valid OrderGroupStatus", s)),
+        }
+    }
+}
+
+impl From<String> for OrderGroupStatus {
+    fn from(s: String) -> Self {
+        s.parse()
+            .unwrap_or_else(|_| panic!("Failed to parse '{}' into an OrderGroupStatus", s))
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct OrderGroup {
+    pub id: String,
+    pub status: OrderGroupStatus,
+    pub payment_method: PaymentMethod,
+    pub total: BigDecimal,
+    pub owner_id: String,
     pub created_at: NaiveDateTime,
     pub updated_at: Option<NaiveDateTime>,
 }
@@ -320,6 +368,7 @@ pub struct CreateOrderPayload {
     pub dispatch_rider_note: String,
     pub kitchen_id: String,
     pub owner_id: String,
+    pub group_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -368,7 +417,8 @@ pub async fn create<'e, E: PgExecutor<'e>>(
             dispatch_rider_note,
             items,
             kitchen_id,
-            owner_id
+            owner_id,
+            group_id
         )
         VALUES (
             $1,
@@ -383,7 +433,8 @@ pub async fn create<'e, E: PgExecutor<'e>>(
             $8,
             $9,
             $10,
-            $11
+            $11,
+            $12
         )
         RETURNING *
         "#,
@@ -398,6 +449,7 @@ pub async fn create<'e, E: PgExecutor<'e>>(
         json!(order_items),
         payload.kitchen_id,
         payload.owner_id,
+        payload.group_id,
     )
     .fetch_one(e)
     .await
@@ -1219,6 +1271,221 @@ pub async fn confirm_payment<'e, E: PgExecutor<'e>>(
     })
 }
 
+pub struct CreateOrderGroupPayload {
+    pub payment_method: PaymentMethod,
+    pub total: BigDecimal,
+    pub owner_id: String,
+}
+
+pub async fn create_group<'e, E: PgExecutor<'e>>(
+    e: E,
+    payload: CreateOrderGroupPayload,
+) -> Result<OrderGroup, Error> {
+    sqlx::query_as!(
+        OrderGroup,
+        r#"
+        INSERT INTO order_groups (
+            id,
+            status,
+            payment_method,
+            total,
+            owner_id
+        )
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING *
+        "#,
+        Ulid::new().to_string(),
+        OrderGroupStatus::AwaitingPayment.to_string(),
+        payload.payment_method.to_string(),
+        payload.total,
+        payload.owner_id,
+    )
+    .fetch_one(e)
+    .await
+    .map_err(|err| {
+        tracing::error!("Error occurred while trying to create an order group: {}", err);
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_group_by_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+) -> Result<Option<OrderGroup>, Error> {
+    sqlx::query_as!(OrderGroup, "SELECT * FROM order_groups WHERE id = $1", id)
+        .fetch_optional(e)
+        .await
+        .map_err(|err| {
+            tracing::error!(
+                "Error occurred while trying to fetch order group by id {}: {}",
+                id,
+                err
+            );
+            Error::UnexpectedError
+        })
+}
+
+pub async fn find_many_by_group_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    group_id: String,
+) -> Result<Vec<Order>, Error> {
+    sqlx::query_as!(
+        Order,
+        "SELECT * FROM orders WHERE group_id = $1 ORDER BY created_at ASC",
+        group_id
+    )
+    .fetch_all(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch orders by group id {}: {}",
+            group_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub struct ConfirmGroupPaymentPayload {
+    pub payment_method: PaymentMethod,
+    pub group_id: String,
+}
+
+pub async fn confirm_group_payment<'e, E: PgExecutor<'e>>(
+    e: E,
+    payload: ConfirmGroupPaymentPayload,
+) -> Result<bool, Error> {
+    sqlx::query!(
+        r#"
+        UPDATE order_groups
+        SET
+            status = 'PAID',
+            payment_method = $1,
+            updated_at = NOW()
+        WHERE
+            id = $2
+            AND status = 'AWAITING_PAYMENT'
+        "#,
+        payload.payment_method.to_string(),
+        payload.group_id,
+    )
+    .execute(e)
+    .await
+    .map(|res| res.rows_affected() > 0)
+    .map_err(|err| {
+        tracing::error!(
+            "Error confirming payment for order group {}: {}",
+            payload.group_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct FavouriteOrder {
+    pub id: String,
+    pub name: String,
+    pub order_id: String,
+    pub owner_id: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+pub struct CreateFavouriteOrderPayload {
+    pub name: String,
+    pub order_id: String,
+    pub owner_id: String,
+}
+
+pub async fn create_favourite<'e, E: PgExecutor<'e>>(
+    e: E,
+    payload: CreateFavouriteOrderPayload,
+) -> Result<FavouriteOrder, Error> {
+    sqlx::query_as!(
+        FavouriteOrder,
+        "
+        INSERT INTO favourite_orders (id, name, order_id, owner_id)
+        VALUES ($1, $2, $3, $4)
+        RETURNING *
+        ",
+        Ulid::new().to_string(),
+        payload.name,
+        payload.order_id,
+        payload.owner_id,
+    )
+    .fetch_one(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to create a favourite order: {}",
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_favourite_by_id_and_owner_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+    owner_id: String,
+) -> Result<Option<FavouriteOrder>, Error> {
+    sqlx::query_as!(
+        FavouriteOrder,
+        "SELECT * FROM favourite_orders WHERE id = $1 AND owner_id = $2",
+        id,
+        owner_id
+    )
+    .fetch_optional(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch favourite order by id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_many_favourites_by_owner_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    owner_id: String,
+) -> Result<Vec<FavouriteOrder>, Error> {
+    sqlx::query_as!(
+        FavouriteOrder,
+        "SELECT * FROM favourite_orders WHERE owner_id = $1 ORDER BY created_at DESC",
+        owner_id
+    )
+    .fetch_all(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch favourite orders by owner id {}: {}",
+            owner_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn delete_favourite_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<(), Error> {
+    sqlx::query!("DELETE FROM favourite_orders WHERE id = $1", id)
+        .execute(e)
+        .await
+        .map(|_| ())
+        .map_err(|err| {
+            tracing::error!(
+                "Error occurred while trying to delete favourite order by id {}: {}",
+                id,
+                err
+            );
+            Error::UnexpectedError
+        })
+}
+
+// NOTE: the transition is checked against the row being updated rather than a separate read of it, so that an update
+// waiting on another one sees the status that one left behind
 pub async fn update_order_status<'e, E: PgExecutor<'e>>(
     e: E,
     order_id: String,
@@ -1227,25 +1494,19 @@ pub async fn update_order_status<'e, E: PgExecutor<'e>>(
     sqlx::query_as!(
         Order,
         r#"
-        WITH valid_transition AS (
-            SELECT CASE
-                WHEN orders.status = 'AWAITING_ACKNOWLEDGEMENT' AND $2 = 'PREPARING' THEN TRUE
-                WHEN orders.status = 'AWAITING_ACKNOWLEDGEMENT' AND $2 = 'CANCELLED' THEN TRUE
-                WHEN orders.status = 'PREPARING' AND $2 = 'IN_TRANSIT' THEN TRUE
-                WHEN orders.status = 'IN_TRANSIT' AND $2 = 'DELIVERED' THEN TRUE
-                ELSE FALSE
-            END AS is_valid
-            FROM
-                orders
-            WHERE id = $1
-        )
         UPDATE
             orders
         SET
             status = $2
         WHERE
             id = $1
-            AND (SELECT is_valid FROM valid_transition)
+            AND CASE
+                WHEN status = 'AWAITING_ACKNOWLEDGEMENT' AND $2 = 'PREPARING' THEN TRUE
+                WHEN status = 'AWAITING_ACKNOWLEDGEMENT' AND $2 = 'CANCELLED' THEN TRUE
+                WHEN status = 'PREPARING' AND $2 = 'IN_TRANSIT' THEN TRUE
+                WHEN status = 'IN_TRANSIT' AND $2 = 'DELIVERED' THEN TRUE
+                ELSE FALSE
+            END
         RETURNING *
         "#,
         order_id,
@@ -1259,6 +1520,36 @@ pub async fn update_order_status<'e, E: PgExecutor<'e>>(
     })
 }
 
+// NOTE: only goes through while the order is still in `status`, so two cancellations racing each other can't both
+// see it as theirs to cancel
+pub async fn cancel_by_id_and_status<'e, E: PgExecutor<'e>>(
+    e: E,
+    order_id: String,
+    status: OrderStatus,
+) -> Result<Option<Order>, Error> {
+    sqlx::query_as!(
+        Order,
+        r#"
+        UPDATE
+            orders
+        SET
+            status = 'CANCELLED'
+        WHERE
+            id = $1
+            AND status = $2
+        RETURNING *
+        "#,
+        order_id,
+        status.to_string()
+    )
+    .fetch_optional(e)
+    .await
+    .map_err(|err| {
+        tracing::error!("Error cancelling order {}: {}", order_id, err);
+        Error::UnexpectedError
+    })
+}
+
 // pub async fn update_order_item_status(
 //     e: E,
 //     order_item_id: String,
@@ -1301,6 +1592,61 @@ pub async fn update_order_status<'e, E: PgExecutor<'e>>(
 //     })
 // }
 
+pub async fn find_many_by_owner_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    owner_id: String,
+) -> Result<Vec<Order>, Error> {
+    sqlx::query_as!(
+        Order,
+        "SELECT * FROM orders WHERE owner_id = $1 ORDER BY created_at DESC",
+        owner_id
+    )
+    .fetch_all(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch orders of user with id {}: {}",
+            owner_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+// NOTE: orders are kept for accounting, only the delivery details that point at the person are cleared
+pub async fn anonymise_many_by_owner_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    owner_id: String,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "
+        UPDATE orders
+        SET
+            delivery_address = '',
+            dispatch_rider_note = '',
+            updated_at = NOW()
+        WHERE
+            owner_id = $1
+        ",
+        owner_id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to anonymise orders of user with id {}: {}",
+            owner_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
 pub fn is_owner(order: &Order, user: &User) -> bool {
     order.owner_id == user.id
 }
+
+pub fn is_group_owner(group: &OrderGroup, user: &User) -> bool {
+    group.owner_id == user.id
+}
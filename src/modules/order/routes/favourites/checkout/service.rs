@@ -0,0 +1,117 @@
+use super::types::{request, response};
+use crate::{
+    modules::{
+        cart::service as cart_service,
+        order::{repository, service},
+        payment,
+    },
+    types::Context,
+};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let parsed_delivery_date = payload
+        .body
+        .delivery_date
+        .map(|d| chrono::NaiveDateTime::parse_from_str(&d.to_string(), "%s"))
+        .transpose()
+        .map_err(|err| response::Error::InvalidDate(err.to_string()))?;
+
+    parsed_delivery_date
+        .clone()
+        .map(|delivery_date| {
+            if delivery_date < chrono::Utc::now().naive_utc() {
+                Err(response::Error::InvalidDate(String::from(
+                    "Delivery date cannot be in the past",
+                )))
+            } else {
+                Ok(())
+            }
+        })
+        .transpose()?;
+
+    let favourite = repository::find_favourite_by_id_and_owner_id(
+        &ctx.db_conn.pool,
+        payload.id,
+        payload.auth.user.id.clone(),
+    )
+    .await
+    .map_err(|_| response::Error::FailedToCheckoutFavouriteOrder)?
+    .ok_or(response::Error::FavouriteOrderNotFound)?;
+
+    let original_order = repository::find_full_order_by_id_and_owner_id(
+        &ctx.db_conn.pool,
+        favourite.order_id,
+        payload.auth.user.id.clone(),
+    )
+    .await
+    .map_err(|_| response::Error::FailedToCheckoutFavouriteOrder)?
+    .ok_or(response::Error::FavouriteOrderNotFound)?;
+
+    let kitchen_id = original_order.kitchen_id.clone();
+    let reorderable = service::collect_reorderable_items(original_order);
+
+    if reorderable.items.is_empty() {
+        return Err(response::Error::NoItemsToCheckout(reorderable.skipped));
+    }
+
+    // NOTE: payment starts right away (and a wallet is debited on the spot), so the customer has to have seen what
+    // was dropped and what it costs now before anything is charged
+    let quote = cart_service::generate_quote(favourite.id.clone(), &reorderable.items);
+    if payload.body.quote_id.as_ref() != Some(&quote.id) {
+        return Err(response::Error::StaleQuote {
+            skipped: reorderable.skipped,
+            price_changes: reorderable.price_changes,
+            quote,
+        });
+    }
+
+    let mut tx = ctx.db_conn.pool.begin().await.map_err(|err| {
+        tracing::error!("Failed to start database transaction: {}", err);
+        response::Error::FailedToCheckoutFavouriteOrder
+    })?;
+
+    let order = repository::create(
+        &mut *tx,
+        repository::CreateOrderPayload {
+            items: reorderable.items,
+            payment_method: payload.body.with.clone(),
+            delivery_address: payload.body.delivery_address,
+            delivery_date: parsed_delivery_date,
+            dispatch_rider_note: payload.body.dispatch_rider_note,
+            kitchen_id,
+            owner_id: payload.auth.user.id.clone(),
+            group_id: None,
+        },
+    )
+    .await
+    .map_err(|_| response::Error::FailedToCheckoutFavouriteOrder)?;
+
+    let details = payment::service::initialize_payment_for_order(
+        ctx.clone(),
+        &mut tx,
+        payment::service::InitializePaymentForOrder {
+            method: payload.body.with.into(),
+            order: order.clone(),
+            payer: payload.auth.user,
+        },
+    )
+    .await
+    .map_err(|err| match err {
+        payment::service::Error::InsufficientBalance => response::Error::InsufficientBalance,
+        _ => response::Error::FailedToCheckoutFavouriteOrder,
+    })?;
+
+    tx.commit()
+        .await
+        .map_err(|err| {
+            tracing::error!("Failed to commit database transaction: {}", err);
+            response::Error::FailedToCheckoutFavouriteOrder
+        })
+        .map(|_| response::Success::CheckoutSuccessful {
+            order,
+            payment: details,
+            skipped: reorderable.skipped,
+            price_changes: reorderable.price_changes,
+        })
+}
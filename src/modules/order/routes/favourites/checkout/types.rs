@@ -0,0 +1,130 @@
+pub mod request {
+    use crate::modules::{
+        auth::middleware::VerifiedUserAuth as Auth, order::repository::PaymentMethod,
+    };
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    pub struct Body {
+        pub with: PaymentMethod,
+        pub delivery_address: String,
+        pub delivery_date: Option<u64>,
+        pub dispatch_rider_note: String,
+        // from the `quote` a previous attempt was turned away with
+        pub quote_id: Option<String>,
+    }
+
+    pub struct Payload {
+        pub id: String,
+        pub body: Body,
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    use crate::modules::{
+        cart::service::Quote,
+        order::{
+            repository::Order,
+            service::{OrderItemPriceChange, SkippedOrderItem},
+        },
+        payment::service::PaymentDetails,
+    };
+
+    pub enum Success {
+        CheckoutSuccessful {
+            order: Order,
+            payment: PaymentDetails,
+            skipped: Vec<SkippedOrderItem>,
+            price_changes: Vec<OrderItemPriceChange>,
+        },
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::CheckoutSuccessful {
+                    order,
+                    payment,
+                    skipped,
+                    price_changes,
+                } => (
+                    StatusCode::CREATED,
+                    Json(json!({
+                        "message": "Favourite order checkedout successfully",
+                        "id": order.id,
+                        "payment": payment,
+                        "skipped": skipped,
+                        "price_changes": price_changes,
+                    })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        InvalidDate(String),
+        FavouriteOrderNotFound,
+        NoItemsToCheckout(Vec<SkippedOrderItem>),
+        StaleQuote {
+            skipped: Vec<SkippedOrderItem>,
+            price_changes: Vec<OrderItemPriceChange>,
+            quote: Quote,
+        },
+        InsufficientBalance,
+        FailedToCheckoutFavouriteOrder,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::InvalidDate(err) => {
+                    (StatusCode::BAD_REQUEST, Json(json!({ "error": err }))).into_response()
+                }
+                Self::FavouriteOrderNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Favourite order not found" })),
+                )
+                    .into_response(),
+                Self::NoItemsToCheckout(skipped) => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({
+                        "error": "None of the meals in this order are available",
+                        "skipped": skipped,
+                    })),
+                )
+                    .into_response(),
+                Self::StaleQuote {
+                    skipped,
+                    price_changes,
+                    quote,
+                } => (
+                    StatusCode::CONFLICT,
+                    Json(json!({
+                        "error": "Favourite order has changed since it was quoted",
+                        "skipped": skipped,
+                        "price_changes": price_changes,
+                        "quote": quote,
+                    })),
+                )
+                    .into_response(),
+                Self::InsufficientBalance => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Insufficient balance" })),
+                )
+                    .into_response(),
+                Self::FailedToCheckoutFavouriteOrder => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to checkout favourite order" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -0,0 +1,32 @@
+use super::types::{request, response};
+use crate::{modules::order::repository, types::Context};
+use std::sync::Arc;
+use validator::Validate;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    payload.body.validate().map_err(|errors| {
+        tracing::warn!("Failed to validate payload: {errors}");
+        response::Error::FailedToValidate(errors)
+    })?;
+
+    let order = repository::find_by_id(&ctx.db_conn.pool, payload.body.order_id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToCreateFavouriteOrder)?
+        .ok_or(response::Error::OrderNotFound)?;
+
+    if !repository::is_owner(&order, &payload.auth.user) {
+        return Err(response::Error::OrderNotFound);
+    }
+
+    repository::create_favourite(
+        &ctx.db_conn.pool,
+        repository::CreateFavouriteOrderPayload {
+            name: payload.body.name,
+            order_id: order.id,
+            owner_id: payload.auth.user.id,
+        },
+    )
+    .await
+    .map_err(|_| response::Error::FailedToCreateFavouriteOrder)
+    .map(response::Success::FavouriteOrderCreated)
+}
@@ -0,0 +1,69 @@
+pub mod request {
+    use crate::modules::auth::middleware::UserAuth as Auth;
+    use serde::Deserialize;
+    use validator::Validate;
+
+    #[derive(Deserialize, Validate)]
+    pub struct Body {
+        #[validate(length(min = 1, max = 64))]
+        pub name: String,
+        pub order_id: String,
+    }
+
+    pub struct Payload {
+        pub body: Body,
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+    use validator::ValidationErrors;
+
+    use crate::modules::order::repository::FavouriteOrder;
+
+    pub enum Success {
+        FavouriteOrderCreated(FavouriteOrder),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FavouriteOrderCreated(favourite) => (
+                    StatusCode::CREATED,
+                    Json(json!({ "message": "Favourite order created", "id": favourite.id })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        OrderNotFound,
+        FailedToCreateFavouriteOrder,
+        FailedToValidate(ValidationErrors),
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::OrderNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Order not found" })),
+                )
+                    .into_response(),
+                Self::FailedToCreateFavouriteOrder => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to create favourite order" })),
+                )
+                    .into_response(),
+                Self::FailedToValidate(errors) => {
+                    (StatusCode::BAD_REQUEST, Json(json!({ "errors": errors }))).into_response()
+                }
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
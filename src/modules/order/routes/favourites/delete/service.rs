@@ -0,0 +1,19 @@
+use super::types::{request, response};
+use crate::{modules::order::repository, types::Context};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let favourite = repository::find_favourite_by_id_and_owner_id(
+        &ctx.db_conn.pool,
+        payload.id,
+        payload.auth.user.id,
+    )
+    .await
+    .map_err(|_| response::Error::FailedToDeleteFavouriteOrder)?
+    .ok_or(response::Error::FavouriteOrderNotFound)?;
+
+    repository::delete_favourite_by_id(&ctx.db_conn.pool, favourite.id)
+        .await
+        .map_err(|_| response::Error::FailedToDeleteFavouriteOrder)
+        .map(|_| response::Success::FavouriteOrderDeleted)
+}
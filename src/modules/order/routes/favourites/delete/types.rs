@@ -0,0 +1,53 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub id: String,
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        FavouriteOrderDeleted,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FavouriteOrderDeleted => (
+                    StatusCode::OK,
+                    Json(json!({ "message": "Favourite order deleted" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        FavouriteOrderNotFound,
+        FailedToDeleteFavouriteOrder,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FavouriteOrderNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Favourite order not found" })),
+                )
+                    .into_response(),
+                Self::FailedToDeleteFavouriteOrder => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to delete favourite order" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -0,0 +1,10 @@
+use super::types::{request, response};
+use crate::{modules::order::repository, types::Context};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    repository::find_many_favourites_by_owner_id(&ctx.db_conn.pool, payload.auth.user.id)
+        .await
+        .map_err(|_| response::Error::FailedToFetchFavouriteOrders)
+        .map(response::Success::FavouriteOrders)
+}
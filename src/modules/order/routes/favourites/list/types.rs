@@ -0,0 +1,46 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    use crate::modules::order::repository::FavouriteOrder;
+
+    pub enum Success {
+        FavouriteOrders(Vec<FavouriteOrder>),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FavouriteOrders(favourites) => {
+                    (StatusCode::OK, Json(json!(favourites))).into_response()
+                }
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToFetchFavouriteOrders,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToFetchFavouriteOrders => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch favourite orders" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -1,12 +1,12 @@
 use super::types::{request, response};
 use crate::{
-    modules::{order::repository, user},
+    modules::{auth::permission::Permission, order::repository, user},
     types::Context,
 };
 use std::sync::Arc;
 
 pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
-    if user::repository::is_admin(&payload.auth.user) {
+    if payload.auth.user.has_permission(&Permission::ViewOrders) {
         let order = repository::find_full_order_by_id(&ctx.db_conn.pool, payload.id)
             .await
             .map_err(|_| response::Error::FailedToFetchOrder)?
@@ -0,0 +1,9 @@
+mod pay;
+
+use crate::types::Context;
+use axum::routing::Router;
+use std::sync::Arc;
+
+pub fn get_router() -> Router<Arc<Context>> {
+    Router::new().nest("/groups", Router::new().nest("/", pay::get_router()))
+}
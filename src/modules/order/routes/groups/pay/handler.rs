@@ -0,0 +1,16 @@
+use super::{service::service, types::request};
+use crate::{modules::auth::middleware::UserAuth, types::Context};
+use axum::{
+    extract::{Json, Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+pub async fn handler(
+    State(ctx): State<Arc<Context>>,
+    auth: UserAuth,
+    Path(id): Path<String>,
+    Json(body): Json<request::Body>,
+) -> impl IntoResponse {
+    service(ctx, request::Payload { body, auth, id }).await
+}
@@ -0,0 +1,51 @@
+use super::types::{request, response};
+use crate::{
+    modules::{order::repository, payment},
+    types::Context,
+};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let group = repository::find_group_by_id(&ctx.db_conn.pool, payload.id)
+        .await
+        .map_err(|_| response::Error::FailedToInitiateOrderGroupPayment)?
+        .ok_or(response::Error::OrderGroupNotFound)?;
+
+    if !repository::is_group_owner(&group, &payload.auth.user) {
+        return Err(response::Error::OrderGroupNotFound);
+    }
+
+    let mut tx = ctx.db_conn.pool.begin().await.map_err(|err| {
+        tracing::error!("Failed to start transaction: {}", err);
+        response::Error::FailedToInitiateOrderGroupPayment
+    })?;
+
+    let orders = repository::find_many_by_group_id(&mut *tx, group.id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToInitiateOrderGroupPayment)?;
+
+    let details = payment::service::initialize_payment_for_order_group(
+        ctx.clone(),
+        &mut tx,
+        payment::service::InitializePaymentForOrderGroup {
+            method: payload.body.with.into(),
+            group,
+            orders,
+            payer: payload.auth.user,
+        },
+    )
+    .await
+    .map_err(|err| match err {
+        payment::service::Error::AlreadyPaid => response::Error::PaymentAlreadyMade,
+        payment::service::Error::InsufficientBalance => response::Error::InsufficientBalance,
+        _ => response::Error::FailedToInitiateOrderGroupPayment,
+    })?;
+
+    tx.commit()
+        .await
+        .map_err(|err| {
+            tracing::error!("Failed to commit transaction: {}", err);
+            response::Error::FailedToInitiateOrderGroupPayment
+        })
+        .map(|_| response::Success::PaymentDetails(details))
+}
@@ -1,12 +1,12 @@
 use super::types::{request, response};
 use crate::{
-    modules::{order::repository, user},
+    modules::{auth::permission::Permission, kitchen, order::repository},
     types::Context,
 };
 use std::sync::Arc;
 
 pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
-    let orders = if user::repository::is_admin(&payload.auth.user) {
+    let orders = if payload.auth.user.has_permission(&Permission::ViewOrders) {
         repository::find_many_as_admin(
             &ctx.db_conn.pool,
             payload.pagination,
@@ -19,7 +19,19 @@ pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::
         )
         .await
     } else {
-        if payload.filters.kitchen_id.is_some() {
+        // NOTE: only the kitchen's owner and members get its inbox, anyone else filtering by kitchen just sees their own orders there
+        let is_kitchen_member = match payload.filters.kitchen_id.clone() {
+            Some(kitchen_id) => {
+                kitchen::service::find_access_by_user_id(ctx.clone(), payload.auth.user.id.clone())
+                    .await
+                    .map_err(|_| response::Error::FailedToFetchOrders)?
+                    .map(|access| access.kitchen.id == kitchen_id)
+                    .unwrap_or(false)
+            }
+            None => false,
+        };
+
+        if is_kitchen_member {
             repository::find_many_as_kitchen(
                 &ctx.db_conn.pool,
                 payload.pagination,
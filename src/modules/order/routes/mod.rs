@@ -1,6 +1,10 @@
+mod favourites;
 mod get;
+mod groups;
 mod list;
 mod pay;
+mod refund;
+mod reorder;
 mod update_status;
 
 use crate::types::Context;
@@ -11,6 +15,10 @@ pub fn get_router() -> Router<Arc<Context>> {
     Router::new()
         .nest("/", list::get_router())
         .nest("/", get::get_router())
+        .nest("/", groups::get_router())
+        .nest("/", favourites::get_router())
         .nest("/", pay::get_router())
+        .nest("/", refund::get_router())
+        .nest("/", reorder::get_router())
         .nest("/", update_status::get_router())
 }
@@ -57,6 +57,11 @@ pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::
         .map_err(|_| response::Error::FailedToInitiateOrderPayment)?
         .ok_or(response::Error::OrderNotFound)?;
 
+    // NOTE: the group's invoice covers every order in it, paying for one on its own would charge for it twice
+    if order.group_id.is_some() {
+        return Err(response::Error::OrderIsPartOfGroup);
+    }
+
     pay_for_order(
         ctx.clone(),
         PayForOrderPayload {
@@ -1,5 +1,5 @@
 pub mod request {
-    use crate::modules::{auth::middleware::Auth, order::repository::PaymentMethod};
+    use crate::modules::{auth::middleware::UserAuth as Auth, order::repository::PaymentMethod};
     use serde::Deserialize;
 
     #[derive(Deserialize)]
@@ -35,6 +35,7 @@ pub mod response {
 
     pub enum Error {
         OrderNotFound,
+        OrderIsPartOfGroup,
         FailedToInitiateOrderPayment,
         PaymentAlreadyMade,
         InsufficientBalance,
@@ -53,6 +54,11 @@ pub mod response {
                     Json(json!({ "error": "Order not found" })),
                 )
                     .into_response(),
+                Self::OrderIsPartOfGroup => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Order is part of a group, pay for the group instead" })),
+                )
+                    .into_response(),
                 Self::InsufficientBalance => (
                     StatusCode::BAD_REQUEST,
                     Json(json!({ "error": "Insufficient balance"})),
@@ -0,0 +1,18 @@
+use super::{service::service, types::request};
+use crate::{
+    modules::auth::{middleware::RequirePermission, permission},
+    types::Context,
+};
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+pub async fn handler(
+    State(ctx): State<Arc<Context>>,
+    _: RequirePermission<permission::RefundOrders>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    service(ctx, request::Payload { id }).await
+}
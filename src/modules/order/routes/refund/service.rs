@@ -0,0 +1,70 @@
+use super::types::{request, response};
+use crate::{
+    modules::{
+        kitchen, notification,
+        order::{
+            self,
+            repository::{self, OrderStatus},
+        },
+        user,
+    },
+    types::Context,
+};
+use std::sync::Arc;
+
+// NOTE: refunds cancel the order, once it's delivered the kitchen has been paid and it has to be settled by hand
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let mut tx = ctx.db_conn.pool.begin().await.map_err(|err| {
+        tracing::error!("Failed to start database transaction: {}", err);
+        response::Error::FailedToRefundOrder
+    })?;
+
+    let order = repository::find_by_id(&mut *tx, payload.id)
+        .await
+        .map_err(|_| response::Error::FailedToRefundOrder)?
+        .ok_or(response::Error::OrderNotFound)?;
+
+    match order.status {
+        OrderStatus::AwaitingAcknowledgement | OrderStatus::Preparing | OrderStatus::InTransit => {
+            ()
+        }
+        _ => return Err(response::Error::OrderNotRefundable),
+    };
+
+    // NOTE: the order may have moved on since it was read (another refund, the kitchen cancelling it), nothing is
+    // credited unless this is the cancellation that went through
+    let order = repository::cancel_by_id_and_status(&mut *tx, order.id, order.status)
+        .await
+        .map_err(|_| response::Error::FailedToRefundOrder)?
+        .ok_or(response::Error::OrderNotRefundable)?;
+
+    order::service::refund_order_payment(&mut tx, &order)
+        .await
+        .map_err(|_| response::Error::FailedToRefundOrder)?;
+
+    let kitchen = kitchen::repository::find_by_id(&mut *tx, order.kitchen_id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToRefundOrder)?
+        .ok_or(response::Error::FailedToRefundOrder)?;
+
+    let order_owner = user::repository::find_by_id(&mut *tx, order.owner_id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToRefundOrder)?
+        .ok_or(response::Error::FailedToRefundOrder)?;
+
+    notification::service::enqueue(
+        &mut *tx,
+        notification::service::Notification::order_status_updated(order, kitchen, order_owner),
+        notification::service::Category::Orders,
+        vec![notification::service::Backend::Push],
+    )
+    .await
+    .map_err(|_| response::Error::FailedToRefundOrder)?;
+
+    tx.commit().await.map_err(|err| {
+        tracing::error!("Failed to commit database transaction: {}", err);
+        response::Error::FailedToRefundOrder
+    })?;
+
+    Ok(response::Success::OrderRefunded)
+}
@@ -0,0 +1,56 @@
+pub mod request {
+    pub struct Payload {
+        pub id: String,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        OrderRefunded,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::OrderRefunded => (
+                    StatusCode::OK,
+                    Json(json!({ "message": "Order refunded successfully" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToRefundOrder,
+        OrderNotFound,
+        OrderNotRefundable,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToRefundOrder => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to refund order" })),
+                )
+                    .into_response(),
+                Self::OrderNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Order not found" })),
+                )
+                    .into_response(),
+                Self::OrderNotRefundable => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Only paid orders that haven't been delivered can be refunded" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
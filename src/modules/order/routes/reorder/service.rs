@@ -0,0 +1,86 @@
+use super::types::{request, response};
+use crate::{
+    modules::{
+        cart::{self, repository::CartItem},
+        order::{repository, service},
+    },
+    types::Context,
+};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let order = repository::find_full_order_by_id_and_owner_id(
+        &ctx.db_conn.pool,
+        payload.id,
+        payload.auth.user.id.clone(),
+    )
+    .await
+    .map_err(|_| response::Error::FailedToReorder)?
+    .ok_or(response::Error::OrderNotFound)?;
+
+    let reorderable = service::collect_reorderable_items(order);
+
+    if reorderable.items.is_empty() {
+        return Err(response::Error::NoItemsToReorder(reorderable.skipped));
+    }
+
+    let mut tx = ctx.db_conn.pool.begin().await.map_err(|err| {
+        tracing::error!("Failed to start database transaction: {}", err);
+        response::Error::FailedToReorder
+    })?;
+
+    let cart = match cart::repository::find_active_cart_by_owner_id(
+        &mut *tx,
+        payload.auth.user.id.clone(),
+    )
+    .await
+    .map_err(|_| response::Error::FailedToReorder)?
+    {
+        Some(cart) => cart,
+        None => cart::repository::create(
+            &mut *tx,
+            cart::repository::CreateCartPayload {
+                owner_id: payload.auth.user.id.clone(),
+            },
+        )
+        .await
+        .map_err(|_| response::Error::FailedToReorder)?,
+    };
+
+    let items = reorderable
+        .items
+        .into_iter()
+        .map(|item| CartItem {
+            meal_id: item.meal_id,
+            quantity: item.quantity,
+            price: Some(item.meal.price),
+        })
+        .collect::<Vec<_>>();
+
+    // NOTE: merged rather than replaced, whatever is already in the cart stays there
+    cart::repository::update_by_id(
+        &mut *tx,
+        cart.id.clone(),
+        cart::repository::UpdateCartPayload {
+            items: Some(cart::service::merge_items(
+                cart.items,
+                cart::repository::CartItems(items),
+            )),
+            status: None,
+        },
+    )
+    .await
+    .map_err(|_| response::Error::FailedToReorder)?;
+
+    tx.commit()
+        .await
+        .map_err(|err| {
+            tracing::error!("Failed to commit database transaction: {}", err);
+            response::Error::FailedToReorder
+        })
+        .map(|_| response::Success::CartRebuilt {
+            cart_id: cart.id,
+            skipped: reorderable.skipped,
+            price_changes: reorderable.price_changes,
+        })
+}
@@ -0,0 +1,77 @@
+pub mod request {
+    use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub id: String,
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    use crate::modules::order::service::{OrderItemPriceChange, SkippedOrderItem};
+
+    pub enum Success {
+        CartRebuilt {
+            cart_id: String,
+            skipped: Vec<SkippedOrderItem>,
+            price_changes: Vec<OrderItemPriceChange>,
+        },
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::CartRebuilt {
+                    cart_id,
+                    skipped,
+                    price_changes,
+                } => (
+                    StatusCode::OK,
+                    Json(json!({
+                        "message": "Order items added to cart",
+                        "cart_id": cart_id,
+                        "skipped": skipped,
+                        "price_changes": price_changes,
+                    })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        OrderNotFound,
+        NoItemsToReorder(Vec<SkippedOrderItem>),
+        FailedToReorder,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::OrderNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Order not found" })),
+                )
+                    .into_response(),
+                Self::NoItemsToReorder(skipped) => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({
+                        "error": "None of the meals in this order are available",
+                        "skipped": skipped,
+                    })),
+                )
+                    .into_response(),
+                Self::FailedToReorder => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to reorder" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -2,7 +2,10 @@ use super::types::{request, response};
 use crate::{
     modules::{
         kitchen, notification,
-        order::repository::{self, Order, OrderStatus},
+        order::{
+            self,
+            repository::{self, Order, OrderStatus},
+        },
         transaction, user, wallet,
     },
     types::Context,
@@ -15,12 +18,11 @@ async fn mark_order_as(
     tx: &mut Transaction<'_, Postgres>,
     order: &Order,
     status: repository::OrderStatus,
-) -> Result<(), response::Error> {
+) -> Result<Order, response::Error> {
     repository::update_order_status(&mut **tx, order.id.clone(), status)
         .await
         .map_err(|_| response::Error::FailedToUpdateOrderStatus)?
         .ok_or(response::Error::FailedToUpdateOrderStatus)
-        .map(|_| ())
 }
 
 async fn process_order_delivery_finances(
@@ -83,57 +85,9 @@ async fn process_order_cancellation_finances(
     tx: &mut Transaction<'_, Postgres>,
     order: &Order,
 ) -> Result<(), response::Error> {
-    let wallet = wallet::repository::find_by_owner_id(&mut **tx, order.owner_id.clone())
+    order::service::refund_order_payment(tx, order)
         .await
-        .map_err(|_| response::Error::FailedToUpdateOrderStatus)?
-        .ok_or(response::Error::FailedToUpdateOrderStatus)?;
-
-    let initial_order_payment_transaction =
-        transaction::repository::find_initial_order_payment_transaction_by_order_id(
-            &mut **tx,
-            order.id.clone(),
-        )
-        .await
-        .map_err(|_| response::Error::FailedToUpdateOrderStatus)?
-        .ok_or_else(||{
-            tracing::error!("Required a transaction for an order which doesn't have an initial payment transaction: {}", &order.id);
-            response::Error::FailedToUpdateOrderStatus
-        })?;
-
-    transaction::repository::create(
-        &mut **tx,
-        transaction::repository::CreatePayload::Wallet(
-            transaction::repository::CreateWalletTransactionPayload {
-                amount: initial_order_payment_transaction.amount.clone(),
-                direction: transaction::repository::TransactionDirection::Incoming,
-                note: Some(format!(
-                    "Payment refunded for order {} cancellation",
-                    order.id.clone()
-                )),
-                purpose: Some(transaction::repository::TransactionPurpose::Order(
-                    transaction::repository::TransactionPurposeOrder {
-                        order_id: order.id.clone(),
-                    },
-                )),
-                r#ref: Some(initial_order_payment_transaction.r#ref),
-                wallet_id: wallet.id.clone(),
-                user_id: order.owner_id.clone(),
-            },
-        ),
-    )
-    .await
-    .map_err(|_| response::Error::FailedToUpdateOrderStatus)?;
-
-    wallet::repository::update_by_id(
-        &mut **tx,
-        wallet.id,
-        wallet::repository::UpdateByIdPayload {
-            operation: wallet::repository::UpdateOperation::Credit,
-            amount: order.total.clone(),
-        },
-    )
-    .await
-    .map_err(|_| response::Error::FailedToUpdateOrderStatus)
+        .map_err(|_| response::Error::FailedToUpdateOrderStatus)
 }
 
 pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
@@ -151,10 +105,11 @@ pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::
 
     if as_kitchen {
         let kitchen =
-            kitchen::repository::find_by_owner_id(&ctx.db_conn.pool, payload.auth.user.id.clone())
+            kitchen::service::find_access_by_user_id(ctx.clone(), payload.auth.user.id.clone())
                 .await
                 .map_err(|_| response::Error::FailedToUpdateOrderStatus)?
-                .ok_or(response::Error::UserNotOwnKitchen)?;
+                .ok_or(response::Error::UserNotOwnKitchen)?
+                .kitchen;
 
         if kitchen.id != order.kitchen_id {
             return Err(response::Error::KitchenNotOwner);
@@ -191,12 +146,18 @@ pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::
                     .map_err(|_| response::Error::FailedToUpdateOrderStatus)?
                     .ok_or(response::Error::FailedToUpdateOrderStatus)?;
 
-                notification::service::send(
-                    ctx,
-                    notification::service::Notification::order_status_updated(order, order_owner),
-                    notification::service::Backend::Push,
+                notification::service::enqueue(
+                    &mut *tx,
+                    notification::service::Notification::order_status_updated(
+                        order,
+                        kitchen,
+                        order_owner,
+                    ),
+                    notification::service::Category::Orders,
+                    vec![notification::service::Backend::Push],
                 )
-                .await;
+                .await
+                .map_err(|_| response::Error::FailedToUpdateOrderStatus)?;
             }
             _ => {
                 return Err(response::Error::InvalidStatusTransitionForKitchen);
@@ -207,6 +168,11 @@ pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::
             return Err(response::Error::UserNotOwner);
         }
 
+        let kitchen = kitchen::repository::find_by_id(&mut *tx, order.kitchen_id.clone())
+            .await
+            .map_err(|_| response::Error::FailedToUpdateOrderStatus)?
+            .ok_or(response::Error::FailedToUpdateOrderStatus)?;
+
         let kitchen_owner =
             user::repository::find_by_kitchen_id(&mut *tx, order.kitchen_id.clone())
                 .await
@@ -216,28 +182,42 @@ pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::
         match (order.status.clone(), payload.body.status.clone()) {
             (repository::OrderStatus::InTransit, repository::OrderStatus::Delivered) => {
                 process_order_delivery_finances(&mut tx, &order).await;
-                mark_order_as(&mut tx, &order, repository::OrderStatus::Delivered).await?;
+                let order =
+                    mark_order_as(&mut tx, &order, repository::OrderStatus::Delivered).await?;
 
-                notification::service::send(
-                    ctx,
-                    notification::service::Notification::order_status_updated(order, kitchen_owner),
-                    notification::service::Backend::Push,
+                notification::service::enqueue(
+                    &mut *tx,
+                    notification::service::Notification::order_status_updated(
+                        order,
+                        kitchen,
+                        kitchen_owner,
+                    ),
+                    notification::service::Category::Orders,
+                    vec![notification::service::Backend::Push],
                 )
-                .await;
+                .await
+                .map_err(|_| response::Error::FailedToUpdateOrderStatus)?;
             }
             (
                 repository::OrderStatus::AwaitingAcknowledgement,
                 repository::OrderStatus::Cancelled,
             ) => {
                 process_order_cancellation_finances(&mut tx, &order).await?;
-                mark_order_as(&mut tx, &order, repository::OrderStatus::Cancelled).await?;
+                let order =
+                    mark_order_as(&mut tx, &order, repository::OrderStatus::Cancelled).await?;
 
-                notification::service::send(
-                    ctx,
-                    notification::service::Notification::order_status_updated(order, kitchen_owner),
-                    notification::service::Backend::Push,
+                notification::service::enqueue(
+                    &mut *tx,
+                    notification::service::Notification::order_status_updated(
+                        order,
+                        kitchen,
+                        kitchen_owner,
+                    ),
+                    notification::service::Category::Orders,
+                    vec![notification::service::Backend::Push],
                 )
-                .await;
+                .await
+                .map_err(|_| response::Error::FailedToUpdateOrderStatus)?;
             }
             _ => return Err(response::Error::InvalidStatusTransitionForUser),
         }
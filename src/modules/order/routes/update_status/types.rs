@@ -1,5 +1,5 @@
 pub mod request {
-    use crate::modules::{auth::middleware::Auth, order::repository};
+    use crate::modules::{auth::middleware::UserAuth as Auth, order::repository};
     use serde::Deserialize;
 
     #[derive(Deserialize)]
@@ -1,12 +1,14 @@
+use crate::modules::cart::repository::FullCartItem;
 use crate::modules::payment::service::PaymentDetails;
 use crate::modules::user::repository::User;
-use crate::modules::{payment, transaction, wallet};
+use crate::modules::{kitchen, meal, payment, transaction, wallet};
 use crate::types::Context;
 use bigdecimal::BigDecimal;
+use serde::Serialize;
 use sqlx::{Postgres, Transaction};
 use std::sync::Arc;
 
-use super::repository::{self, Order, OrderStatus};
+use super::repository::{self, FullOrder, Order, OrderStatus};
 
 pub enum Error {
     UnexpectedError,
@@ -142,3 +144,142 @@ pub async fn mark_order_as_delivered(
 
     Ok(())
 }
+
+// Credits the order's payment back to its owner's wallet
+pub async fn refund_order_payment(
+    tx: &mut Transaction<'_, Postgres>,
+    order: &Order,
+) -> Result<(), Error> {
+    let wallet = wallet::repository::find_by_owner_id(&mut **tx, order.owner_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?
+        .ok_or(Error::UnexpectedError)?;
+
+    let initial_order_payment_transaction =
+        transaction::repository::find_initial_order_payment_transaction_by_order_id(
+            &mut **tx,
+            order.id.clone(),
+        )
+        .await
+        .map_err(|_| Error::UnexpectedError)?
+        .ok_or_else(||{
+            tracing::error!("Required a transaction for an order which doesn't have an initial payment transaction: {}", &order.id);
+            Error::UnexpectedError
+        })?;
+
+    transaction::repository::create(
+        &mut **tx,
+        transaction::repository::CreatePayload::Wallet(
+            transaction::repository::CreateWalletTransactionPayload {
+                amount: initial_order_payment_transaction.amount.clone(),
+                direction: transaction::repository::TransactionDirection::Incoming,
+                note: Some(format!(
+                    "Payment refunded for order {} cancellation",
+                    order.id.clone()
+                )),
+                purpose: Some(transaction::repository::TransactionPurpose::Order(
+                    transaction::repository::TransactionPurposeOrder {
+                        order_id: order.id.clone(),
+                    },
+                )),
+                r#ref: Some(initial_order_payment_transaction.r#ref),
+                wallet_id: wallet.id.clone(),
+                user_id: order.owner_id.clone(),
+            },
+        ),
+    )
+    .await
+    .map_err(|_| Error::UnexpectedError)?;
+
+    wallet::repository::update_by_id(
+        &mut **tx,
+        wallet.id,
+        wallet::repository::UpdateByIdPayload {
+            operation: wallet::repository::UpdateOperation::Credit,
+            amount: order.total.clone(),
+        },
+    )
+    .await
+    .map_err(|_| Error::UnexpectedError)
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub enum SkippedOrderItemReason {
+    #[serde(rename = "MEAL_DELETED")]
+    MealDeleted,
+    #[serde(rename = "MEAL_UNAVAILABLE")]
+    MealUnavailable,
+    #[serde(rename = "KITCHEN_UNAVAILABLE")]
+    KitchenUnavailable,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct SkippedOrderItem {
+    pub meal_id: String,
+    pub name: String,
+    pub reason: SkippedOrderItemReason,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct OrderItemPriceChange {
+    pub meal_id: String,
+    pub name: String,
+    pub previous_price: BigDecimal,
+    pub current_price: BigDecimal,
+}
+
+pub struct ReorderableItems {
+    pub items: Vec<FullCartItem>,
+    pub skipped: Vec<SkippedOrderItem>,
+    pub price_changes: Vec<OrderItemPriceChange>,
+}
+
+pub fn collect_reorderable_items(order: FullOrder) -> ReorderableItems {
+    let mut reorderable = ReorderableItems {
+        items: vec![],
+        skipped: vec![],
+        price_changes: vec![],
+    };
+
+    let is_kitchen_accepting_orders = kitchen::repository::is_accepting_orders(&order.kitchen);
+
+    for item in order.items.0 {
+        let reason = if item.meal.deleted_at.is_some() {
+            Some(SkippedOrderItemReason::MealDeleted)
+        } else if !meal::repository::is_orderable(&item.meal) {
+            Some(SkippedOrderItemReason::MealUnavailable)
+        } else if !is_kitchen_accepting_orders {
+            Some(SkippedOrderItemReason::KitchenUnavailable)
+        } else {
+            None
+        };
+
+        if let Some(reason) = reason {
+            reorderable.skipped.push(SkippedOrderItem {
+                meal_id: item.meal_id,
+                name: item.meal.name,
+                reason,
+            });
+            continue;
+        }
+
+        if item.meal.price != item.price {
+            reorderable.price_changes.push(OrderItemPriceChange {
+                meal_id: item.meal_id.clone(),
+                name: item.meal.name.clone(),
+                previous_price: item.price,
+                current_price: item.meal.price.clone(),
+            });
+        }
+
+        reorderable.items.push(FullCartItem {
+            meal_id: item.meal_id,
+            quantity: item.quantity,
+            price: Some(item.meal.price.clone()),
+            meal: item.meal,
+            kitchen: order.kitchen.clone(),
+        });
+    }
+
+    reorderable
+}
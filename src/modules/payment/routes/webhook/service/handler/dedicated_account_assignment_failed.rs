@@ -20,12 +20,14 @@ pub async fn handler(
             response::Error::UserNotFound
         })?;
 
-    notification::service::send(
-        ctx.clone(),
+    notification::service::enqueue(
+        &ctx.db_conn.pool,
         notification::service::Notification::bank_account_creation_failed(user),
-        notification::service::Backend::Email,
+        notification::service::Category::Wallet,
+        vec![notification::service::Backend::Email],
     )
-    .await;
+    .await
+    .map_err(|_| response::Error::ServerError)?;
 
     Ok(response::Success::Successful)
 }
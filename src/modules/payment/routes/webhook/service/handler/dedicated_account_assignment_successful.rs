@@ -20,8 +20,13 @@ pub async fn handler(
             response::Error::UserNotFound
         })?;
 
+    let mut tx = ctx.db_conn.pool.begin().await.map_err(|err| {
+        tracing::error!("Failed to start database transaction: {}", err);
+        response::Error::ServerError
+    })?;
+
     wallet::repository::update_metatata_by_owner_id(
-        &ctx.db_conn.pool,
+        &mut *tx,
         user.id.clone(),
         wallet::repository::WalletMetadata {
             backend: Some(wallet::repository::WalletBackend::Paystack(
@@ -48,11 +53,19 @@ pub async fn handler(
     .await
     .map_err(|_| response::Error::ServerError)?;
 
-    tokio::spawn(notification::service::send(
-        ctx.clone(),
+    notification::service::enqueue(
+        &mut *tx,
         notification::service::Notification::bank_account_creation_successful(user),
-        notification::service::Backend::Email,
-    ));
+        notification::service::Category::Wallet,
+        vec![notification::service::Backend::Email],
+    )
+    .await
+    .map_err(|_| response::Error::ServerError)?;
+
+    tx.commit().await.map_err(|err| {
+        tracing::error!("Failed to commit database transaction: {}", err);
+        response::Error::ServerError
+    })?;
 
     Ok(response::Success::Successful)
 }
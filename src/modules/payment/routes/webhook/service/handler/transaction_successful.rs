@@ -1,5 +1,6 @@
 use super::super::super::types::{
-    response, Metadata, OrderInvoiceMetadata, TopupMetadata, TransactionSuccessful,
+    response, Metadata, OrderGroupInvoiceMetadata, OrderInvoiceMetadata, TopupMetadata,
+    TransactionSuccessful,
 };
 use crate::modules::{payment::service, wallet};
 use crate::{
@@ -49,7 +50,6 @@ async fn successful_order_payment(
     // };
 
     service::confirm_payment_for_order(
-        ctx.clone(),
         &mut tx,
         service::ConfirmPaymentForOrderPayload {
             order: order.clone(),
@@ -70,6 +70,63 @@ async fn successful_order_payment(
         })
 }
 
+async fn successful_order_group_payment(
+    ctx: Arc<Context>,
+    amount: BigDecimal,
+    metadata: OrderGroupInvoiceMetadata,
+) -> response::Response {
+    let mut tx = ctx.db_conn.pool.begin().await.map_err(|err| {
+        tracing::error!("Failed to start database transaction: {:?}", err);
+        response::Error::ServerError
+    })?;
+
+    let group = order::repository::find_group_by_id(&mut *tx, metadata.order_group_id.clone())
+        .await
+        .map_err(|_| response::Error::ServerError)?
+        .ok_or_else(|| {
+            tracing::error!(
+                "Order group not found for successful transaction: {}",
+                &metadata.order_group_id
+            );
+            response::Error::OrderNotFound
+        })?;
+
+    if group.status != order::repository::OrderGroupStatus::AwaitingPayment {
+        return Ok(response::Success::Successful);
+    }
+
+    if amount / BigDecimal::from(100) < group.total {
+        tracing::error!(
+            "Payload order group amount is less than order group total: {}",
+            &group.id
+        );
+        return Err(response::Error::InvalidPayload);
+    }
+
+    let orders = order::repository::find_many_by_group_id(&mut *tx, group.id.clone())
+        .await
+        .map_err(|_| response::Error::ServerError)?;
+
+    service::confirm_payment_for_order_group(
+        &mut tx,
+        service::ConfirmPaymentForOrderGroupPayload {
+            group: group.clone(),
+            orders,
+            payment_method: service::PaymentMethod::Online,
+        },
+    )
+    .await
+    .map_err(|_| response::Error::ServerError)?;
+
+    tx.commit()
+        .await
+        .map(|_| response::Success::Successful)
+        .map_err(|err| {
+            tracing::error!("Failed to commit database transaction: {:?}", err);
+            response::Error::ServerError
+        })
+}
+
 async fn successful_topup(
     ctx: Arc<Context>,
     amount: BigDecimal,
@@ -130,6 +187,9 @@ pub async fn handler(ctx: Arc<Context>, event: TransactionSuccessful) -> respons
         Metadata::Order(metadata) => {
             successful_order_payment(ctx.clone(), event.amount, metadata).await
         }
+        Metadata::OrderGroup(metadata) => {
+            successful_order_group_payment(ctx.clone(), event.amount, metadata).await
+        }
         Metadata::Topup(metadata) => successful_topup(ctx.clone(), event.amount, metadata).await,
     }
 }
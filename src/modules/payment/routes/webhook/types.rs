@@ -131,6 +131,11 @@ pub struct OrderInvoiceMetadata {
     pub order_id: String,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct OrderGroupInvoiceMetadata {
+    pub order_group_id: String,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct TopupMetadata {
     pub user_id: String,
@@ -140,6 +145,7 @@ pub struct TopupMetadata {
 #[serde(untagged)]
 pub enum Metadata {
     Order(OrderInvoiceMetadata),
+    OrderGroup(OrderGroupInvoiceMetadata),
     Topup(TopupMetadata),
 }
 
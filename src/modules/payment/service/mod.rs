@@ -1,6 +1,6 @@
 pub mod online;
 
-use crate::modules::order::repository::{Order, OrderStatus};
+use crate::modules::order::repository::{Order, OrderGroup, OrderStatus};
 use crate::modules::{kitchen, notification, order, transaction, user, wallet};
 use crate::{modules::user::repository::User, types::Context};
 use serde::Serialize;
@@ -16,6 +16,7 @@ pub enum Error {
     InsufficientBalance,
 }
 
+#[derive(Clone)]
 pub enum PaymentMethod {
     Wallet,
     Online,
@@ -50,7 +51,6 @@ pub async fn initialize_payment_for_order(
 
     match payload.method {
         PaymentMethod::Wallet => wallet::service::initialize_payment_for_order(
-            ctx,
             &mut tx,
             wallet::service::InitializePaymentForOrder {
                 order: payload.order,
@@ -76,13 +76,56 @@ pub async fn initialize_payment_for_order(
     }
 }
 
+pub struct InitializePaymentForOrderGroup {
+    pub method: PaymentMethod,
+    pub payer: User,
+    pub group: OrderGroup,
+    pub orders: Vec<Order>,
+}
+
+pub async fn initialize_payment_for_order_group(
+    ctx: Arc<Context>,
+    mut tx: &mut Transaction<'_, Postgres>,
+    payload: InitializePaymentForOrderGroup,
+) -> Result<PaymentDetails, Error> {
+    if payload.group.status != order::repository::OrderGroupStatus::AwaitingPayment {
+        return Err(Error::AlreadyPaid);
+    }
+
+    match payload.method {
+        PaymentMethod::Wallet => wallet::service::initialize_payment_for_order_group(
+            &mut tx,
+            wallet::service::InitializePaymentForOrderGroup {
+                group: payload.group,
+                orders: payload.orders,
+                payer: payload.payer,
+            },
+        )
+        .await
+        .map(|_| PaymentDetails(json!({ "message": "Payment successful" })))
+        .map_err(|err| match err {
+            wallet::service::Error::InsufficientBalance => Error::InsufficientBalance,
+            _ => Error::UnexpectedError,
+        }),
+        PaymentMethod::Online => online::initialize_invoice_for_order_group(
+            ctx,
+            online::InitializeInvoiceForOrderGroup {
+                group: payload.group,
+                payer: payload.payer,
+            },
+        )
+        .await
+        .map(|details| PaymentDetails(json!(details)))
+        .map_err(|_| Error::UnexpectedError),
+    }
+}
+
 pub struct ConfirmPaymentForOrderPayload {
     pub order: Order,
     pub payment_method: PaymentMethod,
 }
 
 pub async fn confirm_payment_for_order(
-    ctx: Arc<Context>,
     tx: &mut Transaction<'_, Postgres>,
     payload: ConfirmPaymentForOrderPayload,
 ) -> Result<(), Error> {
@@ -131,39 +174,125 @@ pub async fn confirm_payment_for_order(
     .await
     .map_err(|_| Error::UnexpectedError)?;
 
+    // NOTE: the confirmation only moves the order forward, no need to fetch it again to tell everyone about it
+    let order = Order {
+        status: OrderStatus::AwaitingAcknowledgement,
+        ..payload.order.clone()
+    };
+
     let kitchen = kitchen::repository::find_by_id(&mut **tx, payload.order.kitchen_id.clone())
         .await
         .map_err(|_| Error::UnexpectedError)?
         .ok_or(Error::UnexpectedError)?;
 
-    let kitchen_owner = user::repository::find_by_id(&mut **tx, kitchen.owner_id)
+    let kitchen_owner = user::repository::find_by_id(&mut **tx, kitchen.owner_id.clone())
         .await
         .map_err(|_| Error::UnexpectedError)?
         .ok_or(Error::UnexpectedError)?;
 
-    tokio::spawn(notification::service::send(
-        ctx.clone(),
+    notification::service::enqueue(
+        &mut **tx,
         notification::service::Notification::order_status_updated(
-            payload.order.clone(),
-            kitchen_owner,
+            order.clone(),
+            kitchen.clone(),
+            kitchen_owner.clone(),
         ),
-        notification::service::Backend::Push,
-    ));
+        notification::service::Category::Orders,
+        vec![notification::service::Backend::Push],
+    )
+    .await
+    .map_err(|_| Error::UnexpectedError)?;
 
     let admin_users = user::repository::find_all_admins(&mut **tx)
         .await
         .map_err(|_| Error::UnexpectedError)?;
 
     for admin_user in admin_users {
-        tokio::spawn(notification::service::send(
-            ctx.clone(),
+        notification::service::enqueue(
+            &mut **tx,
             notification::service::Notification::order_status_updated(
-                payload.order.clone(),
+                order.clone(),
+                kitchen.clone(),
                 admin_user,
             ),
-            notification::service::Backend::Push,
-        ));
+            notification::service::Category::Orders,
+            vec![notification::service::Backend::Push],
+        )
+        .await
+        .map_err(|_| Error::UnexpectedError)?;
+    }
+
+    // NOTE: the receipt and the summary list the meals by name, which only the full order has
+    let full_order = order::repository::find_full_order_by_id(&mut **tx, order.id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?
+        .ok_or(Error::UnexpectedError)?;
+
+    let order_owner = user::repository::find_by_id(&mut **tx, order.owner_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?
+        .ok_or(Error::UnexpectedError)?;
+
+    notification::service::enqueue(
+        &mut **tx,
+        notification::service::Notification::order_receipt(full_order.clone(), order_owner),
+        notification::service::Category::Transactional,
+        vec![notification::service::Backend::Email],
+    )
+    .await
+    .map_err(|_| Error::UnexpectedError)?;
+
+    notification::service::enqueue(
+        &mut **tx,
+        notification::service::Notification::kitchen_order_summary(full_order, kitchen_owner),
+        notification::service::Category::Orders,
+        vec![notification::service::Backend::Email],
+    )
+    .await
+    .map_err(|_| Error::UnexpectedError)?;
+
+    Ok(())
+}
+
+pub struct ConfirmPaymentForOrderGroupPayload {
+    pub group: OrderGroup,
+    pub orders: Vec<Order>,
+    pub payment_method: PaymentMethod,
+}
+
+pub async fn confirm_payment_for_order_group(
+    tx: &mut Transaction<'_, Postgres>,
+    payload: ConfirmPaymentForOrderGroupPayload,
+) -> Result<(), Error> {
+    for order in payload.orders {
+        if order.status != OrderStatus::AwaitingPayment {
+            continue;
+        }
+
+        confirm_payment_for_order(
+            tx,
+            ConfirmPaymentForOrderPayload {
+                order,
+                payment_method: payload.payment_method.clone(),
+            },
+        )
+        .await?;
     }
 
+    order::repository::confirm_group_payment(
+        &mut **tx,
+        order::repository::ConfirmGroupPaymentPayload {
+            group_id: payload.group.id.clone(),
+            payment_method: payload.payment_method.into(),
+        },
+    )
+    .await
+    .map_err(|_| Error::UnexpectedError)?;
+
+    tracing::info!(
+        "Transaction successful for order group {}",
+        payload.group.id.clone()
+    );
+
     Ok(())
 }
@@ -6,7 +6,12 @@ use serde_json::json;
 use sqlx::Postgres;
 
 use crate::{
-    modules::{order::repository::Order, payment::utils, transaction, user::repository::User},
+    modules::{
+        order::repository::{Order, OrderGroup},
+        payment::utils,
+        transaction,
+        user::repository::User,
+    },
     types::Context,
 };
 use std::sync::Arc;
@@ -20,6 +25,11 @@ pub struct OrderInvoiceMetadata {
     pub order_id: String,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct OrderGroupInvoiceMetadata {
+    pub order_group_id: String,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct TopupMetadata {
     pub user_id: String,
@@ -29,6 +39,7 @@ pub struct TopupMetadata {
 #[serde(untagged)]
 pub enum Metadata {
     Order(OrderInvoiceMetadata),
+    OrderGroup(OrderGroupInvoiceMetadata),
     Topup(TopupMetadata),
 }
 
@@ -118,6 +129,31 @@ pub async fn initialize_invoice_for_order(
     Ok(json!({ "url": payment_link }))
 }
 
+pub struct InitializeInvoiceForOrderGroup {
+    pub group: OrderGroup,
+    pub payer: User,
+}
+
+pub async fn initialize_invoice_for_order_group(
+    ctx: Arc<Context>,
+    payload: InitializeInvoiceForOrderGroup,
+) -> Result<serde_json::Value, Error> {
+    let metadata = OrderGroupInvoiceMetadata {
+        order_group_id: payload.group.id.clone(),
+    };
+
+    let payload = json!({
+        "email": payload.payer.email,
+        "amount": payload.group.total * BigDecimal::from(100),
+        "metadata": metadata,
+    })
+    .to_string();
+
+    let payment_link = create_paystack_invoice(ctx, payload).await?;
+
+    Ok(json!({ "url": payment_link }))
+}
+
 pub struct CreateTopupInvoicePayload {
     pub amount: BigDecimal,
     pub user: User,
@@ -5,7 +5,7 @@ use super::{
     ad, auth, cart, dashboard, dev, kitchen, meal, media, notification, order, payment, search,
     transaction, user, wallet, zoho,
 };
-use crate::types::Context;
+use crate::types::{rate_limit, Context};
 use std::sync::Arc;
 
 async fn health_check() -> impl IntoResponse {
@@ -27,11 +27,17 @@ pub fn get_router() -> Router<Arc<Context>> {
         .nest("/meals", meal::get_router())
         .nest("/carts", cart::get_router())
         .nest("/orders", order::get_router())
-        .nest("/payment", payment::get_router())
         .nest("/notifications", notification::get_router())
         .nest("/dashboard", dashboard::get_router())
         .nest("/wallets", wallet::get_router())
         .nest("/transactions", transaction::get_router())
         .nest("/search", search::get_router())
         .nest("/zoho", zoho::get_router())
+        .layer(axum::middleware::from_fn_with_state(
+            rate_limit::GROUP_API,
+            auth::middleware::rate_limit,
+        ))
+        // NOTE: outside of the rate limit, paystack retries its webhooks from a handful of shared ips and a 429 there
+        // would lose a payment confirmation
+        .nest("/payment", payment::get_router())
 }
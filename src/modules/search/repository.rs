@@ -1,10 +1,16 @@
-use crate::define_paginated;
 use crate::modules::kitchen::repository::Kitchen;
 use crate::modules::meal::repository::Meal;
+use crate::utils::database::pagination::{Item, Meta};
 use crate::utils::pagination::Pagination;
+use bigdecimal::BigDecimal;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use sqlx::PgExecutor;
 
+// NOTE: kitchens that have not set a delivery radius are assumed to deliver this far (in km)
+pub const DEFAULT_DELIVERY_RADIUS: f64 = 10.0;
+pub const RELEVANCE_THRESHOLD: f64 = 0.1;
+
 #[derive(Deserialize, Serialize, Debug)]
 #[serde(untagged)]
 pub enum MealOrKitchen {
@@ -12,85 +18,246 @@ pub enum MealOrKitchen {
     Kitchen(Kitchen),
 }
 
-define_paginated!(DatabasePaginatedMealOrKitchen, MealOrKitchen);
+#[derive(Deserialize, Serialize, Debug)]
+pub struct KitchenTypeFacet {
+    pub r#type: String,
+    pub count: i64,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct CityFacet {
+    pub id: String,
+    pub name: String,
+    pub count: i64,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct Facets {
+    pub kitchen_types: Vec<KitchenTypeFacet>,
+    pub cities: Vec<CityFacet>,
+}
+
+impl From<Option<Value>> for Facets {
+    fn from(option: Option<Value>) -> Self {
+        // NOTE: facets only refine the results, a search shouldn't fail (or panic) because of them
+        option
+            .map(|value| {
+                serde_json::from_value(value).unwrap_or_else(|err| {
+                    tracing::error!("Invalid facets found: {}", err);
+                    Facets::default()
+                })
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SearchResults {
+    pub items: Item<MealOrKitchen>,
+    pub meta: Meta,
+    pub facets: Facets,
+}
 
 #[derive(Deserialize)]
 pub struct FindManyMealsAndKitchenFilters {
+    #[serde(default)]
     pub search: String,
+    pub kitchen_type: Option<String>,
+    pub city_id: Option<String>,
+    pub min_price: Option<BigDecimal>,
+    pub max_price: Option<BigDecimal>,
+    pub min_rating: Option<BigDecimal>,
+    pub open_now: Option<bool>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub delivers_to_me: Option<bool>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct Suggestion {
+    pub text: String,
+    pub r#type: String,
 }
 
 pub enum Error {
     UnexpectedError,
 }
 
+// NOTE: results are ranked by a weighted mix of text relevance (0.55), rating (0.2), likes (0.15) and distance (0.1)
 pub async fn find_many_meals_and_kitchens<'e, E: PgExecutor<'e>>(
     e: E,
     filters: FindManyMealsAndKitchenFilters,
     pagination: Pagination,
-) -> Result<DatabasePaginatedMealOrKitchen, Error> {
+) -> Result<SearchResults, Error> {
     sqlx::query_as!(
-        DatabasePaginatedMealOrKitchen,
-        "
-            WITH ranked_results AS (
-                SELECT 
+        SearchResults,
+        r#"
+            WITH search_query AS (
+                SELECT
+                    $3::TEXT AS text,
+                    WEBSEARCH_TO_TSQUERY('english', $3) AS tsquery
+            ),
+            located_kitchens AS (
+                SELECT
+                    kitchens.*,
+                    kitchen_cities.name AS city_name,
+                    TO_JSONB(kitchen_cities) AS city,
+                    CASE
+                        WHEN $10::FLOAT8 IS NULL
+                            OR $11::FLOAT8 IS NULL
+                            OR kitchens.latitude IS NULL
+                            OR kitchens.longitude IS NULL
+                        THEN NULL
+                        ELSE 6371 * 2 * ASIN(SQRT(
+                            POWER(SIN(RADIANS(kitchens.latitude - $10) / 2), 2)
+                            + COS(RADIANS($10))
+                            * COS(RADIANS(kitchens.latitude))
+                            * POWER(SIN(RADIANS(kitchens.longitude - $11) / 2), 2)
+                        ))
+                    END AS distance,
+                    CASE
+                        WHEN kitchens.opening_time::TIME <= kitchens.closing_time::TIME
+                        THEN (NOW() AT TIME ZONE 'Africa/Lagos')::TIME >= kitchens.opening_time::TIME
+                            AND (NOW() AT TIME ZONE 'Africa/Lagos')::TIME < kitchens.closing_time::TIME
+                        ELSE (NOW() AT TIME ZONE 'Africa/Lagos')::TIME >= kitchens.opening_time::TIME
+                            OR (NOW() AT TIME ZONE 'Africa/Lagos')::TIME < kitchens.closing_time::TIME
+                    END AS is_open
+                FROM
+                    kitchens
+                INNER JOIN kitchen_cities
+                ON
+                    kitchens.city_id = kitchen_cities.id
+                WHERE
+                    kitchens.is_available = TRUE
+                    AND kitchens.is_blocked = FALSE
+                    AND kitchens.is_verified = TRUE
+                    AND ($4::VARCHAR IS NULL OR kitchens.type = $4)
+                    AND ($5::VARCHAR IS NULL OR kitchens.city_id = $5)
+            ),
+            eligible_kitchens AS (
+                SELECT
+                    *
+                FROM
+                    located_kitchens
+                WHERE
+                    ($7::BOOLEAN IS NOT TRUE OR located_kitchens.is_open)
+                    AND (
+                        $12::BOOLEAN IS NOT TRUE
+                        OR located_kitchens.distance <= COALESCE(located_kitchens.delivery_radius, $14)
+                    )
+            ),
+            ranked_results AS (
+                SELECT
                     meals.name,
                     'meal' AS type,
-                    SIMILARITY(meals.name, $3) AS rank_score,
-                    TO_JSONB(meals) || JSONB_BUILD_OBJECT('kitchen', kitchens) AS item
+                    eligible_kitchens.type AS kitchen_type,
+                    eligible_kitchens.city_id,
+                    eligible_kitchens.city_name,
+                    meals.rating,
+                    meals.likes,
+                    eligible_kitchens.distance,
+                    GREATEST(
+                        SIMILARITY(meals.name, search_query.text),
+                        WORD_SIMILARITY(search_query.text, meals.description) * 0.8,
+                        TS_RANK(
+                            TO_TSVECTOR('english', meals.name || ' ' || meals.description),
+                            search_query.tsquery
+                        ),
+                        SIMILARITY(eligible_kitchens.type, search_query.text) * 0.6,
+                        SIMILARITY(eligible_kitchens.city_name, search_query.text) * 0.6
+                    ) AS text_score,
+                    TO_JSONB(meals) || JSONB_BUILD_OBJECT('kitchen', eligible_kitchens) AS item
                 FROM
                     meals
-                INNER JOIN kitchens
+                INNER JOIN eligible_kitchens
                 ON
-                     meals.kitchen_id = kitchens.id
+                    meals.kitchen_id = eligible_kitchens.id,
+                    search_query
+                WHERE
+                    meals.is_available = TRUE
+                    AND meals.deleted_at IS NULL
+                    AND ($8::NUMERIC IS NULL OR meals.price >= $8)
+                    AND ($9::NUMERIC IS NULL OR meals.price <= $9)
                 UNION ALL
-                SELECT 
-                    kitchens.name,
+                SELECT
+                    eligible_kitchens.name,
                     'kitchen' AS type,
-                    SIMILARITY(kitchens.name, $3) AS rank_score,
-                    TO_JSONB(kitchens) || JSONB_BUILD_OBJECT(
-                        'city', kitchen_cities
-                    ) AS item
+                    eligible_kitchens.type AS kitchen_type,
+                    eligible_kitchens.city_id,
+                    eligible_kitchens.city_name,
+                    eligible_kitchens.rating,
+                    eligible_kitchens.likes,
+                    eligible_kitchens.distance,
+                    GREATEST(
+                        SIMILARITY(eligible_kitchens.name, search_query.text),
+                        SIMILARITY(eligible_kitchens.type, search_query.text) * 0.8,
+                        SIMILARITY(eligible_kitchens.city_name, search_query.text) * 0.8
+                    ) AS text_score,
+                    TO_JSONB(eligible_kitchens) AS item
                 FROM
-                    kitchens
-                INNER JOIN kitchen_cities
-                ON
-                     kitchens.city_id = kitchen_cities.id
+                    eligible_kitchens,
+                    search_query
+                WHERE
+                    ($8::NUMERIC IS NULL AND $9::NUMERIC IS NULL)
+                    OR EXISTS (
+                        SELECT
+                            1
+                        FROM
+                            meals
+                        WHERE
+                            meals.kitchen_id = eligible_kitchens.id
+                            AND meals.is_available = TRUE
+                            AND meals.deleted_at IS NULL
+                            AND ($8::NUMERIC IS NULL OR meals.price >= $8)
+                            AND ($9::NUMERIC IS NULL OR meals.price <= $9)
+                    )
             ),
             filtered_results AS (
                 SELECT
-                    *
+                    *,
+                    (
+                        0.55 * ranked_results.text_score
+                        + 0.2 * (ranked_results.rating::FLOAT8 / 5)
+                        + 0.15 * LEAST(LN(1 + GREATEST(ranked_results.likes, 0)) / LN(1001), 1)
+                        + 0.1 * COALESCE(1 / (1 + ranked_results.distance), 0)
+                    ) AS rank_score
                 FROM
                     ranked_results
                 WHERE
-                    rank_score > 0.1
-                    AND (
-                        type = 'kitchen'
-                        AND (
-                            item->'is_available' = TO_JSONB(TRUE)
-                            AND item->'is_blocked' = TO_JSONB(FALSE)
-                            AND item->'is_verified' = TO_JSONB(TRUE)
-                        )
-                        OR (
-                            type = 'meal'
-                            AND (
-                                item->'kitchen'->'is_available' = TO_JSONB(TRUE)
-                                AND item->'kitchen'->'is_blocked' = TO_JSONB(FALSE)
-                                AND item->'kitchen'->'is_verified' = TO_JSONB(TRUE)
-                            )
-                        )
-                    )
-                ORDER BY
-                    rank_score DESC,
-                    name
+                    ($3 = '' OR ranked_results.text_score > $13)
+                    AND ($6::NUMERIC IS NULL OR ranked_results.rating >= $6)
             ),
             total_count AS (
                 SELECT COUNT(name) AS total_rows FROM filtered_results
             ),
+            kitchen_type_facets AS (
+                SELECT
+                    kitchen_type AS type,
+                    COUNT(*) AS count
+                FROM
+                    filtered_results
+                GROUP BY
+                    kitchen_type
+            ),
+            city_facets AS (
+                SELECT
+                    city_id AS id,
+                    city_name AS name,
+                    COUNT(*) AS count
+                FROM
+                    filtered_results
+                GROUP BY
+                    city_id,
+                    city_name
+            ),
             truncated_results AS (
                 SELECT
                     item
                 FROM
                     filtered_results
+                ORDER BY
+                    rank_score DESC,
+                    name
                 LIMIT $2
                 OFFSET ($1 - 1) * $2
             )
@@ -100,13 +267,34 @@ pub async fn find_many_meals_and_kitchens<'e, E: PgExecutor<'e>>(
                     'page', $1,
                     'per_page', $2,
                     'total', (SELECT total_rows FROM total_count)
-                ) AS meta
+                ) AS meta,
+                JSONB_BUILD_OBJECT(
+                    'kitchen_types', COALESCE(
+                        (SELECT JSONB_AGG(kitchen_type_facets ORDER BY count DESC) FROM kitchen_type_facets),
+                        '[]'::JSONB
+                    ),
+                    'cities', COALESCE(
+                        (SELECT JSONB_AGG(city_facets ORDER BY count DESC) FROM city_facets),
+                        '[]'::JSONB
+                    )
+                ) AS facets
             FROM
                 truncated_results
-        ",
+        "#,
         pagination.page as i32,
         pagination.per_page as i32,
-        filters.search
+        filters.search,
+        filters.kitchen_type,
+        filters.city_id,
+        filters.min_rating,
+        filters.open_now,
+        filters.min_price,
+        filters.max_price,
+        filters.latitude,
+        filters.longitude,
+        filters.delivers_to_me,
+        RELEVANCE_THRESHOLD,
+        DEFAULT_DELIVERY_RADIUS,
     )
     .fetch_one(e)
     .await
@@ -118,3 +306,104 @@ pub async fn find_many_meals_and_kitchens<'e, E: PgExecutor<'e>>(
         Error::UnexpectedError
     })
 }
+
+pub async fn find_many_suggestions<'e, E: PgExecutor<'e>>(
+    e: E,
+    prefix: String,
+    limit: i64,
+) -> Result<Vec<Suggestion>, Error> {
+    let pattern = format!(
+        "{}%",
+        prefix
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_")
+    );
+
+    sqlx::query_as!(
+        Suggestion,
+        r#"
+            WITH available_kitchens AS (
+                SELECT
+                    kitchens.*,
+                    kitchen_cities.name AS city_name
+                FROM
+                    kitchens
+                INNER JOIN kitchen_cities
+                ON
+                    kitchens.city_id = kitchen_cities.id
+                WHERE
+                    kitchens.is_available = TRUE
+                    AND kitchens.is_blocked = FALSE
+                    AND kitchens.is_verified = TRUE
+                    AND kitchen_cities.is_deleted = FALSE
+            ),
+            candidates AS (
+                SELECT
+                    meals.name AS text,
+                    'meal' AS type,
+                    meals.likes AS popularity
+                FROM
+                    meals
+                INNER JOIN available_kitchens
+                ON
+                    meals.kitchen_id = available_kitchens.id
+                WHERE
+                    meals.is_available = TRUE
+                    AND meals.deleted_at IS NULL
+                    AND (meals.name ILIKE $1 OR meals.name ILIKE '% ' || $1)
+                UNION ALL
+                SELECT
+                    available_kitchens.name AS text,
+                    'kitchen' AS type,
+                    available_kitchens.likes AS popularity
+                FROM
+                    available_kitchens
+                WHERE
+                    available_kitchens.name ILIKE $1
+                    OR available_kitchens.name ILIKE '% ' || $1
+                UNION ALL
+                SELECT
+                    available_kitchens.type AS text,
+                    'kitchen_type' AS type,
+                    0 AS popularity
+                FROM
+                    available_kitchens
+                WHERE
+                    available_kitchens.type ILIKE $1
+                UNION ALL
+                SELECT
+                    available_kitchens.city_name AS text,
+                    'city' AS type,
+                    0 AS popularity
+                FROM
+                    available_kitchens
+                WHERE
+                    available_kitchens.city_name ILIKE $1
+            )
+            SELECT
+                text AS "text!",
+                type AS "type!"
+            FROM
+                candidates
+            GROUP BY
+                text,
+                type
+            ORDER BY
+                MAX(popularity) DESC,
+                text
+            LIMIT $2
+        "#,
+        pattern,
+        limit
+    )
+    .fetch_all(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch search suggestions: {}",
+            err
+        );
+        Error::UnexpectedError
+    })
+}
@@ -1,4 +1,5 @@
 mod search;
+mod suggestions;
 
 use crate::types::Context;
 use axum::routing::Router;
@@ -7,4 +8,5 @@ use std::sync::Arc;
 pub fn get_router() -> Router<Arc<Context>> {
     Router::new()
         .nest("/", search::get_router())
+        .nest("/", suggestions::get_router())
 }
@@ -6,6 +6,12 @@ use crate::types::Context;
 use std::sync::Arc;
 
 pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    if payload.filters.delivers_to_me == Some(true)
+        && (payload.filters.latitude.is_none() || payload.filters.longitude.is_none())
+    {
+        return Err(response::Error::LocationRequired);
+    }
+
     repository::find_many_meals_and_kitchens(&ctx.db_conn.pool, payload.filters, payload.pagination)
         .await
         .map_err(|_| response::Error::SearchFailed)
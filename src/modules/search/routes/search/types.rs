@@ -9,12 +9,12 @@ pub mod request {
 }
 
 pub mod response {
-    pub use super::super::super::super::repository::DatabasePaginatedMealOrKitchen;
+    pub use super::super::super::super::repository::SearchResults;
     use axum::{extract::Json, http::StatusCode, response::IntoResponse};
     use serde_json::json;
 
     pub enum Success {
-        Result(DatabasePaginatedMealOrKitchen),
+        Result(SearchResults),
     }
 
     impl IntoResponse for Success {
@@ -26,12 +26,20 @@ pub mod response {
     }
 
     pub enum Error {
+        LocationRequired,
         SearchFailed,
     }
 
     impl IntoResponse for Error {
         fn into_response(self) -> axum::response::Response {
             match self {
+                Self::LocationRequired => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({
+                        "error": "latitude and longitude are required to filter by delivery area"
+                    })),
+                )
+                    .into_response(),
                 Self::SearchFailed => (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     Json(json!({ "error": "Search failed" })),
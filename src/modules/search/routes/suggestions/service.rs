@@ -0,0 +1,27 @@
+use super::{
+    super::super::repository,
+    types::{request, response},
+};
+use crate::types::Context;
+use std::sync::Arc;
+
+const DEFAULT_LIMIT: i64 = 10;
+const MAX_LIMIT: i64 = 20;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let prefix = payload.query.q.trim().to_string();
+    if prefix.is_empty() {
+        return Ok(response::Success::Suggestions(vec![]));
+    }
+
+    let limit = payload
+        .query
+        .limit
+        .unwrap_or(DEFAULT_LIMIT)
+        .clamp(1, MAX_LIMIT);
+
+    repository::find_many_suggestions(&ctx.db_conn.pool, prefix, limit)
+        .await
+        .map_err(|_| response::Error::FailedToFetchSuggestions)
+        .map(response::Success::Suggestions)
+}
@@ -0,0 +1,52 @@
+pub mod request {
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    pub struct Query {
+        #[serde(default)]
+        pub q: String,
+        pub limit: Option<i64>,
+    }
+
+    pub struct Payload {
+        pub query: Query,
+    }
+}
+
+pub mod response {
+    pub use super::super::super::super::repository::Suggestion;
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        Suggestions(Vec<Suggestion>),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::Suggestions(suggestions) => {
+                    (StatusCode::OK, Json(json!(suggestions))).into_response()
+                }
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToFetchSuggestions,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToFetchSuggestions => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch suggestions" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -0,0 +1,217 @@
+use super::{Error, Result, StorageBackend, StoredMedia, UploadedMedia};
+use async_trait::async_trait;
+use reqwest::{
+    multipart::{Form, Part},
+    Client, StatusCode,
+};
+use serde::Deserialize;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use ulid::Ulid;
+
+#[derive(Deserialize)]
+struct UploadResponse {
+    secure_url: String,
+    public_id: String,
+}
+
+pub struct CloudinaryStorageBackendConfig {
+    pub api_key: String,
+    pub api_secret: String,
+    pub upload_endpoint: String,
+    pub delete_endpoint: String,
+    pub upload_preset: String,
+}
+
+pub struct CloudinaryStorageBackend {
+    client: Client,
+    api_key: String,
+    api_secret: String,
+    upload_endpoint: String,
+    delete_endpoint: String,
+    upload_preset: String,
+    delivery_url: String,
+}
+
+fn sign(data_to_sign: String) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(data_to_sign);
+    let hash = hasher.finalize();
+    base16ct::lower::encode_string(&hash)
+}
+
+impl CloudinaryStorageBackend {
+    pub fn new(config: CloudinaryStorageBackendConfig) -> Self {
+        // NOTE: https://api.cloudinary.com/v1_1/<cloud>/image/upload is delivered from
+        // https://res.cloudinary.com/<cloud>/image/upload/<public_id>
+        let delivery_url =
+            config
+                .upload_endpoint
+                .replacen("api.cloudinary.com/v1_1", "res.cloudinary.com", 1);
+
+        Self {
+            client: Client::new(),
+            api_key: config.api_key,
+            api_secret: config.api_secret,
+            upload_endpoint: config.upload_endpoint,
+            delete_endpoint: config.delete_endpoint,
+            upload_preset: config.upload_preset,
+            delivery_url,
+        }
+    }
+
+    async fn send_upload(&self, form: Form, timestamp: i64) -> Result<UploadedMedia> {
+        let res = self
+            .client
+            .post(self.upload_endpoint.clone())
+            .multipart(form)
+            .send()
+            .await
+            .map_err(|err| {
+                tracing::error!("Error occurred while trying to upload a file: {:?}", err);
+                Error::UploadFailed
+            })?;
+
+        let response_status_code = res.status();
+        if response_status_code != StatusCode::OK {
+            let data = res.text().await.map_err(|err| {
+                tracing::error!("Error occurred while processing return data: {:?}", err);
+                Error::UploadFailed
+            })?;
+
+            tracing::error!(
+                "Failed to upload file, non OK status {}: {}",
+                response_status_code,
+                data
+            );
+            return Err(Error::UploadFailed);
+        }
+
+        let data = res.text().await.map_err(|err| {
+            tracing::error!("Error occurred while processing return data: {:?}", err);
+            Error::UploadFailed
+        })?;
+
+        serde_json::de::from_str::<UploadResponse>(data.as_ref())
+            .map(|res| UploadedMedia {
+                url: res.secure_url,
+                public_id: res.public_id,
+                timestamp,
+                sizes: None,
+            })
+            .map_err(|err| {
+                tracing::error!("Failed to deserialize cloudinary response: {:?}", err);
+                Error::UploadFailed
+            })
+    }
+}
+
+#[async_trait]
+impl StorageBackend for CloudinaryStorageBackend {
+    async fn upload(&self, contents: Vec<u8>) -> Result<UploadedMedia> {
+        let file_name = Ulid::new().to_string();
+        let part = Part::bytes(contents).file_name(file_name);
+
+        let timestamp = chrono::Utc::now().timestamp();
+        let signature = sign(format!(
+            "timestamp={}&upload_preset={}{}",
+            timestamp, self.upload_preset, self.api_secret
+        ));
+
+        let form = Form::new()
+            .text("upload_preset", self.upload_preset.clone())
+            .text("api_key", self.api_key.clone())
+            .text("timestamp", format!("{}", timestamp))
+            .text("signature", signature)
+            .text("signature_algorithm", "sha256")
+            .part("file", part);
+
+        self.send_upload(form, timestamp).await
+    }
+
+    // cloudinary overwrites the file in place, the new url only differs in its version
+    async fn update(&self, media: UploadedMedia, contents: Vec<u8>) -> Result<UploadedMedia> {
+        let file_name = Ulid::new().to_string();
+        let part = Part::bytes(contents).file_name(file_name);
+
+        let timestamp = chrono::Utc::now().timestamp();
+        let signature = sign(format!(
+            "public_id={}&timestamp={}{}",
+            media.public_id, timestamp, self.api_secret
+        ));
+
+        let form = Form::new()
+            .text("api_key", self.api_key.clone())
+            .text("public_id", media.public_id.clone())
+            .text("timestamp", format!("{}", timestamp))
+            .text("signature", signature)
+            .text("signature_algorithm", "sha256")
+            .part("file", part);
+
+        self.send_upload(form, timestamp).await
+    }
+
+    async fn delete(&self, public_id: &str) -> Result<()> {
+        // NOTE: cloudinary rejects signatures older than an hour, so it can't be the upload's timestamp
+        let timestamp = chrono::Utc::now().timestamp();
+        let signature = sign(format!(
+            "public_id={}&timestamp={}{}",
+            public_id, timestamp, self.api_secret
+        ));
+
+        let body = json!({
+            "public_id": public_id,
+            "api_key": self.api_key,
+            "signature": signature,
+            "signature_algorithm": "sha256",
+            "timestamp": timestamp,
+        })
+        .to_string();
+
+        let res = self
+            .client
+            .post(self.delete_endpoint.clone())
+            .header("Content-Type", "application/json")
+            .body(body)
+            .send()
+            .await
+            .map_err(|err| {
+                tracing::error!("Failed to delete file {}: {:?}", public_id, err);
+                Error::DeleteFailed
+            })?;
+
+        if res.status() != StatusCode::OK {
+            let data = res.text().await.map_err(|err| {
+                tracing::error!(
+                    "Failed to process delete file response {}: {:?}",
+                    public_id,
+                    err
+                );
+                Error::DeleteFailed
+            })?;
+
+            tracing::error!("Failed to delete uploaded file: {}", data);
+            return Err(Error::DeleteFailed);
+        }
+
+        let data = res.text().await.map_err(|err| {
+            tracing::error!(
+                "Failed to process delete file response {}: {:?}",
+                public_id,
+                err
+            );
+            Error::DeleteFailed
+        })?;
+
+        tracing::debug!("Delete file response: {}", data);
+
+        Ok(())
+    }
+
+    async fn get(&self, public_id: &str) -> Result<StoredMedia> {
+        Ok(StoredMedia::Url(format!(
+            "{}/{}",
+            self.delivery_url, public_id
+        )))
+    }
+}
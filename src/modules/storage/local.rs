@@ -0,0 +1,87 @@
+use super::{Error, Result, StorageBackend, StoredMedia, UploadedMedia};
+use async_trait::async_trait;
+use std::path::PathBuf;
+use ulid::Ulid;
+
+// Keeps files in `directory` and serves them through the media routes, for development only since the disk doesn't
+// survive a restart of the machine
+pub struct LocalStorageBackend {
+    directory: PathBuf,
+    url: String,
+}
+
+impl LocalStorageBackend {
+    // `url` is where the media routes are mounted, e.g http://localhost:8000/api/media
+    pub fn new(directory: String, url: String) -> Self {
+        let directory = PathBuf::from(directory);
+
+        std::fs::create_dir_all(&directory).unwrap_or_else(|err| {
+            tracing::error!("{}", err);
+            panic!("Failed to create storage directory {}", directory.display())
+        });
+
+        Self { directory, url }
+    }
+
+    // NOTE: every file is named with a ulid, anything else could point outside of the directory
+    fn get_path(&self, public_id: &str) -> Result<PathBuf> {
+        Ulid::from_string(public_id)
+            .map(|_| self.directory.join(public_id))
+            .map_err(|_| Error::NotFound)
+    }
+}
+
+#[async_trait]
+impl StorageBackend for LocalStorageBackend {
+    async fn upload(&self, contents: Vec<u8>) -> Result<UploadedMedia> {
+        let public_id = Ulid::new().to_string();
+        let path = self.directory.join(&public_id);
+
+        tokio::fs::write(&path, contents).await.map_err(|err| {
+            tracing::error!("Failed to write file to {}: {}", path.display(), err);
+            Error::UploadFailed
+        })?;
+
+        Ok(UploadedMedia {
+            url: format!("{}/{}", self.url, public_id),
+            public_id,
+            timestamp: chrono::Utc::now().timestamp(),
+            sizes: None,
+        })
+    }
+
+    async fn update(&self, media: UploadedMedia, contents: Vec<u8>) -> Result<UploadedMedia> {
+        let uploaded_media = self.upload(contents).await?;
+
+        // NOTE: the new file is already in place, a stale one left behind isn't worth failing the update over
+        self.delete(&media.public_id).await.ok();
+
+        Ok(uploaded_media)
+    }
+
+    async fn delete(&self, public_id: &str) -> Result<()> {
+        let path = self.get_path(public_id)?;
+
+        tokio::fs::remove_file(&path)
+            .await
+            .map_err(|err| match err.kind() {
+                std::io::ErrorKind::NotFound => Error::NotFound,
+                _ => {
+                    tracing::error!("Failed to delete file {}: {}", path.display(), err);
+                    Error::DeleteFailed
+                }
+            })
+    }
+
+    async fn get(&self, public_id: &str) -> Result<StoredMedia> {
+        let path = self.get_path(public_id)?;
+
+        tokio::fs::read(&path)
+            .await
+            .map(StoredMedia::Contents)
+            .map_err(|err| {
+                tracing::debug!("Failed to read file {}: {}", path.display(), err);
+                Error::NotFound
+            })
+    }
+}
@@ -1,31 +1,53 @@
+mod cloudinary;
+mod local;
+mod processing;
+mod s3;
+
 use crate::types::StorageContext;
-use reqwest::{
-    multipart::{Form, Part},
-    Client, StatusCode, Url,
-};
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
 use serde_json::Value;
-use sha2::{Digest, Sha256};
-use ulid::Ulid;
+
+pub use cloudinary::{CloudinaryStorageBackend, CloudinaryStorageBackendConfig};
+pub use local::LocalStorageBackend;
+pub use s3::{S3StorageBackend, S3StorageBackendConfig};
 
 #[derive(Debug)]
 pub enum Error {
     UploadFailed,
     DeleteFailed,
+    NotFound,
+    // not a JPEG, PNG or WebP image going by its contents
+    UnsupportedImage,
+    InvalidImageDimensions,
 }
 
-#[derive(Deserialize)]
-struct UploadResponse {
-    secure_url: String,
-    public_id: String,
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Serialize, Clone, Debug, Deserialize)]
+pub struct SizedMedia {
+    pub public_id: String,
+    pub url: String,
+    pub width: u32,
+    pub height: u32,
 }
 
+#[derive(Serialize, Clone, Debug, Deserialize)]
+pub struct MediaSizes {
+    pub thumbnail: SizedMedia,
+    pub card: SizedMedia,
+    pub banner: SizedMedia,
+}
+
+// `url` is wherever the backend serves the file from, nothing else should need to know which backend that is.
+// `sizes` is missing on images uploaded before they were generated, clients fall back to `url` for those
 #[derive(Serialize, Clone, Debug, Deserialize)]
 pub struct UploadedMedia {
     pub public_id: String,
     pub url: String,
     pub timestamp: i64,
+    #[serde(default)]
+    pub sizes: Option<MediaSizes>,
 }
 
 impl From<Value> for UploadedMedia {
@@ -36,190 +58,146 @@ impl From<Value> for UploadedMedia {
                 public_id: String::from(""),
                 url: String::from(""),
                 timestamp: 0,
+                sizes: None,
             },
         }
     }
 }
 
-pub async fn upload_file(ctx: StorageContext, contents: Vec<u8>) -> Result<UploadedMedia, Error> {
-    let file_name = Ulid::new().to_string();
-    let part = Part::bytes(contents).file_name(file_name.clone());
-
-    let timestamp = chrono::Utc::now().timestamp();
-    let data_to_sign = format!(
-        "timestamp={}&upload_preset={}{}",
-        timestamp, ctx.upload_preset, ctx.api_secret
-    );
-
-    let mut hasher = Sha256::new();
-    hasher.update(data_to_sign.clone());
-    let hash = hasher.finalize();
-    let signature = base16ct::lower::encode_string(&hash);
-
-    let form = Form::new()
-        .text("upload_preset", ctx.upload_preset.clone())
-        .text("api_key", ctx.api_key.clone())
-        .text("timestamp", format!("{}", timestamp))
-        .text("signature", signature)
-        .text("signature_algorithm", "sha256")
-        .part("file", part);
-
-    let res = Client::new()
-        .post(ctx.upload_endpoint)
-        .multipart(form)
-        .send()
-        .await
-        .map_err(|err| {
-            tracing::error!("Error occurred while trying to upload a file: {:?}", err);
-            Error::UploadFailed
-        })?;
+pub enum StoredMedia {
+    // the backend serves the file itself (e.g a CDN), clients are sent there
+    Url(String),
+    Contents(Vec<u8>),
+}
 
-    let response_status_code = res.status();
-    if response_status_code != StatusCode::OK {
-        let data = res.text().await.map_err(|err| {
-            tracing::error!("Error occurred while processing return data: {:?}", err);
-            Error::UploadFailed
-        })?;
+// Where uploaded files end up, picked with `STORAGE_BACKEND` and built once on startup
+#[async_trait]
+pub trait StorageBackend: Send + Sync {
+    async fn upload(&self, contents: Vec<u8>) -> Result<UploadedMedia>;
 
-        tracing::error!("Failed to upload file, non OK status {}: {}", response_status_code, data);
-        return Err(Error::UploadFailed);
-    }
+    // NOTE: the returned media replaces `media`, its url isn't guaranteed to stay the same
+    async fn update(&self, media: UploadedMedia, contents: Vec<u8>) -> Result<UploadedMedia>;
+
+    async fn delete(&self, public_id: &str) -> Result<()>;
 
-    let data = res.text().await.map_err(|err| {
-        tracing::error!("Error occurred while processing return data: {:?}", err);
-        Error::UploadFailed
-    })?;
-
-    serde_json::de::from_str::<UploadResponse>(data.as_ref())
-        .map(|res| UploadedMedia {
-            url: res.secure_url,
-            public_id: res.public_id,
-            timestamp,
-        })
+    async fn get(&self, public_id: &str) -> Result<StoredMedia>;
+}
+
+async fn process_image(contents: Vec<u8>) -> Result<processing::ProcessedImages> {
+    tokio::task::spawn_blocking(move || processing::process(&contents))
+        .await
         .map_err(|err| {
-            tracing::error!("Failed to deserialize cloudinary response: {:?}", err);
+            tracing::error!("Failed to process image: {}", err);
             Error::UploadFailed
-        })
+        })?
 }
 
-pub async fn delete_file(cfg: StorageContext, media: UploadedMedia) -> Result<(), Error> {
-    let url = Url::parse(media.url.as_ref()).map_err(|err| {
-        tracing::error!("Failed to parse url {}: {:?}", media.url, err);
-        Error::DeleteFailed
-    })?;
-
-    let data_to_sign = format!(
-        "public_id={}&timestamp={}{}",
-        media.public_id, media.timestamp, cfg.api_secret
-    );
-
-    let mut hasher = Sha256::new();
-    hasher.update(data_to_sign.clone());
-    let hash = hasher.finalize();
-    let signature = base16ct::lower::encode_string(&hash);
-
-    let body = json!({
-        "public_id": media.public_id,
-        "api_key": cfg.api_key,
-        "signature": signature,
-        "timestamp": media.timestamp,
+async fn upload_size(
+    ctx: &StorageContext,
+    image: processing::ProcessedImage,
+) -> Result<SizedMedia> {
+    let media = ctx.backend.upload(image.contents).await?;
+
+    Ok(SizedMedia {
+        public_id: media.public_id,
+        url: media.url,
+        width: image.width,
+        height: image.height,
     })
-    .to_string();
-
-    let res = Client::new()
-        .post(cfg.delete_endpoint)
-        .header("Content-Type", "application/json")
-        .body(body)
-        .send()
-        .await
-        .map_err(|err| {
-            tracing::error!("Failed to delete file {}: {:?}", url, err);
-            Error::DeleteFailed
-        })?;
-
-    if res.status() != StatusCode::OK {
-        let data = res.text().await.map_err(|err| {
-            tracing::error!("Failed to process delete file response {}: {:?}", url, err);
-            Error::DeleteFailed
-        })?;
-
-        tracing::error!("Failed to delete uploaded file: {}", data);
-        return Err(Error::UploadFailed);
-    }
+}
 
-    let data = res.text().await.map_err(|err| {
-        tracing::error!("Failed to process delete file response {}: {:?}", url, err);
-        Error::DeleteFailed
-    })?;
+async fn upload_sizes(
+    ctx: &StorageContext,
+    thumbnail: processing::ProcessedImage,
+    card: processing::ProcessedImage,
+    banner: processing::ProcessedImage,
+) -> Result<MediaSizes> {
+    let (thumbnail, card, banner) = tokio::try_join!(
+        upload_size(ctx, thumbnail),
+        upload_size(ctx, card),
+        upload_size(ctx, banner)
+    )?;
+
+    Ok(MediaSizes {
+        thumbnail,
+        card,
+        banner,
+    })
+}
 
-    tracing::debug!("Delete file response: {}", data);
+// NOTE: best effort, a size left behind only takes up space
+async fn delete_sizes(ctx: &StorageContext, sizes: MediaSizes) {
+    for size in [sizes.thumbnail, sizes.card, sizes.banner] {
+        ctx.backend.delete(&size.public_id).await.ok();
+    }
+}
 
-    Ok(())
+// Validates the image, strips its metadata and stores it along with its thumbnail, card and banner sizes
+pub async fn upload_image(ctx: StorageContext, contents: Vec<u8>) -> Result<UploadedMedia> {
+    let processing::ProcessedImages {
+        original,
+        thumbnail,
+        card,
+        banner,
+    } = process_image(contents).await?;
+
+    let media = ctx.backend.upload(original.contents).await?;
+
+    match upload_sizes(&ctx, thumbnail, card, banner).await {
+        Ok(sizes) => Ok(UploadedMedia {
+            sizes: Some(sizes),
+            ..media
+        }),
+        Err(err) => {
+            ctx.backend.delete(&media.public_id).await.ok();
+            Err(err)
+        }
+    }
 }
 
-pub async fn update_file(
-    cfg: StorageContext,
+pub async fn update_image(
+    ctx: StorageContext,
     media: UploadedMedia,
     contents: Vec<u8>,
-) -> Result<UploadedMedia, Error> {
-    let file_name = Ulid::new().to_string();
-    let part = Part::bytes(contents).file_name(file_name.clone());
-
-    let timestamp = chrono::Utc::now().timestamp();
-    let data_to_sign = format!(
-        "public_id={}&timestamp={}{}",
-        media.public_id.clone(),
-        timestamp,
-        cfg.api_secret
-    );
-
-    let mut hasher = Sha256::new();
-    hasher.update(data_to_sign.clone());
-    let hash = hasher.finalize();
-    let signature = base16ct::lower::encode_string(&hash);
-
-    let form = Form::new()
-        .text("api_key", cfg.api_key.clone())
-        .text("public_id", media.public_id.clone())
-        .text("timestamp", format!("{}", timestamp))
-        .text("signature", signature)
-        .text("signature_algorithm", "sha256")
-        .part("file", part);
-
-    let res = Client::new()
-        .post(cfg.upload_endpoint)
-        .multipart(form)
-        .send()
-        .await
-        .map_err(|err| {
-            tracing::error!("Error occurred while trying to upload a file: {:?}", err);
-            Error::UploadFailed
-        })?;
-
-    if res.status() != StatusCode::OK {
-        let data = res.text().await.map_err(|err| {
-            tracing::error!("Error occurred while processing return data: {:?}", err);
-            Error::UploadFailed
-        })?;
+) -> Result<UploadedMedia> {
+    let processing::ProcessedImages {
+        original,
+        thumbnail,
+        card,
+        banner,
+    } = process_image(contents).await?;
+
+    let sizes = upload_sizes(&ctx, thumbnail, card, banner).await?;
+    let old_sizes = media.sizes.clone();
+
+    let updated_media = match ctx.backend.update(media, original.contents).await {
+        Ok(updated_media) => updated_media,
+        Err(err) => {
+            delete_sizes(&ctx, sizes).await;
+            return Err(err);
+        }
+    };
 
-        tracing::error!("Failed to upload file: {}", data);
-        return Err(Error::UploadFailed);
+    if let Some(old_sizes) = old_sizes {
+        delete_sizes(&ctx, old_sizes).await;
     }
 
-    let data = res.text().await.map_err(|err| {
-        tracing::error!("Error occurred while processing return data: {:?}", err);
-        Error::UploadFailed
-    })?;
+    Ok(UploadedMedia {
+        sizes: Some(sizes),
+        ..updated_media
+    })
+}
 
-    match serde_json::de::from_str::<UploadResponse>(data.as_ref()) {
-        Ok(res) => Ok(UploadedMedia {
-            url: res.secure_url,
-            public_id: res.public_id,
-            timestamp,
-        }),
-        Err(err) => {
-            tracing::error!("Failed to deserialize cloudinary response: {:?}", err);
-            Err(Error::UploadFailed)
-        }
+pub async fn delete_image(ctx: StorageContext, media: UploadedMedia) -> Result<()> {
+    ctx.backend.delete(&media.public_id).await?;
+
+    if let Some(sizes) = media.sizes {
+        delete_sizes(&ctx, sizes).await;
     }
+
+    Ok(())
+}
+
+pub async fn get_file(ctx: StorageContext, public_id: String) -> Result<StoredMedia> {
+    ctx.backend.get(&public_id).await
 }
@@ -0,0 +1,126 @@
+use super::{Error, Result};
+use image::{
+    codecs::jpeg::JpegEncoder, imageops::FilterType, metadata::Orientation, DynamicImage,
+    ImageDecoder, ImageFormat, ImageReader,
+};
+use std::io::Cursor;
+
+const MIN_DIMENSION: u32 = 64;
+const MAX_DIMENSION: u32 = 6000;
+const JPEG_QUALITY: u8 = 82;
+
+pub struct ProcessedImage {
+    pub contents: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+}
+
+pub struct ProcessedImages {
+    pub original: ProcessedImage,
+    pub thumbnail: ProcessedImage,
+    pub card: ProcessedImage,
+    pub banner: ProcessedImage,
+}
+
+// Thumbnails are cropped to fill their square (avatars, list items), cards and banners keep their aspect ratio and
+// only ever get smaller
+enum Size {
+    Thumbnail,
+    Card,
+    Banner,
+}
+
+impl Size {
+    fn get_bounds(&self) -> (u32, u32) {
+        match self {
+            Self::Thumbnail => (200, 200),
+            Self::Card => (640, 640),
+            Self::Banner => (1280, 720),
+        }
+    }
+}
+
+// NOTE: only the magic bytes count, the file name and content type are whatever the client says they are
+fn get_format(contents: &[u8]) -> Option<ImageFormat> {
+    match contents {
+        [0xFF, 0xD8, 0xFF, ..] => Some(ImageFormat::Jpeg),
+        [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, ..] => Some(ImageFormat::Png),
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Some(ImageFormat::WebP),
+        _ => None,
+    }
+}
+
+// What the image is served as, `None` for anything that isn't one
+pub fn get_content_type(contents: &[u8]) -> Option<&'static str> {
+    get_format(contents).map(|format| format.to_mime_type())
+}
+
+fn is_valid_dimension(dimension: u32) -> bool {
+    (MIN_DIMENSION..=MAX_DIMENSION).contains(&dimension)
+}
+
+fn resize(image: &DynamicImage, size: Size) -> DynamicImage {
+    let (width, height) = size.get_bounds();
+
+    match size {
+        Size::Thumbnail => image.resize_to_fill(width, height, FilterType::CatmullRom),
+        _ if image.width() <= width && image.height() <= height => image.clone(),
+        _ => image.resize(width, height, FilterType::CatmullRom),
+    }
+}
+
+// Only the pixels get written back out, which is what drops the EXIF data (and the location it tends to carry)
+fn encode(image: &DynamicImage) -> Result<ProcessedImage> {
+    let mut contents = vec![];
+
+    let result = match image.color().has_alpha() {
+        true => image.write_to(&mut Cursor::new(&mut contents), ImageFormat::Png),
+        false => JpegEncoder::new_with_quality(&mut contents, JPEG_QUALITY)
+            .encode_image(&image.to_rgb8()),
+    };
+
+    result.map_err(|err| {
+        tracing::error!("Failed to encode image: {}", err);
+        Error::UploadFailed
+    })?;
+
+    Ok(ProcessedImage {
+        contents,
+        width: image.width(),
+        height: image.height(),
+    })
+}
+
+// NOTE: cpu bound, run it off the async runtime
+pub fn process(contents: &[u8]) -> Result<ProcessedImages> {
+    let format = get_format(contents).ok_or(Error::UnsupportedImage)?;
+
+    let mut decoder = ImageReader::with_format(Cursor::new(contents), format)
+        .into_decoder()
+        .map_err(|err| {
+            tracing::debug!("Failed to read image: {}", err);
+            Error::UnsupportedImage
+        })?;
+
+    // checked before decoding, so that a huge image is turned away before its pixels take up any memory
+    let (width, height) = decoder.dimensions();
+    if !is_valid_dimension(width) || !is_valid_dimension(height) {
+        return Err(Error::InvalidImageDimensions);
+    }
+
+    // the orientation lives in the EXIF data, it has to be applied before that's dropped or photos end up sideways
+    let orientation = decoder.orientation().unwrap_or(Orientation::NoTransforms);
+
+    let mut image = DynamicImage::from_decoder(decoder).map_err(|err| {
+        tracing::debug!("Failed to decode image: {}", err);
+        Error::UnsupportedImage
+    })?;
+    image.apply_orientation(orientation);
+
+    Ok(ProcessedImages {
+        original: encode(&image)?,
+        thumbnail: encode(&resize(&image, Size::Thumbnail))?,
+        card: encode(&resize(&image, Size::Card))?,
+        banner: encode(&resize(&image, Size::Banner))?,
+    })
+}
@@ -590,3 +590,25 @@ pub async fn find_initial_order_payment_transaction_by_order_id<'e, E: PgExecuto
         Error::UnexpectedError
     })
 }
+
+pub async fn find_many_by_user_id<'e, E: PgExecutor<'e>>(
+    executor: E,
+    user_id: String,
+) -> Result<Vec<Transaction>, Error> {
+    sqlx::query_as!(
+        DbTransaction,
+        "SELECT * FROM transactions WHERE user_id = $1 ORDER BY created_at DESC",
+        user_id
+    )
+    .fetch_all(executor)
+    .await
+    .map(|db_transactions| db_transactions.into_iter().map(Into::into).collect())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch transactions of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
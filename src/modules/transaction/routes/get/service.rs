@@ -1,12 +1,16 @@
 use super::types::{request, response};
 use crate::{
-    modules::{transaction::repository, user},
+    modules::{auth::permission::Permission, transaction::repository},
     types::Context,
 };
 use std::sync::Arc;
 
 pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
-    match user::repository::is_admin(&payload.auth.user) {
+    match payload
+        .auth
+        .user
+        .has_permission(&Permission::ViewTransactions)
+    {
         true => repository::find_by_id(&ctx.db_conn.pool, payload.id).await,
         false => {
             repository::find_by_id_and_user_id(
@@ -3,13 +3,17 @@ use super::{
     types::{request, response},
 };
 use crate::{
-    modules::{kitchen, user},
+    modules::{auth::permission::Permission, kitchen},
     types::Context,
 };
 use std::sync::Arc;
 
 pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
-    match user::repository::is_admin(&payload.auth.user) {
+    match payload
+        .auth
+        .user
+        .has_permission(&Permission::ViewTransactions)
+    {
         true => {
             repository::find_many(
                 &ctx.db_conn.pool,
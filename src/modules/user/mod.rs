@@ -1,3 +1,5 @@
 mod routes;
+pub mod job;
 pub mod repository;
+pub mod service;
 pub use routes::get_router;
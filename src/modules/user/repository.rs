@@ -26,6 +26,10 @@ type Result<T> = std::result::Result<T, Error>;
 pub enum Role {
     #[serde(rename = "ADMIN")]
     Admin,
+    #[serde(rename = "SUPPORT")]
+    Support,
+    #[serde(rename = "FINANCE")]
+    Finance,
     #[serde(rename = "USER")]
     User,
 }
@@ -34,6 +38,8 @@ impl From<String> for Role {
     fn from(value: String) -> Self {
         match value.as_ref() {
             "ADMIN" => Role::Admin,
+            "SUPPORT" => Role::Support,
+            "FINANCE" => Role::Finance,
             "USER" => Role::User,
             role => unreachable!("Invalid user role: {}", role),
         }
@@ -44,23 +50,70 @@ impl ToString for Role {
     fn to_string(&self) -> String {
         match self {
             Role::Admin => String::from("ADMIN"),
+            Role::Support => String::from("SUPPORT"),
+            Role::Finance => String::from("FINANCE"),
             Role::User => String::from("USER"),
         }
     }
 }
 
+// The language notifications are written in
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub enum Locale {
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    #[serde(rename = "pcm")]
+    Pidgin,
+    #[serde(rename = "yo")]
+    Yoruba,
+    #[serde(rename = "ig")]
+    Igbo,
+    #[serde(rename = "ha")]
+    Hausa,
+}
+
+impl From<String> for Locale {
+    fn from(value: String) -> Self {
+        match value.as_ref() {
+            "en" => Locale::English,
+            "pcm" => Locale::Pidgin,
+            "yo" => Locale::Yoruba,
+            "ig" => Locale::Igbo,
+            "ha" => Locale::Hausa,
+            locale => unreachable!("Invalid locale: {}", locale),
+        }
+    }
+}
+
+impl ToString for Locale {
+    fn to_string(&self) -> String {
+        match self {
+            Locale::English => String::from("en"),
+            Locale::Pidgin => String::from("pcm"),
+            Locale::Yoruba => String::from("yo"),
+            Locale::Igbo => String::from("ig"),
+            Locale::Hausa => String::from("ha"),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct User {
     pub id: String,
     pub email: String,
     pub phone_number: String,
     pub is_verified: bool,
+    // set once the user has shown they can read the email, e.g by following a link sent to it
+    pub email_verified_at: Option<NaiveDateTime>,
     pub first_name: String,
     pub last_name: String,
     pub role: Role,
     pub has_kitchen: bool,
     pub referral_code: Option<String>,
     pub profile_picture: ProfilePicture,
+    #[serde(default)]
+    pub locale: Locale,
     pub created_at: NaiveDateTime,
     pub updated_at: Option<NaiveDateTime>,
     pub deleted_at: Option<NaiveDateTime>,
@@ -72,13 +125,6 @@ impl From<sqlx::types::Json<User>> for User {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct ExemptUser {
-    pub id: String,
-    pub created_at: NaiveDateTime,
-    pub updated_at: Option<NaiveDateTime>,
-}
-
 pub struct CreateUserPayload {
     pub email: String,
     pub phone_number: String,
@@ -266,6 +312,32 @@ pub async fn verify_by_phone_number<'e, E: PgExecutor<'e>>(
     .map(|_| {})
 }
 
+pub async fn verify_email_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<()> {
+    sqlx::query!(
+        "
+        UPDATE
+            users
+        SET
+            email_verified_at = COALESCE(email_verified_at, NOW()),
+            updated_at = NOW()
+        WHERE
+            id = $1
+        ",
+        id
+    )
+    .execute(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to verify email of user {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+    .map(|_| {})
+}
+
 pub struct UpdateUserPayload {
     pub email: Option<String>,
     pub phone_number: Option<String>,
@@ -273,6 +345,7 @@ pub struct UpdateUserPayload {
     pub last_name: Option<String>,
     pub has_kitchen: Option<bool>,
     pub profile_picture: Option<UploadedMedia>,
+    pub locale: Option<Locale>,
 }
 
 pub async fn update_by_id<'e, E: PgExecutor<'e>>(
@@ -292,9 +365,10 @@ pub async fn update_by_id<'e, E: PgExecutor<'e>>(
                     CASE WHEN $6::text = 'null' THEN NULL ELSE $6::json END, 
                     profile_picture
                 ),
+                locale = COALESCE($7, locale),
                 updated_at = NOW()
             WHERE
-                id = $7
+                id = $8
         ",
         payload.email,
         payload.phone_number,
@@ -302,6 +376,7 @@ pub async fn update_by_id<'e, E: PgExecutor<'e>>(
         payload.last_name,
         payload.has_kitchen,
         json!(payload.profile_picture).to_string(),
+        payload.locale.map(|locale| locale.to_string()),
         id,
     )
     .execute(e)
@@ -317,37 +392,52 @@ pub async fn update_by_id<'e, E: PgExecutor<'e>>(
     .map(|_| ())
 }
 
-pub async fn find_exempt_by_id<'e, E: PgExecutor<'e>>(
+pub async fn update_role_by_id<'e, E: PgExecutor<'e>>(
     e: E,
-    user_id: String,
-) -> Result<Option<ExemptUser>> {
+    id: String,
+    role: Role,
+) -> Result<Option<User>> {
     sqlx::query_as!(
-        ExemptUser,
-        "SELECT * FROM exempt_users WHERE id = $1",
-        user_id
+        User,
+        "UPDATE users SET role = $1, updated_at = NOW() WHERE id = $2 RETURNING *",
+        role.to_string(),
+        id
     )
     .fetch_optional(e)
     .await
     .map_err(|err| {
         tracing::error!(
-            "Error occurred while trying to fetch exempt user by id {}: {}",
-            user_id,
+            "Error occurred while trying to update role of user by id {}: {}",
+            id,
             err
         );
         Error::UnexpectedError
     })
 }
 
-pub async fn delete_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<()> {
+// NOTE: the row itself is kept so that orders and transactions still point somewhere, only what identifies the person
+// goes away
+pub async fn anonymise_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<()> {
     sqlx::query!(
         r#"
         UPDATE
             users
         SET
-            deleted_at = NOW()
+            email = $1,
+            phone_number = $2,
+            first_name = 'Deleted',
+            last_name = 'User',
+            is_verified = FALSE,
+            email_verified_at = NULL,
+            referral_code = NULL,
+            profile_picture = NULL,
+            updated_at = NOW(),
+            deleted_at = COALESCE(deleted_at, NOW())
         WHERE
-            id = $1
+            id = $3
         "#,
+        format!("deleted-{}@deleted.invalid", id),
+        format!("deleted-{}", id),
         id
     )
     .execute(e)
@@ -355,7 +445,7 @@ pub async fn delete_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<()>
     .map(|_| ())
     .map_err(|err| {
         tracing::error!(
-            "Error occurred while trying to delete user by id {}: {}",
+            "Error occurred while trying to anonymise user by id {}: {}",
             id,
             err
         );
@@ -363,6 +453,535 @@ pub async fn delete_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<()>
     })
 }
 
-pub fn is_admin(user: &User) -> bool {
-    return user.role == Role::Admin;
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum ContactKind {
+    #[serde(rename = "PHONE_NUMBER")]
+    PhoneNumber,
+    #[serde(rename = "EMAIL")]
+    Email,
+}
+
+impl From<String> for ContactKind {
+    fn from(value: String) -> Self {
+        match value.as_ref() {
+            "PHONE_NUMBER" => ContactKind::PhoneNumber,
+            "EMAIL" => ContactKind::Email,
+            kind => unreachable!("Invalid contact kind: {}", kind),
+        }
+    }
+}
+
+impl ToString for ContactKind {
+    fn to_string(&self) -> String {
+        match self {
+            ContactKind::PhoneNumber => String::from("PHONE_NUMBER"),
+            ContactKind::Email => String::from("EMAIL"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum ContactChangeStatus {
+    #[serde(rename = "PENDING")]
+    Pending,
+    #[serde(rename = "COMMITTED")]
+    Committed,
+    #[serde(rename = "CANCELLED")]
+    Cancelled,
+}
+
+impl From<String> for ContactChangeStatus {
+    fn from(value: String) -> Self {
+        match value.as_ref() {
+            "PENDING" => ContactChangeStatus::Pending,
+            "COMMITTED" => ContactChangeStatus::Committed,
+            "CANCELLED" => ContactChangeStatus::Cancelled,
+            status => unreachable!("Invalid contact change status: {}", status),
+        }
+    }
+}
+
+impl ToString for ContactChangeStatus {
+    fn to_string(&self) -> String {
+        match self {
+            ContactChangeStatus::Pending => String::from("PENDING"),
+            ContactChangeStatus::Committed => String::from("COMMITTED"),
+            ContactChangeStatus::Cancelled => String::from("CANCELLED"),
+        }
+    }
+}
+
+// Changes are only ever deleted along with the account, so this doubles as the audit log of a user's phone number and
+// email
+#[derive(Serialize, Clone, Debug)]
+pub struct ContactChange {
+    pub id: String,
+    pub user_id: String,
+    pub kind: ContactKind,
+    pub previous_value: String,
+    pub new_value: String,
+    pub status: ContactChangeStatus,
+    // hash of the token in the link sent to a new email
+    #[serde(skip_serializing)]
+    pub token_hash: Option<String>,
+    pub current_verified_at: Option<NaiveDateTime>,
+    pub new_verified_at: Option<NaiveDateTime>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub expires_at: NaiveDateTime,
+    pub committed_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+pub struct CreateContactChangePayload {
+    pub user_id: String,
+    pub kind: ContactKind,
+    pub previous_value: String,
+    pub new_value: String,
+    pub token_hash: Option<String>,
+    pub current_verified_at: Option<NaiveDateTime>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub expires_at: NaiveDateTime,
+}
+
+pub async fn create_contact_change<'e, E: PgExecutor<'e>>(
+    e: E,
+    payload: CreateContactChangePayload,
+) -> Result<ContactChange> {
+    sqlx::query_as!(
+        ContactChange,
+        "
+        INSERT INTO contact_changes (
+            id,
+            user_id,
+            kind,
+            previous_value,
+            new_value,
+            status,
+            token_hash,
+            current_verified_at,
+            ip_address,
+            user_agent,
+            expires_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+        RETURNING *
+        ",
+        Ulid::new().to_string(),
+        payload.user_id,
+        payload.kind.to_string(),
+        payload.previous_value,
+        payload.new_value,
+        ContactChangeStatus::Pending.to_string(),
+        payload.token_hash,
+        payload.current_verified_at,
+        payload.ip_address,
+        payload.user_agent,
+        payload.expires_at
+    )
+    .fetch_one(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to create a contact change for user with id {}: {}",
+            payload.user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_contact_change_by_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+) -> Result<Option<ContactChange>> {
+    sqlx::query_as!(
+        ContactChange,
+        "SELECT * FROM contact_changes WHERE id = $1",
+        id
+    )
+    .fetch_optional(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch contact change by id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_contact_change_by_token_hash<'e, E: PgExecutor<'e>>(
+    e: E,
+    token_hash: String,
+) -> Result<Option<ContactChange>> {
+    sqlx::query_as!(
+        ContactChange,
+        "SELECT * FROM contact_changes WHERE token_hash = $1",
+        token_hash
+    )
+    .fetch_optional(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch contact change by token hash: {}",
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_many_contact_changes_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<Vec<ContactChange>> {
+    sqlx::query_as!(
+        ContactChange,
+        "SELECT * FROM contact_changes WHERE user_id = $1 ORDER BY created_at DESC",
+        user_id
+    )
+    .fetch_all(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch contact changes of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn cancel_pending_contact_changes_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<()> {
+    sqlx::query!(
+        "
+        UPDATE contact_changes
+        SET
+            status = $1,
+            updated_at = NOW()
+        WHERE
+            user_id = $2
+            AND status = $3
+        ",
+        ContactChangeStatus::Cancelled.to_string(),
+        user_id,
+        ContactChangeStatus::Pending.to_string()
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to cancel pending contact changes of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn verify_contact_change_current_value_by_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+) -> Result<()> {
+    sqlx::query!(
+        "UPDATE contact_changes SET current_verified_at = NOW(), updated_at = NOW() WHERE id = $1",
+        id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to verify the current value of contact change with id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn verify_contact_change_new_value_by_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+) -> Result<()> {
+    sqlx::query!(
+        "UPDATE contact_changes SET new_verified_at = NOW(), updated_at = NOW() WHERE id = $1",
+        id
+    )
+    .execute(e)
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to verify the new value of contact change with id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+// NOTE: returns false when the change is no longer pending, e.g another request committed it first
+pub async fn commit_contact_change_by_id<'e, E: PgExecutor<'e>>(e: E, id: String) -> Result<bool> {
+    sqlx::query!(
+        "
+        UPDATE contact_changes
+        SET
+            status = $1,
+            committed_at = NOW(),
+            updated_at = NOW()
+        WHERE
+            id = $2
+            AND status = $3
+        ",
+        ContactChangeStatus::Committed.to_string(),
+        id,
+        ContactChangeStatus::Pending.to_string()
+    )
+    .execute(e)
+    .await
+    .map(|result| result.rows_affected() > 0)
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to commit contact change with id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn delete_many_contact_changes_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<()> {
+    sqlx::query!("DELETE FROM contact_changes WHERE user_id = $1", user_id)
+        .execute(e)
+        .await
+        .map(|_| ())
+        .map_err(|err| {
+            tracing::error!(
+                "Error occurred while trying to delete contact changes of user with id {}: {}",
+                user_id,
+                err
+            );
+            Error::UnexpectedError
+        })
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum AccountDeletionStatus {
+    #[serde(rename = "PENDING")]
+    Pending,
+    #[serde(rename = "CANCELLED")]
+    Cancelled,
+    #[serde(rename = "COMPLETED")]
+    Completed,
+}
+
+impl From<String> for AccountDeletionStatus {
+    fn from(value: String) -> Self {
+        match value.as_ref() {
+            "PENDING" => AccountDeletionStatus::Pending,
+            "CANCELLED" => AccountDeletionStatus::Cancelled,
+            "COMPLETED" => AccountDeletionStatus::Completed,
+            status => unreachable!("Invalid account deletion status: {}", status),
+        }
+    }
+}
+
+impl ToString for AccountDeletionStatus {
+    fn to_string(&self) -> String {
+        match self {
+            AccountDeletionStatus::Pending => String::from("PENDING"),
+            AccountDeletionStatus::Cancelled => String::from("CANCELLED"),
+            AccountDeletionStatus::Completed => String::from("COMPLETED"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AccountDeletion {
+    pub id: String,
+    pub user_id: String,
+    pub status: AccountDeletionStatus,
+    pub scheduled_for: NaiveDateTime,
+    pub cancelled_at: Option<NaiveDateTime>,
+    pub completed_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+pub async fn create_account_deletion<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+    scheduled_for: NaiveDateTime,
+) -> Result<AccountDeletion> {
+    sqlx::query_as!(
+        AccountDeletion,
+        "
+        INSERT INTO account_deletions (id, user_id, status, scheduled_for)
+        VALUES ($1, $2, $3, $4)
+        RETURNING *
+        ",
+        Ulid::new().to_string(),
+        user_id,
+        AccountDeletionStatus::Pending.to_string(),
+        scheduled_for
+    )
+    .fetch_one(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to schedule the deletion of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_pending_account_deletion_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<Option<AccountDeletion>> {
+    sqlx::query_as!(
+        AccountDeletion,
+        "SELECT * FROM account_deletions WHERE user_id = $1 AND status = $2",
+        user_id,
+        AccountDeletionStatus::Pending.to_string()
+    )
+    .fetch_optional(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch the pending deletion of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_many_account_deletions_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<Vec<AccountDeletion>> {
+    sqlx::query_as!(
+        AccountDeletion,
+        "SELECT * FROM account_deletions WHERE user_id = $1 ORDER BY created_at DESC",
+        user_id
+    )
+    .fetch_all(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch account deletions of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+pub async fn find_many_due_account_deletions<'e, E: PgExecutor<'e>>(
+    e: E,
+) -> Result<Vec<AccountDeletion>> {
+    sqlx::query_as!(
+        AccountDeletion,
+        "
+        SELECT
+            *
+        FROM
+            account_deletions
+        WHERE
+            status = $1
+            AND scheduled_for <= NOW()
+        ORDER BY
+            scheduled_for ASC
+        ",
+        AccountDeletionStatus::Pending.to_string()
+    )
+    .fetch_all(e)
+    .await
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to fetch due account deletions: {}",
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+// NOTE: returns false when there was no pending deletion to cancel
+pub async fn cancel_pending_account_deletion_by_user_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    user_id: String,
+) -> Result<bool> {
+    sqlx::query!(
+        "
+        UPDATE account_deletions
+        SET
+            status = $1,
+            cancelled_at = NOW(),
+            updated_at = NOW()
+        WHERE
+            user_id = $2
+            AND status = $3
+        ",
+        AccountDeletionStatus::Cancelled.to_string(),
+        user_id,
+        AccountDeletionStatus::Pending.to_string()
+    )
+    .execute(e)
+    .await
+    .map(|result| result.rows_affected() > 0)
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to cancel the pending deletion of user with id {}: {}",
+            user_id,
+            err
+        );
+        Error::UnexpectedError
+    })
+}
+
+// NOTE: returns false when the deletion was cancelled in the meantime
+pub async fn complete_account_deletion_by_id<'e, E: PgExecutor<'e>>(
+    e: E,
+    id: String,
+) -> Result<bool> {
+    sqlx::query!(
+        "
+        UPDATE account_deletions
+        SET
+            status = $1,
+            completed_at = NOW(),
+            updated_at = NOW()
+        WHERE
+            id = $2
+            AND status = $3
+        ",
+        AccountDeletionStatus::Completed.to_string(),
+        id,
+        AccountDeletionStatus::Pending.to_string()
+    )
+    .execute(e)
+    .await
+    .map(|result| result.rows_affected() > 0)
+    .map_err(|err| {
+        tracing::error!(
+            "Error occurred while trying to complete account deletion with id {}: {}",
+            id,
+            err
+        );
+        Error::UnexpectedError
+    })
 }
@@ -1,5 +1,6 @@
 mod get;
 mod profile;
+mod roles;
 
 use crate::types::Context;
 use axum::routing::Router;
@@ -9,4 +10,5 @@ pub fn get_router() -> Router<Arc<Context>> {
     Router::new()
         .nest("/", get::get_router())
         .nest("/", profile::get_router())
+        .nest("/", roles::get_router())
 }
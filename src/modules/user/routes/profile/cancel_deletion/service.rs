@@ -0,0 +1,16 @@
+use super::types::{request, response};
+use crate::{modules::user::repository, types::Context};
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    match repository::cancel_pending_account_deletion_by_user_id(
+        &ctx.db_conn.pool,
+        payload.auth.user.id,
+    )
+    .await
+    .map_err(|_| response::Error::FailedToCancelDeletion)?
+    {
+        true => Ok(response::Success::DeletionCancelled),
+        false => Err(response::Error::NoPendingDeletion),
+    }
+}
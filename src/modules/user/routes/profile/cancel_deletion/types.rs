@@ -0,0 +1,52 @@
+pub mod request {
+    pub use crate::modules::auth::middleware::Auth;
+
+    pub struct Payload {
+        pub auth: Auth,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        DeletionCancelled,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::DeletionCancelled => (
+                    StatusCode::OK,
+                    Json(json!({ "message": "Account deletion cancelled" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        NoPendingDeletion,
+        FailedToCancelDeletion,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::NoPendingDeletion => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Account is not scheduled for deletion" })),
+                )
+                    .into_response(),
+                Self::FailedToCancelDeletion => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to cancel account deletion" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -0,0 +1,133 @@
+use super::types::{request, response};
+use crate::{
+    modules::{
+        user::{
+            repository::{self, ContactChangeStatus, ContactKind},
+            service,
+        },
+        zoho,
+    },
+    types::Context,
+};
+use chrono::Utc;
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let user = payload.auth.user;
+
+    let change = repository::find_contact_change_by_id(&ctx.db_conn.pool, payload.id)
+        .await
+        .map_err(|_| response::Error::UnexpectedError)?
+        .filter(|change| change.user_id == user.id)
+        .ok_or(response::Error::ContactChangeNotFound)?;
+
+    if change.status != ContactChangeStatus::Pending {
+        return Err(response::Error::ContactChangeNotPending);
+    }
+
+    if Utc::now().naive_utc() > change.expires_at {
+        return Err(response::Error::ContactChangeExpired);
+    }
+
+    if change.current_verified_at.is_none() {
+        return Err(response::Error::CurrentValueNotVerified);
+    }
+
+    if change.new_verified_at.is_none() {
+        return Err(response::Error::NewValueNotVerified);
+    }
+
+    let mut tx = ctx.db_conn.pool.begin().await.map_err(|err| {
+        tracing::error!("Failed to start database transaction: {}", err);
+        response::Error::UnexpectedError
+    })?;
+
+    // NOTE: someone else might have taken the new value while this change was pending
+    let existing_user = match change.kind {
+        ContactKind::PhoneNumber => {
+            repository::find_by_phone_number(&mut *tx, change.new_value.clone()).await
+        }
+        ContactKind::Email => repository::find_by_email(&mut *tx, change.new_value.clone()).await,
+    }
+    .map_err(|_| response::Error::UnexpectedError)?;
+
+    if existing_user.is_some() {
+        return Err(response::Error::ContactInUse);
+    }
+
+    let update_payload = match change.kind {
+        ContactKind::PhoneNumber => repository::UpdateUserPayload {
+            email: None,
+            phone_number: Some(change.new_value.clone()),
+            first_name: None,
+            last_name: None,
+            has_kitchen: None,
+            profile_picture: None,
+            locale: None,
+        },
+        ContactKind::Email => repository::UpdateUserPayload {
+            email: Some(change.new_value.clone()),
+            phone_number: None,
+            first_name: None,
+            last_name: None,
+            has_kitchen: None,
+            profile_picture: None,
+            locale: None,
+        },
+    };
+
+    repository::update_by_id(&mut *tx, user.id.clone(), update_payload)
+        .await
+        .map_err(|_| response::Error::UnexpectedError)?;
+
+    // NOTE: a new email is only ever verified through the link sent to it
+    if change.kind == ContactKind::Email {
+        repository::verify_email_by_id(&mut *tx, user.id.clone())
+            .await
+            .map_err(|_| response::Error::UnexpectedError)?;
+    }
+
+    if change.kind == ContactKind::PhoneNumber {
+        repository::verify_by_phone_number(&mut *tx, change.new_value.clone())
+            .await
+            .map_err(|_| response::Error::UnexpectedError)?;
+
+        if user.is_verified == false {
+            zoho::service::register_user(
+                ctx.clone(),
+                repository::User {
+                    phone_number: change.new_value.clone(),
+                    ..user.clone()
+                },
+            )
+            .await
+            .map_err(|_| response::Error::UnexpectedError)?;
+        }
+    }
+
+    if !repository::commit_contact_change_by_id(&mut *tx, change.id.clone())
+        .await
+        .map_err(|_| response::Error::UnexpectedError)?
+    {
+        return Err(response::Error::ContactChangeNotPending);
+    }
+
+    // NOTE: an unverified phone number or email might not even belong to the user, so it isn't told about the change
+    let previous_value_verified = match change.kind {
+        ContactKind::PhoneNumber => user.is_verified,
+        ContactKind::Email => user.email_verified_at.is_some(),
+    };
+
+    if previous_value_verified {
+        service::notify_previous_contact(&mut *tx, user, change.kind)
+            .await
+            .map_err(|_| response::Error::UnexpectedError)?;
+    }
+
+    tx.commit().await.map_err(|err| {
+        tracing::error!("Failed to commit transaction: {}", err);
+        response::Error::UnexpectedError
+    })?;
+
+    Ok(response::Success::ContactChanged)
+}
@@ -0,0 +1,85 @@
+pub mod request {
+    use crate::modules::auth::middleware::UserAuth;
+
+    pub struct Payload {
+        pub auth: UserAuth,
+        pub id: String,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        ContactChanged,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::ContactChanged => (
+                    StatusCode::OK,
+                    Json(json!({ "message": "Contact changed successfully" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        ContactChangeNotFound,
+        ContactChangeNotPending,
+        ContactChangeExpired,
+        CurrentValueNotVerified,
+        NewValueNotVerified,
+        ContactInUse,
+        UnexpectedError,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::ContactChangeNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Contact change not found" })),
+                )
+                    .into_response(),
+                Self::ContactChangeNotPending => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Contact change is no longer pending" })),
+                )
+                    .into_response(),
+                Self::ContactChangeExpired => (
+                    StatusCode::BAD_REQUEST,
+                    Json(
+                        json!({ "error": "Contact change has expired, please request a new one" }),
+                    ),
+                )
+                    .into_response(),
+                Self::CurrentValueNotVerified => (
+                    StatusCode::FORBIDDEN,
+                    Json(json!({ "error": "Current value not verified" })),
+                )
+                    .into_response(),
+                Self::NewValueNotVerified => (
+                    StatusCode::FORBIDDEN,
+                    Json(json!({ "error": "New value not verified" })),
+                )
+                    .into_response(),
+                Self::ContactInUse => (
+                    StatusCode::CONFLICT,
+                    Json(json!({ "error": "Already in use by another account" })),
+                )
+                    .into_response(),
+                Self::UnexpectedError => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Sorry an error occurred" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
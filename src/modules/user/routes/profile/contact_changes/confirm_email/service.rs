@@ -0,0 +1,33 @@
+use super::types::{request, response};
+use crate::{
+    modules::{
+        auth::service::auth::hash_token,
+        user::repository::{self, ContactChangeStatus},
+    },
+    types::Context,
+};
+use chrono::Utc;
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let change = repository::find_contact_change_by_token_hash(
+        &ctx.db_conn.pool,
+        hash_token(&payload.query.token),
+    )
+    .await
+    .map_err(|_| response::Error::UnexpectedError)?
+    .ok_or(response::Error::InvalidLink)?;
+
+    if change.status != ContactChangeStatus::Pending {
+        return Err(response::Error::InvalidLink);
+    }
+
+    if Utc::now().naive_utc() > change.expires_at {
+        return Err(response::Error::ExpiredLink);
+    }
+
+    repository::verify_contact_change_new_value_by_id(&ctx.db_conn.pool, change.id)
+        .await
+        .map_err(|_| response::Error::UnexpectedError)
+        .map(|_| response::Success::EmailConfirmed)
+}
@@ -0,0 +1,63 @@
+pub mod request {
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    pub struct Query {
+        pub token: String,
+    }
+
+    pub struct Payload {
+        pub query: Query,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        EmailConfirmed,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::EmailConfirmed => (
+                    StatusCode::OK,
+                    Json(json!({ "message": "Email confirmed, you can now complete the change in the app" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        InvalidLink,
+        ExpiredLink,
+        UnexpectedError,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::InvalidLink => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Invalid link" })),
+                )
+                    .into_response(),
+                Self::ExpiredLink => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Link has expired, please request a new one" })),
+                )
+                    .into_response(),
+                Self::UnexpectedError => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Sorry an error occurred" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
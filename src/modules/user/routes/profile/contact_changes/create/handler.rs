@@ -0,0 +1,20 @@
+use super::service::service;
+use super::types::request;
+use crate::{
+    modules::auth::{middleware::UserAuth, service::auth::Device},
+    types::Context,
+};
+use axum::{
+    extract::{Json, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+pub async fn handler(
+    State(ctx): State<Arc<Context>>,
+    auth: UserAuth,
+    device: Device,
+    Json(body): Json<request::Body>,
+) -> impl IntoResponse {
+    service(ctx, request::Payload { auth, device, body }).await
+}
@@ -0,0 +1,111 @@
+use super::types::{request, response};
+use crate::{
+    modules::user::{
+        repository::{self, ContactKind},
+        service,
+    },
+    types::Context,
+};
+use chrono::Utc;
+use regex::Regex;
+use std::sync::Arc;
+use validator::ValidateEmail;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let user = payload.auth.user;
+    let kind = payload.body.kind;
+    let value = match kind {
+        ContactKind::PhoneNumber => payload.body.value.trim().to_string(),
+        ContactKind::Email => payload.body.value.trim().to_lowercase(),
+    };
+
+    let existing_user = match kind {
+        ContactKind::PhoneNumber => {
+            let regex = Regex::new(r"^\+234\d{10}$").expect("Invalid phone number regex");
+            if !regex.is_match(&value) {
+                return Err(response::Error::InvalidPhoneNumber);
+            }
+
+            repository::find_by_phone_number(&ctx.db_conn.pool, value.clone()).await
+        }
+        ContactKind::Email => {
+            if !value.validate_email() {
+                return Err(response::Error::InvalidEmail);
+            }
+
+            repository::find_by_email(&ctx.db_conn.pool, value.clone()).await
+        }
+    }
+    .map_err(|_| response::Error::UnexpectedError)?;
+
+    let previous_value = service::get_contact(&user, &kind);
+    if previous_value == value {
+        return Err(response::Error::SameAsCurrentValue);
+    }
+
+    if existing_user.is_some() {
+        return Err(response::Error::ContactInUse);
+    }
+
+    // NOTE: when the current value was never verified there's nothing worth protecting, so only the new value has to
+    // be verified. An email that was never confirmed is vouched for by the verified phone number instead, see
+    // `service::send_current_contact_otp`
+    let current_verified_at = match kind {
+        ContactKind::PhoneNumber if !user.is_verified => Some(Utc::now().naive_utc()),
+        ContactKind::Email if user.email_verified_at.is_none() && !user.is_verified => {
+            Some(Utc::now().naive_utc())
+        }
+        _ => None,
+    };
+
+    let (token, token_hash) = match kind {
+        ContactKind::Email => {
+            let (token, token_hash) = service::generate_contact_change_token();
+            (Some(token), Some(token_hash))
+        }
+        ContactKind::PhoneNumber => (None, None),
+    };
+
+    let mut tx = ctx.db_conn.pool.begin().await.map_err(|err| {
+        tracing::error!("Failed to start database transaction: {}", err);
+        response::Error::UnexpectedError
+    })?;
+
+    repository::cancel_pending_contact_changes_by_user_id(&mut *tx, user.id.clone())
+        .await
+        .map_err(|_| response::Error::UnexpectedError)?;
+
+    let change = repository::create_contact_change(
+        &mut *tx,
+        repository::CreateContactChangePayload {
+            user_id: user.id.clone(),
+            kind,
+            previous_value,
+            new_value: value,
+            token_hash,
+            current_verified_at,
+            ip_address: payload.device.ip_address,
+            user_agent: payload.device.user_agent,
+            expires_at: service::get_contact_change_expiry(),
+        },
+    )
+    .await
+    .map_err(|_| response::Error::UnexpectedError)?;
+
+    tx.commit().await.map_err(|err| {
+        tracing::error!("Failed to commit transaction: {}", err);
+        response::Error::UnexpectedError
+    })?;
+
+    if change.current_verified_at.is_none() {
+        service::send_current_contact_otp(ctx.clone(), user.clone(), &change)
+            .await
+            .map_err(|_| response::Error::FailedToSendVerification)?;
+    }
+
+    service::send_new_contact_verification(ctx.clone(), user, &change, token)
+        .await
+        .map_err(|_| response::Error::FailedToSendVerification)?;
+
+    Ok(response::Success::ContactChangeRequested(change))
+}
@@ -0,0 +1,87 @@
+pub mod request {
+    use crate::modules::{
+        auth::{middleware::UserAuth, service::auth::Device},
+        user::repository::ContactKind,
+    };
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    pub struct Body {
+        pub kind: ContactKind,
+        pub value: String,
+    }
+
+    pub struct Payload {
+        pub auth: UserAuth,
+        pub device: Device,
+        pub body: Body,
+    }
+}
+
+pub mod response {
+    use crate::modules::user::repository::ContactChange;
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        ContactChangeRequested(ContactChange),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::ContactChangeRequested(change) => {
+                    (StatusCode::CREATED, Json(json!(change))).into_response()
+                }
+            }
+        }
+    }
+
+    pub enum Error {
+        InvalidPhoneNumber,
+        InvalidEmail,
+        SameAsCurrentValue,
+        ContactInUse,
+        FailedToSendVerification,
+        UnexpectedError,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::InvalidPhoneNumber => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Phone number must be a nigerian phone number in international format (e.g: +234...)" })),
+                )
+                    .into_response(),
+                Self::InvalidEmail => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Invalid email" })),
+                )
+                    .into_response(),
+                Self::SameAsCurrentValue => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "New value is the same as the current one" })),
+                )
+                    .into_response(),
+                Self::ContactInUse => (
+                    StatusCode::CONFLICT,
+                    Json(json!({ "error": "Already in use by another account" })),
+                )
+                    .into_response(),
+                Self::FailedToSendVerification => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to send verification" })),
+                )
+                    .into_response(),
+                Self::UnexpectedError => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Sorry an error occurred" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
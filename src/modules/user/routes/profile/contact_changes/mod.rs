@@ -0,0 +1,21 @@
+mod commit;
+mod confirm_email;
+mod create;
+mod list;
+mod verify;
+
+use crate::types::Context;
+use axum::routing::Router;
+use std::sync::Arc;
+
+pub fn get_router() -> Router<Arc<Context>> {
+    Router::new().nest(
+        "/contact-changes",
+        Router::new()
+            .nest("/", list::get_router())
+            .nest("/", create::get_router())
+            .nest("/", verify::get_router())
+            .nest("/", commit::get_router())
+            .nest("/", confirm_email::get_router()),
+    )
+}
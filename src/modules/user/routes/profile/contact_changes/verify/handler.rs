@@ -0,0 +1,17 @@
+use super::service::service;
+use super::types::request;
+use crate::{modules::auth::middleware::UserAuth, types::Context};
+use axum::{
+    extract::{Json, Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+pub async fn handler(
+    State(ctx): State<Arc<Context>>,
+    auth: UserAuth,
+    Path(id): Path<String>,
+    Json(body): Json<request::Body>,
+) -> impl IntoResponse {
+    service(ctx, request::Payload { auth, id, body }).await
+}
@@ -0,0 +1,80 @@
+use super::types::{request, response};
+use crate::{
+    modules::user::{
+        repository::{self, ContactChangeStatus, ContactKind},
+        service::{self, ContactChangeSide},
+    },
+    types::Context,
+};
+use chrono::Utc;
+use std::sync::Arc;
+
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let user = payload.auth.user;
+
+    let change = repository::find_contact_change_by_id(&ctx.db_conn.pool, payload.id)
+        .await
+        .map_err(|_| response::Error::UnexpectedError)?
+        .filter(|change| change.user_id == user.id)
+        .ok_or(response::Error::ContactChangeNotFound)?;
+
+    if change.status != ContactChangeStatus::Pending {
+        return Err(response::Error::ContactChangeNotPending);
+    }
+
+    if Utc::now().naive_utc() > change.expires_at {
+        return Err(response::Error::ContactChangeExpired);
+    }
+
+    let side = match payload.body.target {
+        request::Target::Current => {
+            if change.current_verified_at.is_some() {
+                return Err(response::Error::AlreadyVerified);
+            }
+            ContactChangeSide::Current
+        }
+        request::Target::New => {
+            if change.kind == ContactKind::Email {
+                return Err(response::Error::UseEmailLink);
+            }
+            if change.new_verified_at.is_some() {
+                return Err(response::Error::AlreadyVerified);
+            }
+            ContactChangeSide::New
+        }
+    };
+
+    let mut tx = ctx.db_conn.pool.begin().await.map_err(|err| {
+        tracing::error!("Failed to start database transaction: {}", err);
+        response::Error::UnexpectedError
+    })?;
+
+    if let Err(err) =
+        service::verify_contact_otp(ctx.clone(), &mut tx, user, &change, side, payload.body.otp)
+            .await
+    {
+        if let service::Error::InvalidOtp | service::Error::TooManyAttempts = err {
+            // NOTE: keeps the failed attempt, see `otp::verify`
+            if let Err(err) = tx.commit().await {
+                tracing::error!("Failed to commit transaction: {}", err);
+            }
+        }
+
+        return Err(match err {
+            service::Error::TooManyAttempts => response::Error::TooManyOtpAttempts,
+            service::Error::InvalidOtp | service::Error::ExpiredOtp => {
+                response::Error::InvalidOrExpiredOtp
+            }
+            service::Error::NotSent | service::Error::UnexpectedError => {
+                response::Error::UnexpectedError
+            }
+        });
+    }
+
+    tx.commit().await.map_err(|err| {
+        tracing::error!("Failed to commit transaction: {}", err);
+        response::Error::UnexpectedError
+    })?;
+
+    Ok(response::Success::ContactVerified)
+}
@@ -0,0 +1,107 @@
+pub mod request {
+    use crate::modules::auth::middleware::UserAuth;
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    pub enum Target {
+        #[serde(rename = "CURRENT")]
+        Current,
+        #[serde(rename = "NEW")]
+        New,
+    }
+
+    #[derive(Deserialize)]
+    pub struct Body {
+        pub target: Target,
+        pub otp: String,
+    }
+
+    pub struct Payload {
+        pub auth: UserAuth,
+        pub id: String,
+        pub body: Body,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    pub enum Success {
+        ContactVerified,
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::ContactVerified => (
+                    StatusCode::OK,
+                    Json(json!({ "message": "Verification successful" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        ContactChangeNotFound,
+        ContactChangeNotPending,
+        ContactChangeExpired,
+        AlreadyVerified,
+        UseEmailLink,
+        InvalidOrExpiredOtp,
+        TooManyOtpAttempts,
+        UnexpectedError,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::ContactChangeNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Contact change not found" })),
+                )
+                    .into_response(),
+                Self::ContactChangeNotPending => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Contact change is no longer pending" })),
+                )
+                    .into_response(),
+                Self::ContactChangeExpired => (
+                    StatusCode::BAD_REQUEST,
+                    Json(
+                        json!({ "error": "Contact change has expired, please request a new one" }),
+                    ),
+                )
+                    .into_response(),
+                Self::AlreadyVerified => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Already verified" })),
+                )
+                    .into_response(),
+                Self::UseEmailLink => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Please use the link sent to the new email" })),
+                )
+                    .into_response(),
+                Self::InvalidOrExpiredOtp => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "error": "Invalid or expired OTP" })),
+                )
+                    .into_response(),
+                Self::TooManyOtpAttempts => (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    Json(json!({ "error": "Too many attempts, please request a new OTP" })),
+                )
+                    .into_response(),
+                Self::UnexpectedError => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Sorry an error occurred" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
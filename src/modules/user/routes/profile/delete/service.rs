@@ -1,51 +1,38 @@
 use super::types::{request, response};
 use crate::{
-    modules::{ad::repository, storage},
+    modules::user::{repository, service},
     types::Context,
 };
 use std::sync::Arc;
 
+// NOTE: nothing is removed right away, the account is only purged once the grace period is over so that the user can
+// still change their mind, see `user::job`
 pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
-    let mut tx = ctx.db_conn.pool.begin().await.map_err(|err| {
-        tracing::error!("Failed to start database transaction: {:?}", err);
-        response::Error::FailedToDeleteAccount
-    })?;
+    if repository::find_pending_account_deletion_by_user_id(
+        &ctx.db_conn.pool,
+        payload.auth.user.id.clone(),
+    )
+    .await
+    .map_err(|_| response::Error::FailedToDeleteAccount)?
+    .is_some()
+    {
+        return Err(response::Error::DeletionAlreadyScheduled);
+    }
 
-    let kitchen = kitchen::repository::find_by_owner_id(&mut *tx, auth.user.id.clone())
+    if !service::find_funded_wallets(ctx.clone(), payload.auth.user.id.clone())
         .await
         .map_err(|_| response::Error::FailedToDeleteAccount)?
-        .ok_or(response::Error::AccountNotFound)?;
+        .is_empty()
+    {
+        return Err(response::Error::WalletNotEmpty);
+    }
 
-    kitchen::repository::update_by_id(
-        &mut *tx,
-        kitchen.id,
-        kitchen::repository::UpdateKitchenPayload {
-            name: None,
-            address: None,
-            phone_number: None,
-            r#type: None,
-            opening_time: None,
-            closing_time: None,
-            preparation_time: None,
-            delivery_time: None,
-            cover_image: None,
-            rating: None,
-            likes: None,
-            is_available: Some(false),
-        },
+    repository::create_account_deletion(
+        &ctx.db_conn.pool,
+        payload.auth.user.id,
+        service::get_account_deletion_date(&ctx),
     )
     .await
-    .map_err(|_| response::Error::FailedToDeleteAccount)?;
-
-    repository::delete_by_id(&mut *tx, auth.user.id).await
-        .map_err(|_| response::Error:FailedToDeleteAccount)
-        .map(|_| response::Success::AccountDeleted)?;
-
-    tx.commit()
-        .await
-        .map(|_| response::Success::Successful)
-        .map_err(|err| {
-            tracing::error!("Failed to commit database transaction: {:?}", err);
-            response::Error::ServerError
-        })
+    .map_err(|_| response::Error::FailedToDeleteAccount)
+    .map(response::Success::AccountDeletionScheduled)
 }
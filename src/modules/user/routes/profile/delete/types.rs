@@ -7,19 +7,23 @@ pub mod request {
 }
 
 pub mod response {
+    use crate::modules::user::repository::AccountDeletion;
     use axum::{extract::Json, http::StatusCode, response::IntoResponse};
     use serde_json::json;
 
     pub enum Success {
-        AdDeleted,
+        AccountDeletionScheduled(AccountDeletion),
     }
 
     impl IntoResponse for Success {
         fn into_response(self) -> axum::response::Response {
             match self {
-                Self::AdDeleted => (
-                    StatusCode::OK,
-                    Json(json!({ "message": "Ad deleted successfully" })),
+                Self::AccountDeletionScheduled(deletion) => (
+                    StatusCode::ACCEPTED,
+                    Json(json!({
+                        "message": "Account scheduled for deletion",
+                        "scheduled_for": deletion.scheduled_for,
+                    })),
                 )
                     .into_response(),
             }
@@ -27,22 +31,29 @@ pub mod response {
     }
 
     pub enum Error {
+        DeletionAlreadyScheduled,
+        WalletNotEmpty,
         FailedToDeleteAccount,
     }
 
     impl IntoResponse for Error {
         fn into_response(self) -> axum::response::Response {
             match self {
+                Self::DeletionAlreadyScheduled => (
+                    StatusCode::CONFLICT,
+                    Json(json!({ "error": "Account is already scheduled for deletion" })),
+                )
+                    .into_response(),
+                Self::WalletNotEmpty => (
+                    StatusCode::CONFLICT,
+                    Json(json!({ "error": "Please withdraw your remaining wallet balance first" })),
+                )
+                    .into_response(),
                 Self::FailedToDeleteAccount => (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     Json(json!({ "error": "Failed to delete account" })),
                 )
                     .into_response(),
-                Self::AdNotFound => (
-                    StatusCode::NOT_FOUND,
-                    Json(json!({ "error": "Ad not found" })),
-                )
-                    .into_response(),
             }
         }
     }
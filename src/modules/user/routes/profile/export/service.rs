@@ -0,0 +1,153 @@
+use super::types::{request, response};
+use crate::{
+    modules::{auth, cart, kitchen, notification, order, transaction, user, wallet},
+    types::Context,
+};
+use chrono::Utc;
+use std::sync::Arc;
+
+// Everything we hold about the user, for them to take with them
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    let user = payload.auth.user;
+    let db = &ctx.db_conn.pool;
+
+    let mut wallets = vec![];
+    if let Some(wallet) = wallet::repository::find_by_owner_id(db, user.id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToExportData)?
+    {
+        wallets.push(wallet);
+    }
+
+    let kitchen = kitchen::repository::find_by_owner_id(db, user.id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToExportData)?;
+
+    if let Some(kitchen) = kitchen.clone() {
+        if let Some(wallet) = wallet::repository::find_by_kitchen_id(db, kitchen.id)
+            .await
+            .map_err(|_| response::Error::FailedToExportData)?
+        {
+            wallets.push(wallet);
+        }
+    }
+
+    let kitchen_membership = kitchen::repository::find_member_by_user_id(db, user.id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToExportData)?;
+
+    let orders = order::repository::find_many_by_owner_id(db, user.id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToExportData)?;
+
+    let favourite_orders = order::repository::find_many_favourites_by_owner_id(db, user.id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToExportData)?;
+
+    let carts = cart::repository::find_many_by_owner_id(db, user.id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToExportData)?;
+
+    let transactions = transaction::repository::find_many_by_user_id(db, user.id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToExportData)?;
+
+    let identities = auth::repository::identity::find_many_by_user_id(db, user.id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToExportData)?;
+
+    let sessions = auth::repository::session::find_many_active_by_user_id(db, user.id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToExportData)?
+        .into_iter()
+        .map(|session| response::ExportedSession {
+            id: session.id,
+            user_agent: session.user_agent,
+            ip_address: session.ip_address,
+            created_at: session.created_at,
+            updated_at: session.updated_at,
+        })
+        .collect();
+
+    let otps = auth::repository::otp::find_many_by_user_id(db, user.id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToExportData)?
+        .into_iter()
+        .map(|otp| response::ExportedOtp {
+            id: otp.id,
+            purpose: otp.purpose,
+            provider: otp.provider,
+            attempts: otp.attempts,
+            delivery_attempts: otp.delivery_attempts,
+            sent_at: otp.sent_at,
+            expires_at: otp.expires_at,
+            created_at: otp.created_at,
+        })
+        .collect();
+
+    let security_events =
+        auth::repository::security_event::find_many_by_user_id(db, user.id.clone())
+            .await
+            .map_err(|_| response::Error::FailedToExportData)?;
+
+    let notifications = notification::repository::inbox::find_all_by_user_id(db, user.id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToExportData)?;
+
+    let notification_settings =
+        notification::repository::setting::find_many_by_user_id(db, user.id.clone())
+            .await
+            .map_err(|_| response::Error::FailedToExportData)?;
+
+    let notification_preferences =
+        notification::repository::preference::find_many_by_user_id(db, user.id.clone())
+            .await
+            .map_err(|_| response::Error::FailedToExportData)?;
+
+    let quiet_hours = notification::repository::quiet_hours::find_by_user_id(db, user.id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToExportData)?;
+
+    let push_tokens =
+        notification::repository::push_token::find_many_by_user_id(db, user.id.clone())
+            .await
+            .map_err(|_| response::Error::FailedToExportData)?;
+
+    let sms_messages = notification::repository::sms::find_many_by_user_id(db, user.id.clone())
+        .await
+        .map_err(|_| response::Error::FailedToExportData)?;
+
+    let contact_changes =
+        user::repository::find_many_contact_changes_by_user_id(db, user.id.clone())
+            .await
+            .map_err(|_| response::Error::FailedToExportData)?;
+
+    let account_deletions =
+        user::repository::find_many_account_deletions_by_user_id(db, user.id.clone())
+            .await
+            .map_err(|_| response::Error::FailedToExportData)?;
+
+    Ok(response::Success::Export(response::Export {
+        user,
+        wallets,
+        kitchen,
+        kitchen_membership,
+        orders,
+        favourite_orders,
+        carts,
+        transactions,
+        identities,
+        sessions,
+        otps,
+        security_events,
+        notifications,
+        notification_settings,
+        notification_preferences,
+        quiet_hours,
+        push_tokens,
+        sms_messages,
+        contact_changes,
+        account_deletions,
+        exported_at: Utc::now().naive_utc(),
+    }))
+}
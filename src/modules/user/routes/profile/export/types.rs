@@ -0,0 +1,115 @@
+pub mod request {
+    pub use crate::modules::auth::middleware::UserAuth;
+
+    pub struct Payload {
+        pub auth: UserAuth,
+    }
+}
+
+pub mod response {
+    use crate::modules::{
+        auth::repository::{
+            identity::Identity, otp::OtpDeliveryAttempts, security_event::SecurityEvent,
+        },
+        cart::repository::Cart,
+        kitchen::repository::{Kitchen, KitchenMember},
+        notification::repository::{
+            inbox::InboxNotification, preference::NotificationPreference, push_token::PushToken,
+            quiet_hours::QuietHours, setting::NotificationSetting, sms::SmsMessage,
+        },
+        order::repository::{FavouriteOrder, Order},
+        transaction::repository::Transaction,
+        user::repository::{AccountDeletion, ContactChange, User},
+        wallet::repository::Wallet,
+    };
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use chrono::NaiveDateTime;
+    use serde::Serialize;
+    use serde_json::json;
+
+    // NOTE: tokens are left out, only what the user needs to recognise the device
+    #[derive(Serialize)]
+    pub struct ExportedSession {
+        pub id: String,
+        pub user_agent: Option<String>,
+        pub ip_address: Option<String>,
+        pub created_at: NaiveDateTime,
+        pub updated_at: Option<NaiveDateTime>,
+    }
+
+    // NOTE: the code and its hash are left out, the rest says when and how one was sent
+    #[derive(Serialize)]
+    pub struct ExportedOtp {
+        pub id: String,
+        pub purpose: String,
+        pub provider: String,
+        pub attempts: i32,
+        pub delivery_attempts: OtpDeliveryAttempts,
+        pub sent_at: NaiveDateTime,
+        pub expires_at: NaiveDateTime,
+        pub created_at: NaiveDateTime,
+    }
+
+    #[derive(Serialize)]
+    pub struct Export {
+        pub user: User,
+        pub wallets: Vec<Wallet>,
+        pub kitchen: Option<Kitchen>,
+        pub kitchen_membership: Option<KitchenMember>,
+        pub orders: Vec<Order>,
+        pub favourite_orders: Vec<FavouriteOrder>,
+        pub carts: Vec<Cart>,
+        pub transactions: Vec<Transaction>,
+        pub identities: Vec<Identity>,
+        pub sessions: Vec<ExportedSession>,
+        pub otps: Vec<ExportedOtp>,
+        pub security_events: Vec<SecurityEvent>,
+        pub notifications: Vec<InboxNotification>,
+        pub notification_settings: Vec<NotificationSetting>,
+        pub notification_preferences: Vec<NotificationPreference>,
+        pub quiet_hours: Option<QuietHours>,
+        pub push_tokens: Vec<PushToken>,
+        pub sms_messages: Vec<SmsMessage>,
+        pub contact_changes: Vec<ContactChange>,
+        pub account_deletions: Vec<AccountDeletion>,
+        pub exported_at: NaiveDateTime,
+    }
+
+    pub enum Success {
+        Export(Export),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::Export(export) => (
+                    StatusCode::OK,
+                    [(
+                        axum::http::header::CONTENT_DISPOSITION,
+                        "attachment; filename=\"foodhut-export.json\"",
+                    )],
+                    Json(json!(export)),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToExportData,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToExportData => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to export data" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
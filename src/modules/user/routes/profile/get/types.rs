@@ -1,5 +1,5 @@
 pub mod request {
-    pub use crate::modules::auth::middleware::Auth;
+    pub use crate::modules::auth::middleware::UserAuth as Auth;
 
     pub struct Payload {
         pub auth: Auth,
@@ -1,3 +1,7 @@
+mod cancel_deletion;
+mod contact_changes;
+mod delete;
+mod export;
 mod get;
 mod update;
 mod update_cover_image;
@@ -12,6 +16,10 @@ pub fn get_router() -> Router<Arc<Context>> {
         Router::new()
             .nest("/", get::get_router())
             .nest("/", update::get_router())
-            .nest("/", update_cover_image::get_router()),
+            .nest("/", update_cover_image::get_router())
+            .nest("/", delete::get_router())
+            .nest("/", cancel_deletion::get_router())
+            .nest("/", export::get_router())
+            .nest("/", contact_changes::get_router()),
     )
 }
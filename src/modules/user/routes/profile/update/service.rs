@@ -5,12 +5,13 @@ use std::sync::Arc;
 
 pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
     let update_payload = repository::UpdateUserPayload {
-        email: payload.body.email,
-        phone_number: payload.body.phone_number,
+        email: None,
+        phone_number: None,
         first_name: payload.body.first_name,
         last_name: payload.body.last_name,
         has_kitchen: None,
         profile_picture: None,
+        locale: payload.body.locale,
     };
 
     repository::update_by_id(&ctx.db_conn.pool, payload.auth.user.id, update_payload)
@@ -1,13 +1,13 @@
 pub mod request {
-    use crate::modules::auth::middleware::Auth;
+    use crate::modules::{auth::middleware::Auth, user::repository::Locale};
     use serde::Deserialize;
 
+    // NOTE: email and phone number changes have to be re-verified, see `contact_changes`
     #[derive(Deserialize)]
     pub struct Body {
-        pub email: Option<String>,
-        pub phone_number: Option<String>,
         pub first_name: Option<String>,
         pub last_name: Option<String>,
+        pub locale: Option<Locale>,
     }
 
     pub struct Payload {
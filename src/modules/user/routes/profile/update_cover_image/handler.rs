@@ -1,11 +1,11 @@
 use super::{service::service, types::request};
-use crate::{modules::auth::middleware::Auth, types::Context};
+use crate::{modules::auth::middleware::UserAuth, types::Context};
 use axum::{extract::State, response::IntoResponse};
 use axum_typed_multipart::TypedMultipart;
 use std::sync::Arc;
 
 pub async fn handler(
-    auth: Auth,
+    auth: UserAuth,
     State(ctx): State<Arc<Context>>,
     TypedMultipart(body): TypedMultipart<request::Body>,
 ) -> impl IntoResponse {
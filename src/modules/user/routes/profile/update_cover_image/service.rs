@@ -18,11 +18,15 @@ pub async fn service(ctx: Arc<Context>, mut payload: request::Payload) -> respon
 
     let profile_picture = match payload.auth.user.profile_picture.0 {
         Some(profile_picture) => {
-            storage::update_file(ctx.storage.clone(), profile_picture, buf).await
+            storage::update_image(ctx.storage.clone(), profile_picture, buf).await
         }
-        None => storage::upload_file(ctx.storage.clone(), buf).await,
+        None => storage::upload_image(ctx.storage.clone(), buf).await,
     }
-    .map_err(|_| response::Error::FailedToUpdateProfilePicture)?;
+    .map_err(|err| match err {
+        storage::Error::UnsupportedImage => response::Error::UnsupportedImage,
+        storage::Error::InvalidImageDimensions => response::Error::InvalidImageDimensions,
+        _ => response::Error::FailedToUpdateProfilePicture,
+    })?;
 
     repository::update_by_id(
         &ctx.db_conn.pool,
@@ -34,6 +38,7 @@ pub async fn service(ctx: Arc<Context>, mut payload: request::Payload) -> respon
             last_name: None,
             has_kitchen: None,
             profile_picture: Some(profile_picture),
+            locale: None,
         },
     )
     .await
@@ -1,5 +1,5 @@
 pub mod request {
-    use crate::modules::auth::middleware::Auth;
+    use crate::modules::auth::middleware::UserAuth as Auth;
     use axum_typed_multipart::{FieldData, TryFromMultipart};
     use tempfile::NamedTempFile;
 
@@ -36,11 +36,23 @@ pub mod response {
 
     pub enum Error {
         FailedToUpdateProfilePicture,
+        UnsupportedImage,
+        InvalidImageDimensions,
     }
 
     impl IntoResponse for Error {
         fn into_response(self) -> axum::response::Response {
             match self {
+                Self::UnsupportedImage => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "message": "Only JPEG, PNG and WebP images are supported" })),
+                )
+                    .into_response(),
+                Self::InvalidImageDimensions => (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "message": "Image must be between 64 and 6000 pixels wide and tall" })),
+                )
+                    .into_response(),
                 Self::FailedToUpdateProfilePicture => (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     Json(json!({ "message": "Failed to update profile picture" })),
@@ -0,0 +1,19 @@
+use super::{service::service, types::request};
+use crate::{
+    modules::auth::{middleware::RequirePermission, permission},
+    types::Context,
+};
+use axum::{
+    extract::{Json, Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+pub async fn handler(
+    _: RequirePermission<permission::ManageRoles>,
+    State(ctx): State<Arc<Context>>,
+    Path(id): Path<String>,
+    Json(body): Json<request::Body>,
+) -> impl IntoResponse {
+    service(ctx, request::Payload { id, body }).await
+}
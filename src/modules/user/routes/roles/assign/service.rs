@@ -0,0 +1,13 @@
+use super::types::{request, response};
+use crate::{modules::user::repository, types::Context};
+use std::sync::Arc;
+
+// NOTE: roles are carried in access tokens, so a new role only applies once the user's current token is refreshed.
+// Kitchen access comes from kitchen memberships, which the kitchen's owner manages
+pub async fn service(ctx: Arc<Context>, payload: request::Payload) -> response::Response {
+    repository::update_role_by_id(&ctx.db_conn.pool, payload.id, payload.body.role)
+        .await
+        .map_err(|_| response::Error::FailedToAssignRole)?
+        .ok_or(response::Error::UserNotFound)
+        .map(response::Success::RoleAssigned)
+}
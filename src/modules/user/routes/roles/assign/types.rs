@@ -0,0 +1,57 @@
+pub mod request {
+    use crate::modules::user::repository::Role;
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    pub struct Body {
+        pub role: Role,
+    }
+
+    pub struct Payload {
+        pub id: String,
+        pub body: Body,
+    }
+}
+
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde_json::json;
+
+    use crate::modules::user::repository::User;
+
+    pub enum Success {
+        RoleAssigned(User),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::RoleAssigned(user) => (StatusCode::OK, Json(json!(user))).into_response(),
+            }
+        }
+    }
+
+    pub enum Error {
+        FailedToAssignRole,
+        UserNotFound,
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::FailedToAssignRole => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to assign role" })),
+                )
+                    .into_response(),
+                Self::UserNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "User not found" })),
+                )
+                    .into_response(),
+            }
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
@@ -0,0 +1,7 @@
+use super::service::service;
+use crate::modules::auth::{middleware::RequirePermission, permission};
+use axum::response::IntoResponse;
+
+pub async fn handler(_: RequirePermission<permission::ManageRoles>) -> impl IntoResponse {
+    service()
+}
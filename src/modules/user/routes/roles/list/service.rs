@@ -0,0 +1,14 @@
+use super::types::response;
+use crate::modules::auth::permission;
+
+pub fn service() -> response::Response {
+    Ok(response::Success::Roles(
+        permission::ROLES
+            .into_iter()
+            .map(|role| response::RoleWithPermissions {
+                permissions: permission::get_permissions(&role),
+                role,
+            })
+            .collect(),
+    ))
+}
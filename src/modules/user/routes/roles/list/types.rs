@@ -0,0 +1,35 @@
+pub mod response {
+    use axum::{extract::Json, http::StatusCode, response::IntoResponse};
+    use serde::Serialize;
+    use serde_json::json;
+
+    use crate::modules::{auth::permission::Permission, user::repository::Role};
+
+    #[derive(Serialize)]
+    pub struct RoleWithPermissions {
+        pub role: Role,
+        pub permissions: Vec<Permission>,
+    }
+
+    pub enum Success {
+        Roles(Vec<RoleWithPermissions>),
+    }
+
+    impl IntoResponse for Success {
+        fn into_response(self) -> axum::response::Response {
+            match self {
+                Self::Roles(roles) => (StatusCode::OK, Json(json!(roles))).into_response(),
+            }
+        }
+    }
+
+    pub enum Error {}
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> axum::response::Response {
+            unreachable!()
+        }
+    }
+
+    pub type Response = Result<Success, Error>;
+}
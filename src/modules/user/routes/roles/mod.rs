@@ -0,0 +1,12 @@
+mod assign;
+mod list;
+
+use crate::types::Context;
+use axum::routing::Router;
+use std::sync::Arc;
+
+pub fn get_router() -> Router<Arc<Context>> {
+    Router::new()
+        .nest("/", list::get_router())
+        .nest("/", assign::get_router())
+}
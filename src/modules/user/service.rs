@@ -0,0 +1,410 @@
+use super::repository::{self, AccountDeletion, ContactChange, ContactKind, User};
+use crate::{
+    modules::{
+        auth::{
+            self,
+            repository::otp::OtpChannel,
+            service::{
+                auth::{generate_token, hash_token},
+                otp,
+            },
+        },
+        kitchen,
+        notification::{
+            self,
+            service::{Backend, Category, Notification},
+        },
+        order, storage, wallet,
+    },
+    types::Context,
+};
+use bigdecimal::BigDecimal;
+use chrono::{Duration, Utc};
+use sqlx::{Acquire, Postgres, Transaction};
+use std::sync::Arc;
+
+// How long (in minutes) both sides have to be verified before the change has to be requested again
+pub const CONTACT_CHANGE_VALIDITY: i64 = 30;
+
+pub enum Error {
+    NotSent,
+    InvalidOtp,
+    ExpiredOtp,
+    TooManyAttempts,
+    UnexpectedError,
+}
+
+pub enum ContactChangeSide {
+    Current,
+    New,
+}
+
+// NOTE: every change gets its own purposes, so codes from an earlier request can't verify a later one
+fn get_otp_purpose(change: &ContactChange, side: &ContactChangeSide) -> String {
+    match side {
+        ContactChangeSide::Current => format!("user.contact_change.{}.current", change.id),
+        ContactChangeSide::New => format!("user.contact_change.{}.new", change.id),
+    }
+}
+
+fn get_otp_channels(kind: &ContactKind) -> Vec<OtpChannel> {
+    match kind {
+        ContactKind::PhoneNumber => vec![OtpChannel::Sms, OtpChannel::Whatsapp, OtpChannel::Voice],
+        ContactKind::Email => vec![OtpChannel::Email],
+    }
+}
+
+// The user as they'd be once the change is committed, which is who the new side's OTP goes to
+fn with_new_contact(user: User, change: &ContactChange) -> User {
+    match change.kind {
+        ContactKind::PhoneNumber => User {
+            phone_number: change.new_value.clone(),
+            ..user
+        },
+        ContactKind::Email => User {
+            email: change.new_value.clone(),
+            ..user
+        },
+    }
+}
+
+pub fn get_contact(user: &User, kind: &ContactKind) -> String {
+    match kind {
+        ContactKind::PhoneNumber => user.phone_number.clone(),
+        ContactKind::Email => user.email.clone(),
+    }
+}
+
+pub fn generate_contact_change_token() -> (String, String) {
+    let token = generate_token();
+    let token_hash = hash_token(&token);
+    (token, token_hash)
+}
+
+pub fn get_contact_change_expiry() -> chrono::NaiveDateTime {
+    Utc::now().naive_utc() + Duration::minutes(CONTACT_CHANGE_VALIDITY)
+}
+
+// An email that was never confirmed proves nothing, so the code goes to the (verified) phone number instead
+fn get_current_contact_kind(user: &User, change: &ContactChange) -> ContactKind {
+    match change.kind {
+        ContactKind::Email if user.email_verified_at.is_none() => ContactKind::PhoneNumber,
+        _ => change.kind.clone(),
+    }
+}
+
+pub async fn send_current_contact_otp(
+    ctx: Arc<Context>,
+    user: User,
+    change: &ContactChange,
+) -> Result<(), Error> {
+    let kind = get_current_contact_kind(&user, change);
+
+    otp::send_over_channels(
+        ctx,
+        user,
+        get_otp_purpose(change, &ContactChangeSide::Current),
+        get_otp_channels(&kind),
+    )
+    .await
+    .map(|_| ())
+    .map_err(|_| Error::NotSent)
+}
+
+// A new phone number gets an OTP while a new email gets a link, see `confirm_email`
+pub async fn send_new_contact_verification(
+    ctx: Arc<Context>,
+    user: User,
+    change: &ContactChange,
+    token: Option<String>,
+) -> Result<(), Error> {
+    let user = with_new_contact(user, change);
+
+    match (&change.kind, token) {
+        (ContactKind::PhoneNumber, _) => otp::send_over_channels(
+            ctx,
+            user,
+            get_otp_purpose(change, &ContactChangeSide::New),
+            get_otp_channels(&change.kind),
+        )
+        .await
+        .map(|_| ())
+        .map_err(|_| Error::NotSent),
+        (ContactKind::Email, Some(token)) => {
+            let link = format!(
+                "{}/api/users/profile/contact-changes/confirm-email?token={}",
+                ctx.app.url, token
+            );
+            notification::service::send(
+                ctx,
+                Notification::email_change_requested(user, link, CONTACT_CHANGE_VALIDITY as i32),
+                Category::Transactional,
+                Backend::Email,
+            )
+            .await
+            .map_err(|_| Error::NotSent)
+        }
+        (ContactKind::Email, None) => Err(Error::UnexpectedError),
+    }
+}
+
+pub async fn verify_contact_otp(
+    ctx: Arc<Context>,
+    tx: &mut Transaction<'_, Postgres>,
+    user: User,
+    change: &ContactChange,
+    side: ContactChangeSide,
+    code: String,
+) -> Result<(), Error> {
+    let user = match side {
+        ContactChangeSide::Current => user,
+        ContactChangeSide::New => with_new_contact(user, change),
+    };
+
+    otp::verify(ctx, tx, user, get_otp_purpose(change, &side), code)
+        .await
+        .map_err(|err| match err {
+            otp::VerificationError::Expired => Error::ExpiredOtp,
+            otp::VerificationError::InvalidOtp => Error::InvalidOtp,
+            otp::VerificationError::TooManyAttempts => Error::TooManyAttempts,
+            otp::VerificationError::UnexpectedError => Error::UnexpectedError,
+        })?;
+
+    match side {
+        ContactChangeSide::Current => {
+            repository::verify_contact_change_current_value_by_id(&mut **tx, change.id.clone())
+                .await
+        }
+        ContactChangeSide::New => {
+            repository::verify_contact_change_new_value_by_id(&mut **tx, change.id.clone()).await
+        }
+    }
+    .map_err(|_| Error::UnexpectedError)
+}
+
+// Queues the notice for the previous phone number or email, pass the transaction committing the change
+pub async fn notify_previous_contact<'a, A: Acquire<'a, Database = Postgres>>(
+    a: A,
+    user: User,
+    kind: ContactKind,
+) -> Result<(), Error> {
+    let backend = match kind {
+        ContactKind::PhoneNumber => Backend::Sms,
+        ContactKind::Email => Backend::Email,
+    };
+
+    notification::service::enqueue(
+        a,
+        Notification::contact_changed(user, kind),
+        Category::Transactional,
+        vec![backend],
+    )
+    .await
+    .map_err(|_| Error::UnexpectedError)
+}
+
+pub fn get_account_deletion_date(ctx: &Context) -> chrono::NaiveDateTime {
+    Utc::now().naive_utc() + Duration::days(ctx.auth.account_deletion_grace_period)
+}
+
+// The wallets a user would be leaving money behind in, which has to be withdrawn before the account can be deleted
+pub async fn find_funded_wallets(
+    ctx: Arc<Context>,
+    user_id: String,
+) -> Result<Vec<wallet::repository::Wallet>, Error> {
+    let mut wallets = vec![];
+
+    if let Some(wallet) = wallet::repository::find_by_owner_id(&ctx.db_conn.pool, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?
+    {
+        wallets.push(wallet);
+    }
+
+    if let Some(kitchen) = kitchen::repository::find_by_owner_id(&ctx.db_conn.pool, user_id)
+        .await
+        .map_err(|_| Error::UnexpectedError)?
+    {
+        if let Some(wallet) = wallet::repository::find_by_kitchen_id(&ctx.db_conn.pool, kitchen.id)
+            .await
+            .map_err(|_| Error::UnexpectedError)?
+        {
+            wallets.push(wallet);
+        }
+    }
+
+    Ok(wallets
+        .into_iter()
+        .filter(|wallet| wallet.balance > BigDecimal::from(0))
+        .collect())
+}
+
+// Runs once the grace period is over. The user row, orders, transactions and wallets are kept for accounting, everything
+// else that identifies the person is either cleared or removed
+pub async fn purge_account(ctx: Arc<Context>, deletion: AccountDeletion) -> Result<(), Error> {
+    let user_id = deletion.user_id.clone();
+
+    // NOTE: money can still come in during the grace period (e.g refunds), it stays in the wallet for finance to settle
+    for wallet in find_funded_wallets(ctx.clone(), user_id.clone()).await? {
+        tracing::warn!(
+            "Wallet {} of deleted user {} still holds {}",
+            wallet.id,
+            user_id,
+            wallet.balance
+        );
+    }
+
+    let mut tx = ctx.db_conn.pool.begin().await.map_err(|err| {
+        tracing::error!("Failed to start database transaction: {}", err);
+        Error::UnexpectedError
+    })?;
+
+    if let Some(kitchen) = kitchen::repository::find_by_owner_id(&mut *tx, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?
+    {
+        kitchen::repository::update_by_id(
+            &mut *tx,
+            kitchen.id,
+            kitchen::repository::UpdateKitchenPayload {
+                name: None,
+                address: None,
+                phone_number: None,
+                r#type: None,
+                opening_time: None,
+                closing_time: None,
+                preparation_time: None,
+                delivery_time: None,
+                cover_image: None,
+                rating: None,
+                likes: None,
+                latitude: None,
+                longitude: None,
+                delivery_radius: None,
+                is_available: Some(false),
+            },
+        )
+        .await
+        .map_err(|_| Error::UnexpectedError)?;
+    }
+
+    let profile_picture = repository::find_by_id(&mut *tx, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?
+        .and_then(|user| user.profile_picture.0);
+
+    kitchen::repository::delete_member_by_user_id(&mut *tx, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?;
+
+    let session_ids = auth::repository::session::delete_many_by_user_id(&mut *tx, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?;
+
+    notification::repository::push_token::delete_many_by_user_id(&mut *tx, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?;
+
+    notification::repository::inbox::delete_many_by_user_id(&mut *tx, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?;
+
+    notification::repository::outbox::delete_many_by_user_id(&mut *tx, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?;
+
+    notification::repository::setting::delete_many_by_user_id(&mut *tx, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?;
+
+    notification::repository::preference::delete_many_by_user_id(&mut *tx, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?;
+
+    notification::repository::quiet_hours::delete_by_user_id(&mut *tx, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?;
+
+    notification::repository::campaign::delete_many_recipients_by_user_id(
+        &mut *tx,
+        user_id.clone(),
+    )
+    .await
+    .map_err(|_| Error::UnexpectedError)?;
+
+    notification::repository::sms::anonymise_many_by_user_id(&mut *tx, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?;
+
+    auth::repository::identity::delete_many_by_user_id(&mut *tx, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?;
+
+    auth::repository::magic_link::delete_many_by_user_id(&mut *tx, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?;
+
+    auth::repository::otp::delete_many_by_user_id(&mut *tx, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?;
+
+    auth::repository::security_event::delete_many_by_user_id(&mut *tx, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?;
+
+    repository::delete_many_contact_changes_by_user_id(&mut *tx, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?;
+
+    order::repository::anonymise_many_by_owner_id(&mut *tx, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?;
+
+    repository::anonymise_by_id(&mut *tx, user_id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?;
+
+    if !repository::complete_account_deletion_by_id(&mut *tx, deletion.id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?
+    {
+        tracing::info!(
+            "Deletion of user {} was cancelled before it could complete",
+            user_id
+        );
+        return Ok(());
+    }
+
+    tx.commit().await.map_err(|err| {
+        tracing::error!("Failed to commit database transaction: {}", err);
+        Error::UnexpectedError
+    })?;
+
+    auth::service::auth::revoke_sessions(ctx.clone(), session_ids).await;
+
+    // NOTE: only once the deletion is committed, a rollback would otherwise leave the user pointing at a missing file
+    if let Some(profile_picture) = profile_picture {
+        if storage::delete_image(ctx.storage.clone(), profile_picture)
+            .await
+            .is_err()
+        {
+            tracing::error!("Failed to delete the profile picture of user {}", user_id);
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn purge_due_accounts(ctx: Arc<Context>) {
+    let deletions = match repository::find_many_due_account_deletions(&ctx.db_conn.pool).await {
+        Ok(deletions) => deletions,
+        Err(_) => return,
+    };
+
+    for deletion in deletions {
+        let deletion_id = deletion.id.clone();
+        if purge_account(ctx.clone(), deletion).await.is_err() {
+            tracing::error!("Failed to complete account deletion {}", deletion_id);
+        }
+    }
+}
@@ -1,5 +1,5 @@
 pub mod request {
-    use crate::modules::auth::middleware::Auth;
+    use crate::modules::auth::middleware::UserAuth as Auth;
     use serde::Deserialize;
 
     #[derive(Deserialize)]
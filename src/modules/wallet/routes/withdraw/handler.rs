@@ -1,5 +1,5 @@
 use super::{service::service, types::request};
-use crate::{modules::auth::middleware::Auth, types::Context};
+use crate::{modules::auth::middleware::UserAuth, types::Context};
 use axum::{
     extract::{Json, State},
     response::IntoResponse,
@@ -8,7 +8,7 @@ use std::sync::Arc;
 
 pub async fn handler(
     State(ctx): State<Arc<Context>>,
-    auth: Auth,
+    auth: UserAuth,
     Json(body): Json<request::Body>,
 ) -> impl IntoResponse {
     service(ctx, request::Payload { body, auth }).await
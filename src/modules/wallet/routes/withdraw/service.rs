@@ -31,6 +31,7 @@ pub async fn withdraw_funds(
 
     let wallet = match payload.as_kitchen {
         true => {
+            // NOTE: kitchen members never get to withdraw, only the owner does
             let kitchen = kitchen::repository::find_by_owner_id(&mut *tx, payload.user.id.clone())
                 .await
                 .map_err(|_| response::Error::FailedToPlaceWithdrawal)?
@@ -11,7 +11,11 @@ use std::sync::Arc;
 
 use crate::{
     modules::{
-        kitchen, order::repository::Order, payment, transaction, user::repository::User, wallet,
+        kitchen,
+        order::repository::{Order, OrderGroup},
+        payment, transaction,
+        user::repository::User,
+        wallet,
     },
     types::AppEnvironment,
     Context,
@@ -226,7 +230,6 @@ pub struct InitializePaymentForOrder {
 }
 
 pub async fn initialize_payment_for_order(
-    ctx: Arc<Context>,
     tx: &mut Transaction<'_, Postgres>,
     payload: InitializePaymentForOrder,
 ) -> Result<()> {
@@ -240,7 +243,6 @@ pub async fn initialize_payment_for_order(
     }
 
     payment::service::confirm_payment_for_order(
-        ctx,
         tx,
         payment::service::ConfirmPaymentForOrderPayload {
             payment_method: payment::service::PaymentMethod::Wallet,
@@ -253,6 +255,39 @@ pub async fn initialize_payment_for_order(
     Ok(())
 }
 
+pub struct InitializePaymentForOrderGroup {
+    pub group: OrderGroup,
+    pub orders: Vec<Order>,
+    pub payer: User,
+}
+
+pub async fn initialize_payment_for_order_group(
+    tx: &mut Transaction<'_, Postgres>,
+    payload: InitializePaymentForOrderGroup,
+) -> Result<()> {
+    let wallet = wallet::repository::find_by_owner_id(&mut **tx, payload.payer.id.clone())
+        .await
+        .map_err(|_| Error::UnexpectedError)?
+        .ok_or(Error::WalletNotFound)?;
+
+    if wallet.balance < payload.group.total {
+        return Err(Error::InsufficientBalance);
+    }
+
+    payment::service::confirm_payment_for_order_group(
+        tx,
+        payment::service::ConfirmPaymentForOrderGroupPayload {
+            payment_method: payment::service::PaymentMethod::Wallet,
+            group: payload.group,
+            orders: payload.orders,
+        },
+    )
+    .await
+    .map_err(|_| Error::UnexpectedError)?;
+
+    Ok(())
+}
+
 pub struct ConfirmPaymentForOrderPayload {
     pub order: Order,
     pub wallet: Wallet,
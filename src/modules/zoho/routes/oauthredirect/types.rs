@@ -1,7 +1,9 @@
 pub mod request {
-    pub use crate::modules::auth::middleware::AdminAuth as Auth;
+    use crate::modules::auth::{middleware::RequirePermission, permission};
     use serde::Deserialize;
 
+    pub type Auth = RequirePermission<permission::ManageIntegrations>;
+
     #[derive(Deserialize)]
     pub struct Params {
         #[serde(rename = "accounts-server")]
@@ -1,10 +1,17 @@
-pub use crate::utils::database;
+use crate::modules::{
+    auth::service::id_token::Jwks,
+    notification::service::{email, sms},
+    storage,
+};
+pub use crate::utils::{database, rate_limit};
 use async_trait::async_trait;
 use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
 use base64::Engine;
+use bigdecimal::BigDecimal;
 use chrono::{DateTime, Utc};
 use core::time::Duration;
 use futures::StreamExt;
+use lettre::message::Mailbox;
 use oauth_fcm::{create_shared_token_manager, TokenManager};
 use serde::{Deserialize, Serialize};
 use std::env;
@@ -30,6 +37,74 @@ impl AppEnvironment {
     }
 }
 
+#[derive(Clone)]
+pub enum OtpProviderKind {
+    Termii,
+    Local,
+    Test,
+}
+
+impl OtpProviderKind {
+    pub fn from(raw_provider: String) -> Self {
+        match raw_provider.as_ref() {
+            "local" => Self::Local,
+            "test" => Self::Test,
+            _ => Self::Termii,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum MailTransportKind {
+    Smtp,
+    Http,
+    File,
+}
+
+impl MailTransportKind {
+    pub fn from(raw_transport: String) -> Self {
+        match raw_transport.as_ref() {
+            "http" => Self::Http,
+            "file" => Self::File,
+            _ => Self::Smtp,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum StorageBackendKind {
+    Cloudinary,
+    S3,
+    Local,
+}
+
+impl StorageBackendKind {
+    pub fn from(raw_backend: String) -> Self {
+        match raw_backend.as_ref() {
+            "s3" => Self::S3,
+            "local" => Self::Local,
+            _ => Self::Cloudinary,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum SmsProviderKind {
+    Termii,
+    AfricasTalking,
+    Local,
+}
+
+impl SmsProviderKind {
+    pub fn from(raw_provider: String) -> Self {
+        match raw_provider.as_ref() {
+            "africastalking" | "africas_talking" => Self::AfricasTalking,
+            "local" => Self::Local,
+            _ => Self::Termii,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct AppContext {
     pub host: String,
@@ -40,11 +115,7 @@ pub struct AppContext {
 
 #[derive(Clone)]
 pub struct StorageContext {
-    pub api_key: String,
-    pub api_secret: String,
-    pub upload_endpoint: String,
-    pub delete_endpoint: String,
-    pub upload_preset: String,
+    pub backend: Arc<dyn storage::StorageBackend>,
 }
 
 #[derive(Clone)]
@@ -55,18 +126,111 @@ pub struct PaymentContext {
 
 #[derive(Clone)]
 pub struct MailContext {
-    pub host: String,
-    pub sender: String,
-    pub user: String,
-    pub password: String,
+    pub sender: Mailbox,
+    pub transport: Arc<dyn email::Transport>,
+}
+
+// `cost_per_segment` and `daily_cap` are in naira
+#[derive(Clone)]
+pub struct SmsContext {
+    pub provider: Arc<dyn sms::SmsProvider>,
+    pub cost_per_segment: BigDecimal,
+    pub daily_cap: BigDecimal,
 }
 
 #[derive(Clone)]
 pub struct OtpContext {
+    pub provider: OtpProviderKind,
     pub api_key: String,
     pub app_id: String,
     pub send_endpoint: String,
     pub verify_endpoint: String,
+    pub sms_endpoint: String,
+    pub voice_endpoint: String,
+    pub test_code: String,
+    pub max_attempts: i32,
+    pub resend_cooldown: i64,
+}
+
+#[derive(Clone)]
+pub struct AuthSigningKey {
+    pub id: String,
+    pub secret: String,
+}
+
+// NOTE: HS256 is only as strong as its secret, anything shorter than the hash it produces is easier to guess
+const MIN_SIGNING_KEY_LENGTH: usize = 32;
+
+// Keys take the form `kid:secret,kid:secret`, a key that's been rotated out should stay listed until the tokens it signed expire
+fn parse_signing_keys(raw_keys: String) -> Vec<AuthSigningKey> {
+    let keys = raw_keys
+        .split(',')
+        .filter(|key| !key.trim().is_empty())
+        .map(|key| {
+            let (id, secret) = key
+                .trim()
+                .split_once(':')
+                .expect("Invalid AUTH_SIGNING_KEYS");
+            AuthSigningKey {
+                id: id.to_string(),
+                secret: secret.to_string(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    for (index, key) in keys.iter().enumerate() {
+        if key.id.is_empty() {
+            panic!("AUTH_SIGNING_KEYS has a key without an id");
+        }
+        if key.secret.len() < MIN_SIGNING_KEY_LENGTH {
+            panic!(
+                "Signing key {} must be at least {} bytes long",
+                key.id, MIN_SIGNING_KEY_LENGTH
+            );
+        }
+        if keys[..index].iter().any(|other| other.id == key.id) {
+            panic!("Signing key {} is listed more than once", key.id);
+        }
+    }
+
+    keys
+}
+
+fn parse_list(raw_list: String) -> Vec<String> {
+    raw_list
+        .split(',')
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+// NOTE: `jwks` holds the provider's keys once fetched, a provider with no client ids is disabled
+#[derive(Clone)]
+pub struct IdentityProviderContext {
+    pub client_ids: Vec<String>,
+    pub jwks_url: String,
+    pub jwks: Arc<Mutex<Option<Jwks>>>,
+}
+
+#[derive(Clone)]
+pub struct AuthContext {
+    pub signing_keys: Vec<AuthSigningKey>,
+    pub active_signing_key_id: String,
+    pub access_token_ttl: i64,
+    pub denylist: Arc<dyn rate_limit::Store>,
+    pub magic_link_url: String,
+    pub magic_link_validity: i32,
+    pub account_deletion_grace_period: i64,
+    pub google: IdentityProviderContext,
+    pub apple: IdentityProviderContext,
+}
+
+#[derive(Clone)]
+pub struct RateLimitContext {
+    pub store: Arc<dyn rate_limit::Store>,
+    pub policies: rate_limit::Policies,
+    pub lockout_threshold: u64,
+    pub lockout_duration: u64,
 }
 
 #[derive(Clone)]
@@ -104,7 +268,10 @@ pub struct Context {
     pub storage: StorageContext,
     pub payment: PaymentContext,
     pub mail: MailContext,
+    pub sms: SmsContext,
     pub otp: OtpContext,
+    pub auth: AuthContext,
+    pub rate_limit: RateLimitContext,
     pub google: GoogleContext,
     pub zoho: ZohoContext,
 }
@@ -122,13 +289,22 @@ pub struct AppConfig {
     pub url: String,
 }
 
+// only the settings of the chosen backend have to be set
 #[derive(Clone)]
 pub struct StorageConfig {
-    pub api_key: String,
-    pub api_secret: String,
-    pub upload_endpoint: String,
-    pub delete_endpoint: String,
-    pub upload_preset: String,
+    pub backend: StorageBackendKind,
+    pub cloudinary_api_key: Option<String>,
+    pub cloudinary_api_secret: Option<String>,
+    pub cloudinary_upload_endpoint: Option<String>,
+    pub cloudinary_delete_endpoint: Option<String>,
+    pub cloudinary_upload_preset: Option<String>,
+    pub s3_endpoint: Option<String>,
+    pub s3_bucket: Option<String>,
+    pub s3_region: String,
+    pub s3_access_key_id: Option<String>,
+    pub s3_secret_access_key: Option<String>,
+    pub s3_public_url: Option<String>,
+    pub directory: String,
 }
 
 #[derive(Clone)]
@@ -137,18 +313,73 @@ pub struct PaymentConfig {
     pub secret_key: String,
 }
 
+// `address` defaults to the smtp user, the other transports need it set
 #[derive(Clone)]
 pub struct MailConfig {
+    pub transport: MailTransportKind,
     pub sender: String,
-    pub uri: String,
+    pub address: Option<String>,
+    pub uri: Option<String>,
+    pub pool_size: u32,
+    pub api_endpoint: Option<String>,
+    pub api_key: Option<String>,
+    pub directory: String,
+}
+
+// termii reuses the OTP api key, sender id and sms endpoint
+#[derive(Clone)]
+pub struct SmsConfig {
+    pub provider: SmsProviderKind,
+    pub cost_per_segment: BigDecimal,
+    pub daily_cap: BigDecimal,
+    pub sender_id: Option<String>,
+    pub africastalking_username: Option<String>,
+    pub africastalking_api_key: Option<String>,
+    pub africastalking_endpoint: String,
+    pub directory: String,
 }
 
 #[derive(Clone)]
 pub struct OtpConfig {
+    pub provider: OtpProviderKind,
     pub api_key: String,
     pub app_id: String,
     pub send_endpoint: String,
     pub verify_endpoint: String,
+    pub sms_endpoint: String,
+    pub voice_endpoint: String,
+    pub test_code: String,
+    pub max_attempts: i32,
+    pub resend_cooldown: i64,
+}
+
+// `jwks` is a fixed key set (e.g for tests) that's used instead of fetching `jwks_url`
+#[derive(Clone)]
+pub struct IdentityProviderConfig {
+    pub client_ids: Vec<String>,
+    pub jwks_url: String,
+    pub jwks: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct AuthConfig {
+    pub signing_keys: Vec<AuthSigningKey>,
+    pub active_signing_key_id: String,
+    pub access_token_ttl: i64,
+    pub magic_link_url: String,
+    pub magic_link_validity: i32,
+    pub account_deletion_grace_period: i64,
+    pub google: IdentityProviderConfig,
+    pub apple: IdentityProviderConfig,
+}
+
+#[derive(Clone)]
+pub struct RateLimitConfig {
+    pub store: rate_limit::StoreKind,
+    pub redis_url: Option<String>,
+    pub policies: rate_limit::Policies,
+    pub lockout_threshold: u64,
+    pub lockout_duration: u64,
 }
 
 #[derive(Clone)]
@@ -174,7 +405,10 @@ pub struct Config {
     pub storage: StorageConfig,
     pub payment: PaymentConfig,
     pub mail: MailConfig,
+    pub sms: SmsConfig,
     pub otp: OtpConfig,
+    pub auth: AuthConfig,
+    pub rate_limit: RateLimitConfig,
     pub google: GoogleConfig,
     pub zoho: ZohoConfig,
 }
@@ -320,26 +554,119 @@ impl Default for Config {
             .parse::<u32>()
             .expect("Invalid PORT number");
         let url = env::var("URL").unwrap_or_else(|_| format!("http://{}:{}", host, port));
-        let storage_api_key = env::var("CLOUDINARY_API_KEY").expect("CLOUDINARY_API_KEY not set");
-        let storage_api_secret =
-            env::var("CLOUDINARY_API_SECRET").expect("CLOUDINARY_API_SECRET not set");
-        let storage_upload_endpoint =
-            env::var("CLOUDINARY_UPLOAD_ENDPOINT").expect("CLOUDINARY_UPLOAD_ENDPOINT not set");
-        let storage_delete_endpoint =
-            env::var("CLOUDINARY_DELETE_ENDPOINT").expect("CLOUDINARY_DELETE_ENDPOINT not set");
-        let storage_upload_preset =
-            env::var("CLOUDINARY_UPLOAD_PRESET").expect("CLOUDINARY_UPLOAD_PRESET not set");
+        let storage_backend =
+            env::var("STORAGE_BACKEND").unwrap_or_else(|_| "cloudinary".to_string());
+        let storage_cloudinary_api_key = env::var("CLOUDINARY_API_KEY").ok();
+        let storage_cloudinary_api_secret = env::var("CLOUDINARY_API_SECRET").ok();
+        let storage_cloudinary_upload_endpoint = env::var("CLOUDINARY_UPLOAD_ENDPOINT").ok();
+        let storage_cloudinary_delete_endpoint = env::var("CLOUDINARY_DELETE_ENDPOINT").ok();
+        let storage_cloudinary_upload_preset = env::var("CLOUDINARY_UPLOAD_PRESET").ok();
+        let storage_s3_endpoint = env::var("STORAGE_S3_ENDPOINT").ok();
+        let storage_s3_bucket = env::var("STORAGE_S3_BUCKET").ok();
+        let storage_s3_region =
+            env::var("STORAGE_S3_REGION").unwrap_or_else(|_| "us-east-1".to_string());
+        let storage_s3_access_key_id = env::var("STORAGE_S3_ACCESS_KEY_ID").ok();
+        let storage_s3_secret_access_key = env::var("STORAGE_S3_SECRET_ACCESS_KEY").ok();
+        let storage_s3_public_url = env::var("STORAGE_S3_PUBLIC_URL").ok();
+        let storage_directory =
+            env::var("STORAGE_DIRECTORY").unwrap_or_else(|_| "public/uploads".to_string());
         let payment_api_endpoint =
             env::var("PAYSTACK_API_ENDPOINT").expect("PAYSTACK_API_ENDPOINT not set");
         let payment_secret_key =
             env::var("PAYSTACK_SECRET_KEY").expect("PAYSTACK_SECRET_KEY not set");
         let mail_sender = env::var("MAIL_SENDER").expect("MAIL_SENDER not set");
-        let mail_uri = env::var("MAIL_URI").expect("MAIL_URI not set");
+        let mail_transport = env::var("MAIL_TRANSPORT").unwrap_or_else(|_| "smtp".to_string());
+        let mail_address = env::var("MAIL_ADDRESS").ok();
+        let mail_uri = env::var("MAIL_URI").ok();
+        let mail_pool_size = env::var("MAIL_POOL_SIZE")
+            .unwrap_or_else(|_| "10".to_string())
+            .parse::<u32>()
+            .expect("Invalid MAIL_POOL_SIZE");
+        let mail_api_endpoint = env::var("MAIL_API_ENDPOINT").ok();
+        let mail_api_key = env::var("MAIL_API_KEY").ok();
+        let mail_directory = env::var("MAIL_DIRECTORY").unwrap_or_else(|_| "mail".to_string());
+        let sms_provider = env::var("SMS_PROVIDER").unwrap_or_else(|_| "termii".to_string());
+        let sms_cost_per_segment = env::var("SMS_COST_PER_SEGMENT")
+            .unwrap_or_else(|_| "4".to_string())
+            .parse::<BigDecimal>()
+            .expect("Invalid SMS_COST_PER_SEGMENT");
+        let sms_daily_cap = env::var("SMS_DAILY_CAP")
+            .unwrap_or_else(|_| "10000".to_string())
+            .parse::<BigDecimal>()
+            .expect("Invalid SMS_DAILY_CAP");
+        let sms_sender_id = env::var("SMS_SENDER_ID").ok();
+        let sms_africastalking_username = env::var("SMS_AFRICASTALKING_USERNAME").ok();
+        let sms_africastalking_api_key = env::var("SMS_AFRICASTALKING_API_KEY").ok();
+        let sms_africastalking_endpoint = env::var("SMS_AFRICASTALKING_ENDPOINT")
+            .unwrap_or_else(|_| "https://api.africastalking.com/version1/messaging".to_string());
+        let sms_directory = env::var("SMS_DIRECTORY").unwrap_or_else(|_| "sms".to_string());
         let otp_api_key = env::var("OTP_API_KEY").expect("OTP_API_KEY not set");
         let otp_app_id = env::var("OTP_APP_ID").expect("OTP_APP_ID not set");
         let otp_send_endpoint = env::var("OTP_SEND_ENDPOINT").expect("OTP_SEND_ENDPOINT not set");
         let otp_verify_endpoint =
             env::var("OTP_VERIFY_ENDPOINT").expect("OTP_VERIFY_ENDPOINT not set");
+        let otp_provider = env::var("OTP_PROVIDER").unwrap_or_else(|_| "termii".to_string());
+        let otp_sms_endpoint = env::var("OTP_SMS_ENDPOINT")
+            .unwrap_or_else(|_| "https://api.ng.termii.com/api/sms/send".to_string());
+        let otp_voice_endpoint = env::var("OTP_VOICE_ENDPOINT")
+            .unwrap_or_else(|_| "https://api.ng.termii.com/api/sms/otp/send/voice".to_string());
+        let otp_test_code = env::var("OTP_TEST_CODE").unwrap_or_else(|_| "1234".to_string());
+        let otp_max_attempts = env::var("OTP_MAX_ATTEMPTS")
+            .unwrap_or_else(|_| "5".to_string())
+            .parse::<i32>()
+            .expect("Invalid OTP_MAX_ATTEMPTS");
+        let otp_resend_cooldown = env::var("OTP_RESEND_COOLDOWN")
+            .unwrap_or_else(|_| "60".to_string())
+            .parse::<i64>()
+            .expect("Invalid OTP_RESEND_COOLDOWN");
+        let auth_signing_keys =
+            parse_signing_keys(env::var("AUTH_SIGNING_KEYS").expect("AUTH_SIGNING_KEYS not set"));
+        let auth_active_signing_key_id = env::var("AUTH_ACTIVE_SIGNING_KEY_ID")
+            .ok()
+            .or_else(|| auth_signing_keys.first().map(|key| key.id.clone()))
+            .expect("AUTH_SIGNING_KEYS is empty");
+        if !auth_signing_keys
+            .iter()
+            .any(|key| key.id == auth_active_signing_key_id)
+        {
+            panic!("AUTH_ACTIVE_SIGNING_KEY_ID is not one of AUTH_SIGNING_KEYS");
+        }
+        let auth_access_token_ttl = env::var("AUTH_ACCESS_TOKEN_TTL")
+            .unwrap_or_else(|_| "900".to_string())
+            .parse::<i64>()
+            .expect("Invalid AUTH_ACCESS_TOKEN_TTL");
+        let auth_magic_link_url =
+            env::var("AUTH_MAGIC_LINK_URL").unwrap_or_else(|_| format!("{}/auth/magic-link", url));
+        let auth_magic_link_validity = env::var("AUTH_MAGIC_LINK_VALIDITY")
+            .unwrap_or_else(|_| "15".to_string())
+            .parse::<i32>()
+            .expect("Invalid AUTH_MAGIC_LINK_VALIDITY");
+        let auth_account_deletion_grace_period = env::var("AUTH_ACCOUNT_DELETION_GRACE_PERIOD")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse::<i64>()
+            .expect("Invalid AUTH_ACCOUNT_DELETION_GRACE_PERIOD");
+        let auth_google_client_ids =
+            parse_list(env::var("AUTH_GOOGLE_CLIENT_IDS").unwrap_or_default());
+        let auth_google_jwks_url = env::var("AUTH_GOOGLE_JWKS_URL")
+            .unwrap_or_else(|_| "https://www.googleapis.com/oauth2/v3/certs".to_string());
+        let auth_google_jwks = env::var("AUTH_GOOGLE_JWKS").ok();
+        let auth_apple_client_ids =
+            parse_list(env::var("AUTH_APPLE_CLIENT_IDS").unwrap_or_default());
+        let auth_apple_jwks_url = env::var("AUTH_APPLE_JWKS_URL")
+            .unwrap_or_else(|_| "https://appleid.apple.com/auth/keys".to_string());
+        let auth_apple_jwks = env::var("AUTH_APPLE_JWKS").ok();
+        let rate_limit_store =
+            env::var("RATE_LIMIT_STORE").unwrap_or_else(|_| "memory".to_string());
+        let rate_limit_redis_url = env::var("RATE_LIMIT_REDIS_URL").ok();
+        let rate_limit_policies = env::var("RATE_LIMIT_POLICIES").ok();
+        let rate_limit_lockout_threshold = env::var("RATE_LIMIT_LOCKOUT_THRESHOLD")
+            .unwrap_or_else(|_| "10".to_string())
+            .parse::<u64>()
+            .expect("Invalid RATE_LIMIT_LOCKOUT_THRESHOLD");
+        let rate_limit_lockout_duration = env::var("RATE_LIMIT_LOCKOUT_DURATION")
+            .unwrap_or_else(|_| "1800".to_string())
+            .parse::<u64>()
+            .expect("Invalid RATE_LIMIT_LOCKOUT_DURATION");
         let google_fcm_credentials =
             env::var("GOOGLE_FCM_CREDENTIALS").expect("GOOGLE_FCM_CREDENTIALS not set");
         let zoho_client_id = env::var("ZOHO_CLIENT_ID").expect("ZOHO_CLIENT_ID not set");
@@ -364,25 +691,80 @@ impl Default for Config {
                 url,
             },
             storage: StorageConfig {
-                api_key: storage_api_key,
-                api_secret: storage_api_secret,
-                upload_endpoint: storage_upload_endpoint,
-                delete_endpoint: storage_delete_endpoint,
-                upload_preset: storage_upload_preset,
+                backend: StorageBackendKind::from(storage_backend),
+                cloudinary_api_key: storage_cloudinary_api_key,
+                cloudinary_api_secret: storage_cloudinary_api_secret,
+                cloudinary_upload_endpoint: storage_cloudinary_upload_endpoint,
+                cloudinary_delete_endpoint: storage_cloudinary_delete_endpoint,
+                cloudinary_upload_preset: storage_cloudinary_upload_preset,
+                s3_endpoint: storage_s3_endpoint,
+                s3_bucket: storage_s3_bucket,
+                s3_region: storage_s3_region,
+                s3_access_key_id: storage_s3_access_key_id,
+                s3_secret_access_key: storage_s3_secret_access_key,
+                s3_public_url: storage_s3_public_url,
+                directory: storage_directory,
             },
             payment: PaymentConfig {
                 api_endpoint: payment_api_endpoint,
                 secret_key: payment_secret_key,
             },
             mail: MailConfig {
+                transport: MailTransportKind::from(mail_transport),
                 sender: mail_sender,
+                address: mail_address,
                 uri: mail_uri,
+                pool_size: mail_pool_size,
+                api_endpoint: mail_api_endpoint,
+                api_key: mail_api_key,
+                directory: mail_directory,
+            },
+            sms: SmsConfig {
+                provider: SmsProviderKind::from(sms_provider),
+                cost_per_segment: sms_cost_per_segment,
+                daily_cap: sms_daily_cap,
+                sender_id: sms_sender_id,
+                africastalking_username: sms_africastalking_username,
+                africastalking_api_key: sms_africastalking_api_key,
+                africastalking_endpoint: sms_africastalking_endpoint,
+                directory: sms_directory,
             },
             otp: OtpConfig {
+                provider: OtpProviderKind::from(otp_provider),
                 api_key: otp_api_key,
                 app_id: otp_app_id,
                 send_endpoint: otp_send_endpoint,
                 verify_endpoint: otp_verify_endpoint,
+                sms_endpoint: otp_sms_endpoint,
+                voice_endpoint: otp_voice_endpoint,
+                test_code: otp_test_code,
+                max_attempts: otp_max_attempts,
+                resend_cooldown: otp_resend_cooldown,
+            },
+            auth: AuthConfig {
+                signing_keys: auth_signing_keys,
+                active_signing_key_id: auth_active_signing_key_id,
+                access_token_ttl: auth_access_token_ttl,
+                magic_link_url: auth_magic_link_url,
+                magic_link_validity: auth_magic_link_validity,
+                account_deletion_grace_period: auth_account_deletion_grace_period,
+                google: IdentityProviderConfig {
+                    client_ids: auth_google_client_ids,
+                    jwks_url: auth_google_jwks_url,
+                    jwks: auth_google_jwks,
+                },
+                apple: IdentityProviderConfig {
+                    client_ids: auth_apple_client_ids,
+                    jwks_url: auth_apple_jwks_url,
+                    jwks: auth_apple_jwks,
+                },
+            },
+            rate_limit: RateLimitConfig {
+                store: rate_limit::StoreKind::from(rate_limit_store),
+                redis_url: rate_limit_redis_url,
+                policies: rate_limit::Policies::from(rate_limit_policies),
+                lockout_threshold: rate_limit_lockout_threshold,
+                lockout_duration: rate_limit_lockout_duration,
             },
             google: GoogleConfig {
                 fcm_credentials: google_fcm_credentials,
@@ -416,15 +798,164 @@ impl ToContext for Config {
         let db_conn = database::connect(self.database.url.as_str()).await;
         database::migrate(db_conn.clone()).await;
 
-        let parsed_mail_uri = parse_uri(&self.mail.uri).expect("Invalid mail uri");
-        let mail_host = parsed_mail_uri.host.expect("Invalid mail host").to_string();
-        let mail_user = parsed_mail_uri.user.expect("Invalid mail user");
-        let mail_password = decode(mail_user.password.expect("Invalid mail password"))
-            .expect("Invalid mail password")
-            .to_string();
-        let mail_user = decode(mail_user.name)
-            .expect("Invalid mail user")
-            .to_string();
+        if let (AppEnvironment::Production, OtpProviderKind::Test) =
+            (&self.app.environment, &self.otp.provider)
+        {
+            panic!("The test OTP provider cannot be used in production");
+        }
+
+        if let (AppEnvironment::Production, MailTransportKind::File) =
+            (&self.app.environment, &self.mail.transport)
+        {
+            panic!("The file mail transport cannot be used in production");
+        }
+
+        if let (AppEnvironment::Production, SmsProviderKind::Local) =
+            (&self.app.environment, &self.sms.provider)
+        {
+            panic!("The local SMS provider cannot be used in production");
+        }
+
+        if let (AppEnvironment::Production, StorageBackendKind::Local) =
+            (&self.app.environment, &self.storage.backend)
+        {
+            panic!("The local storage backend cannot be used in production");
+        }
+
+        // NOTE: the denylist lives in this store, in memory a sign out would only reach the machine that handled it
+        if let (AppEnvironment::Production, rate_limit::StoreKind::Memory) =
+            (&self.app.environment, &self.rate_limit.store)
+        {
+            panic!("The memory rate limit store cannot be used in production");
+        }
+
+        let rate_limit_store =
+            rate_limit::connect(self.rate_limit.store, self.rate_limit.redis_url).await;
+
+        let auth_google_jwks = self
+            .auth
+            .google
+            .jwks
+            .map(|jwks| Jwks::fixed(&jwks).expect("Invalid AUTH_GOOGLE_JWKS"));
+        let auth_apple_jwks = self
+            .auth
+            .apple
+            .jwks
+            .map(|jwks| Jwks::fixed(&jwks).expect("Invalid AUTH_APPLE_JWKS"));
+
+        let (mail_transport, mail_address): (Arc<dyn email::Transport>, Option<String>) =
+            match self.mail.transport {
+                MailTransportKind::Smtp => {
+                    let mail_uri = self.mail.uri.expect("MAIL_URI not set");
+                    let parsed_mail_uri = parse_uri(&mail_uri).expect("Invalid mail uri");
+                    let mail_host = parsed_mail_uri.host.expect("Invalid mail host").to_string();
+                    let mail_user = parsed_mail_uri.user.expect("Invalid mail user");
+                    let mail_password = decode(mail_user.password.expect("Invalid mail password"))
+                        .expect("Invalid mail password")
+                        .to_string();
+                    let mail_user = decode(mail_user.name)
+                        .expect("Invalid mail user")
+                        .to_string();
+
+                    (
+                        Arc::new(email::SmtpTransport::new(email::SmtpTransportConfig {
+                            host: mail_host,
+                            port: parsed_mail_uri.port,
+                            user: mail_user.clone(),
+                            password: mail_password,
+                            pool_size: self.mail.pool_size,
+                        })),
+                        self.mail.address.or(Some(mail_user)),
+                    )
+                }
+                MailTransportKind::Http => (
+                    Arc::new(email::HttpTransport::new(
+                        self.mail.api_endpoint.expect("MAIL_API_ENDPOINT not set"),
+                        self.mail.api_key.expect("MAIL_API_KEY not set"),
+                    )),
+                    self.mail.address,
+                ),
+                MailTransportKind::File => (
+                    Arc::new(email::FileTransport::new(self.mail.directory)),
+                    self.mail.address,
+                ),
+            };
+        let mail_sender = Mailbox::new(
+            Some(self.mail.sender),
+            mail_address
+                .expect("MAIL_ADDRESS not set")
+                .parse()
+                .expect("Invalid MAIL_ADDRESS"),
+        );
+
+        let storage_backend: Arc<dyn storage::StorageBackend> = match self.storage.backend {
+            StorageBackendKind::Cloudinary => Arc::new(storage::CloudinaryStorageBackend::new(
+                storage::CloudinaryStorageBackendConfig {
+                    api_key: self
+                        .storage
+                        .cloudinary_api_key
+                        .expect("CLOUDINARY_API_KEY not set"),
+                    api_secret: self
+                        .storage
+                        .cloudinary_api_secret
+                        .expect("CLOUDINARY_API_SECRET not set"),
+                    upload_endpoint: self
+                        .storage
+                        .cloudinary_upload_endpoint
+                        .expect("CLOUDINARY_UPLOAD_ENDPOINT not set"),
+                    delete_endpoint: self
+                        .storage
+                        .cloudinary_delete_endpoint
+                        .expect("CLOUDINARY_DELETE_ENDPOINT not set"),
+                    upload_preset: self
+                        .storage
+                        .cloudinary_upload_preset
+                        .expect("CLOUDINARY_UPLOAD_PRESET not set"),
+                },
+            )),
+            StorageBackendKind::S3 => Arc::new(storage::S3StorageBackend::new(
+                storage::S3StorageBackendConfig {
+                    endpoint: self
+                        .storage
+                        .s3_endpoint
+                        .expect("STORAGE_S3_ENDPOINT not set"),
+                    bucket: self.storage.s3_bucket.expect("STORAGE_S3_BUCKET not set"),
+                    region: self.storage.s3_region,
+                    access_key_id: self
+                        .storage
+                        .s3_access_key_id
+                        .expect("STORAGE_S3_ACCESS_KEY_ID not set"),
+                    secret_access_key: self
+                        .storage
+                        .s3_secret_access_key
+                        .expect("STORAGE_S3_SECRET_ACCESS_KEY not set"),
+                    public_url: self.storage.s3_public_url,
+                },
+            )),
+            StorageBackendKind::Local => Arc::new(storage::LocalStorageBackend::new(
+                self.storage.directory,
+                format!("{}/api/media", self.app.url),
+            )),
+        };
+
+        let sms_provider: Arc<dyn sms::SmsProvider> = match self.sms.provider {
+            SmsProviderKind::Termii => Arc::new(sms::TermiiSmsProvider::new(
+                self.otp.api_key.clone(),
+                self.sms.sender_id.unwrap_or(self.otp.app_id.clone()),
+                self.otp.sms_endpoint.clone(),
+            )),
+            SmsProviderKind::AfricasTalking => Arc::new(sms::AfricasTalkingSmsProvider::new(
+                self.sms
+                    .africastalking_username
+                    .expect("SMS_AFRICASTALKING_USERNAME not set"),
+                self.sms
+                    .africastalking_api_key
+                    .expect("SMS_AFRICASTALKING_API_KEY not set"),
+                self.sms.sender_id,
+                self.sms.africastalking_endpoint,
+            )),
+            SmsProviderKind::Local => Arc::new(sms::LocalSmsProvider::new(self.sms.directory)),
+        };
 
         let google_fcm_credentials_decoded =
             BASE64_STANDARD.decode(self.google.fcm_credentials).unwrap();
@@ -447,27 +978,58 @@ impl ToContext for Config {
             },
             db_conn: db_conn.clone(),
             storage: StorageContext {
-                api_key: self.storage.api_key,
-                api_secret: self.storage.api_secret,
-                upload_endpoint: self.storage.upload_endpoint,
-                delete_endpoint: self.storage.delete_endpoint,
-                upload_preset: self.storage.upload_preset,
+                backend: storage_backend,
             },
             payment: PaymentContext {
                 api_endpoint: self.payment.api_endpoint,
                 secret_key: self.payment.secret_key,
             },
             mail: MailContext {
-                sender: self.mail.sender,
-                user: mail_user,
-                password: mail_password,
-                host: mail_host,
+                sender: mail_sender,
+                transport: mail_transport,
+            },
+            sms: SmsContext {
+                provider: sms_provider,
+                cost_per_segment: self.sms.cost_per_segment,
+                daily_cap: self.sms.daily_cap,
             },
             otp: OtpContext {
+                provider: self.otp.provider,
                 api_key: self.otp.api_key,
                 app_id: self.otp.app_id,
                 send_endpoint: self.otp.send_endpoint,
                 verify_endpoint: self.otp.verify_endpoint,
+                sms_endpoint: self.otp.sms_endpoint,
+                voice_endpoint: self.otp.voice_endpoint,
+                test_code: self.otp.test_code,
+                max_attempts: self.otp.max_attempts,
+                resend_cooldown: self.otp.resend_cooldown,
+            },
+            // NOTE: the denylist shares the rate limit store so that revocations reach every instance
+            auth: AuthContext {
+                signing_keys: self.auth.signing_keys,
+                active_signing_key_id: self.auth.active_signing_key_id,
+                access_token_ttl: self.auth.access_token_ttl,
+                denylist: rate_limit_store.clone(),
+                magic_link_url: self.auth.magic_link_url,
+                magic_link_validity: self.auth.magic_link_validity,
+                account_deletion_grace_period: self.auth.account_deletion_grace_period,
+                google: IdentityProviderContext {
+                    client_ids: self.auth.google.client_ids,
+                    jwks_url: self.auth.google.jwks_url,
+                    jwks: Arc::new(Mutex::new(auth_google_jwks)),
+                },
+                apple: IdentityProviderContext {
+                    client_ids: self.auth.apple.client_ids,
+                    jwks_url: self.auth.apple.jwks_url,
+                    jwks: Arc::new(Mutex::new(auth_apple_jwks)),
+                },
+            },
+            rate_limit: RateLimitContext {
+                store: rate_limit_store,
+                policies: self.rate_limit.policies,
+                lockout_threshold: self.rate_limit.lockout_threshold,
+                lockout_duration: self.rate_limit.lockout_duration,
             },
             google: GoogleContext {
                 fcm_token_manager: google_fcm_token_manager,
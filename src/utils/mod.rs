@@ -1,3 +1,4 @@
 pub mod database;
 pub mod pagination;
+pub mod rate_limit;
 pub mod validation;
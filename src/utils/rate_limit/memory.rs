@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use tokio::{sync::Mutex, time::Instant};
+
+use super::{Error, Hit, Store};
+
+// Expired windows are only swept once the map grows past this many keys
+const SWEEP_THRESHOLD: usize = 10_000;
+
+// Only suitable for a single instance since every instance keeps its own counters
+pub struct MemoryStore {
+    windows: Mutex<HashMap<String, (u64, Instant)>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self {
+            windows: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+fn seconds_until(instant: Instant) -> u64 {
+    let remaining = instant.saturating_duration_since(Instant::now());
+    remaining.as_secs() + if remaining.subsec_nanos() > 0 { 1 } else { 0 }
+}
+
+#[async_trait]
+impl Store for MemoryStore {
+    async fn hit(&self, key: &str, window: u64) -> Result<Hit, Error> {
+        let mut windows = self.windows.lock().await;
+        let now = Instant::now();
+
+        if windows.len() > SWEEP_THRESHOLD {
+            windows.retain(|_, (_, resets_at)| *resets_at > now);
+        }
+
+        let entry = windows
+            .entry(key.to_string())
+            .or_insert((0, now + std::time::Duration::from_secs(window)));
+
+        if entry.1 <= now {
+            *entry = (0, now + std::time::Duration::from_secs(window));
+        }
+
+        entry.0 += 1;
+
+        Ok(Hit {
+            count: entry.0,
+            reset_in: seconds_until(entry.1),
+        })
+    }
+
+    async fn peek(&self, key: &str) -> Result<Option<Hit>, Error> {
+        let windows = self.windows.lock().await;
+
+        Ok(windows
+            .get(key)
+            .filter(|(_, resets_at)| *resets_at > Instant::now())
+            .map(|(count, resets_at)| Hit {
+                count: *count,
+                reset_in: seconds_until(*resets_at),
+            }))
+    }
+
+    async fn clear(&self, key: &str) -> Result<(), Error> {
+        self.windows.lock().await.remove(key);
+        Ok(())
+    }
+}
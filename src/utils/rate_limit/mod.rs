@@ -0,0 +1,187 @@
+mod memory;
+mod redis;
+
+use async_trait::async_trait;
+use std::{collections::HashMap, sync::Arc};
+
+pub use memory::MemoryStore;
+pub use redis::RedisStore;
+
+#[derive(Debug)]
+pub enum Error {
+    UnexpectedError,
+}
+
+#[derive(Clone, Debug)]
+pub struct Hit {
+    pub count: u64,
+    // seconds until the current window resets
+    pub reset_in: u64,
+}
+
+// Fixed window counters, every key lives for `window` seconds from its first hit
+#[async_trait]
+pub trait Store: Send + Sync {
+    async fn hit(&self, key: &str, window: u64) -> Result<Hit, Error>;
+
+    async fn peek(&self, key: &str) -> Result<Option<Hit>, Error>;
+
+    async fn clear(&self, key: &str) -> Result<(), Error>;
+}
+
+#[derive(Clone)]
+pub enum StoreKind {
+    Memory,
+    Redis,
+}
+
+impl StoreKind {
+    pub fn from(raw_store: String) -> Self {
+        match raw_store.as_ref() {
+            "redis" => Self::Redis,
+            _ => Self::Memory,
+        }
+    }
+}
+
+pub async fn connect(kind: StoreKind, redis_url: Option<String>) -> Arc<dyn Store> {
+    match kind {
+        StoreKind::Memory => Arc::new(MemoryStore::new()),
+        StoreKind::Redis => Arc::new(
+            RedisStore::connect(redis_url.expect("RATE_LIMIT_REDIS_URL not set").as_str()).await,
+        ),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Bucket {
+    Ip,
+    PhoneNumber,
+    User,
+}
+
+impl ToString for Bucket {
+    fn to_string(&self) -> String {
+        match self {
+            Self::Ip => String::from("ip"),
+            Self::PhoneNumber => String::from("phone_number"),
+            Self::User => String::from("user"),
+        }
+    }
+}
+
+impl Bucket {
+    fn parse(raw_bucket: &str) -> Option<Self> {
+        match raw_bucket {
+            "ip" => Some(Self::Ip),
+            "phone_number" => Some(Self::PhoneNumber),
+            "user" => Some(Self::User),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Rule {
+    pub bucket: Bucket,
+    pub limit: u64,
+    pub window: u64,
+}
+
+impl Rule {
+    fn new(bucket: Bucket, limit: u64, window: u64) -> Self {
+        Self {
+            bucket,
+            limit,
+            window,
+        }
+    }
+
+    // Takes the form `bucket:limit/window`, e.g. `ip:10/900`
+    fn parse(raw_rule: &str) -> Option<Self> {
+        let (bucket, quota) = raw_rule.trim().split_once(':')?;
+        let (limit, window) = quota.split_once('/')?;
+
+        Some(Self {
+            bucket: Bucket::parse(bucket.trim())?,
+            limit: limit.trim().parse().ok()?,
+            window: window.trim().parse().ok()?,
+        })
+    }
+}
+
+pub const GROUP_API: &str = "api";
+pub const GROUP_AUTH_OTP: &str = "auth.otp";
+pub const GROUP_AUTH_VERIFY: &str = "auth.verify";
+pub const GROUP_AUTH_REFRESH: &str = "auth.refresh";
+
+#[derive(Clone, Debug)]
+pub struct Policies(HashMap<String, Vec<Rule>>);
+
+impl Default for Policies {
+    fn default() -> Self {
+        Self(HashMap::from([
+            (
+                GROUP_API.to_string(),
+                vec![
+                    Rule::new(Bucket::Ip, 600, 60),
+                    Rule::new(Bucket::User, 300, 60),
+                ],
+            ),
+            (
+                GROUP_AUTH_OTP.to_string(),
+                vec![
+                    Rule::new(Bucket::Ip, 10, 900),
+                    Rule::new(Bucket::PhoneNumber, 3, 600),
+                ],
+            ),
+            (
+                GROUP_AUTH_VERIFY.to_string(),
+                vec![
+                    Rule::new(Bucket::Ip, 30, 900),
+                    Rule::new(Bucket::PhoneNumber, 10, 900),
+                ],
+            ),
+            (
+                GROUP_AUTH_REFRESH.to_string(),
+                vec![Rule::new(Bucket::Ip, 60, 900)],
+            ),
+        ]))
+    }
+}
+
+impl Policies {
+    // Overrides take the form `group=bucket:limit/window,...;group=...`, a group listed without rules is left unlimited
+    pub fn from(raw_overrides: Option<String>) -> Self {
+        let mut policies = Self::default();
+
+        let raw_overrides = match raw_overrides {
+            Some(raw_overrides) => raw_overrides,
+            None => return policies,
+        };
+
+        for raw_policy in raw_overrides.split(';').filter(|p| !p.trim().is_empty()) {
+            let (group, raw_rules) = raw_policy
+                .split_once('=')
+                .expect("Invalid RATE_LIMIT_POLICIES");
+
+            let rules = raw_rules
+                .split(',')
+                .filter(|r| !r.trim().is_empty())
+                .map(|r| Rule::parse(r).expect("Invalid RATE_LIMIT_POLICIES"))
+                .collect::<Vec<_>>();
+
+            policies.0.insert(group.trim().to_string(), rules);
+        }
+
+        policies
+    }
+
+    pub fn get(&self, group: &str) -> Vec<Rule> {
+        self.0.get(group).cloned().unwrap_or_default()
+    }
+}
+
+pub fn get_key(group: &str, bucket: &Bucket, value: &str) -> String {
+    format!("rate_limit:{}:{}:{}", group, bucket.to_string(), value)
+}
@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+use redis::{aio::ConnectionManager, AsyncCommands};
+
+use super::{Error, Hit, Store};
+
+pub struct RedisStore {
+    conn: ConnectionManager,
+}
+
+impl RedisStore {
+    pub async fn connect(redis_url: &str) -> Self {
+        let client = redis::Client::open(redis_url).unwrap_or_else(|err| {
+            tracing::error!("{}", err);
+            panic!("Invalid redis url {}", redis_url)
+        });
+
+        let conn = ConnectionManager::new(client).await.unwrap_or_else(|err| {
+            tracing::error!("{}", err);
+            panic!("Error connecting to redis {}", redis_url)
+        });
+
+        Self { conn }
+    }
+}
+
+#[async_trait]
+impl Store for RedisStore {
+    async fn hit(&self, key: &str, window: u64) -> Result<Hit, Error> {
+        let mut conn = self.conn.clone();
+
+        // NOTE: `SET NX` only starts the window on the first hit, so later hits never push it back
+        let (count, ttl): (u64, i64) = redis::pipe()
+            .atomic()
+            .cmd("SET")
+            .arg(key)
+            .arg(0)
+            .arg("EX")
+            .arg(window)
+            .arg("NX")
+            .ignore()
+            .incr(key, 1)
+            .ttl(key)
+            .query_async(&mut conn)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    "Error occurred while trying to record rate limit hit: {}",
+                    err
+                );
+                Error::UnexpectedError
+            })?;
+
+        Ok(Hit {
+            count,
+            reset_in: ttl.max(0) as u64,
+        })
+    }
+
+    async fn peek(&self, key: &str) -> Result<Option<Hit>, Error> {
+        let mut conn = self.conn.clone();
+
+        let (count, ttl): (Option<u64>, i64) = redis::pipe()
+            .get(key)
+            .ttl(key)
+            .query_async(&mut conn)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    "Error occurred while trying to fetch rate limit hits: {}",
+                    err
+                );
+                Error::UnexpectedError
+            })?;
+
+        Ok(count.map(|count| Hit {
+            count,
+            reset_in: ttl.max(0) as u64,
+        }))
+    }
+
+    async fn clear(&self, key: &str) -> Result<(), Error> {
+        let mut conn = self.conn.clone();
+
+        conn.del::<_, ()>(key).await.map_err(|err| {
+            tracing::error!(
+                "Error occurred while trying to clear rate limit hits: {}",
+                err
+            );
+            Error::UnexpectedError
+        })
+    }
+}